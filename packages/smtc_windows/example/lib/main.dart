@@ -27,8 +27,11 @@ class _MyAppState extends State<MyApp> {
         album: 'Album',
         albumArtist: 'Album Artist',
         artist: 'Artist',
-        thumbnail:
-            'https://media.glamour.com/photos/5f4c44e20c71c58fc210d35f/master/w_2560%2Cc_limit/mgid_ao_image_mtv.jpg',
+        thumbnail: [
+          'https://media.glamour.com/photos/5f4c44e20c71c58fc210d35f/master/w_2560%2Cc_limit/mgid_ao_image_mtv.jpg',
+        ],
+        genres: [],
+        mediaType: MediaType.music,
       ),
       timeline: const PlaybackTimeline(
         startTimeMs: 0,
@@ -91,8 +94,11 @@ class _MyAppState extends State<MyApp> {
                 album: 'Album',
                 albumArtist: 'Album Artist',
                 artist: 'Artist',
-                thumbnail:
-                    'https://media.glamour.com/photos/5f4c44e20c71c58fc210d35f/master/w_2560%2Cc_limit/mgid_ao_image_mtv.jpg',
+                thumbnail: [
+                  'https://media.glamour.com/photos/5f4c44e20c71c58fc210d35f/master/w_2560%2Cc_limit/mgid_ao_image_mtv.jpg',
+                ],
+                genres: [],
+                mediaType: MediaType.music,
               ),
             );
           },
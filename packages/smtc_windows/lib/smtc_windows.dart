@@ -2,9 +2,12 @@ library smtc_windows;
 
 export 'src/smtc_windows_base.dart' show SMTCWindows;
 export 'src/extensions.dart';
+export 'src/rust/api/api.dart' show smtcDefaultConfig;
 export 'src/rust/internal/config.dart';
+export 'src/rust/internal/events.dart';
 export 'src/rust/internal/metadata.dart';
 export 'src/rust/internal/playback_status.dart';
+export 'src/rust/internal/thumbnail.dart';
 export 'src/rust/internal/timeline.dart';
 export 'src/enums/button_event.dart';
 export 'src/enums/repeat_mode.dart';
@@ -1,5 +1,8 @@
 import 'rust/internal/config.dart';
+import 'rust/internal/events.dart';
 import 'rust/internal/metadata.dart';
+import 'rust/internal/repeat_mode.dart';
+import 'rust/internal/thumbnail.dart';
 import 'rust/internal/timeline.dart';
 
 extension SMTCConfigCopy on SMTCConfig {
@@ -11,6 +14,35 @@ extension SMTCConfigCopy on SMTCConfig {
     bool? prevEnabled,
     bool? fastForwardEnabled,
     bool? rewindEnabled,
+    bool? channelUpEnabled,
+    bool? channelDownEnabled,
+    bool? thumbnailsEnabled,
+    bool? mirrorPlaybackStatus,
+    bool? strictMetadata,
+    bool? optimisticSeek,
+    bool? sanitizeMetadata,
+    bool? transcodeThumbnails,
+    String? thumbnailContentType,
+    List<ThumbnailScheme>? allowedThumbnailSchemes,
+    List<(String, String)>? thumbnailHeaders,
+    bool? recordAsFavorite,
+    BigInt? buttonDebounceMs,
+    BigInt? eventCoalesceMs,
+    BigInt? autoDisableAfterStopMs,
+    BigInt? thumbnailWorkers,
+    List<RepeatMode>? allowedRepeatModes,
+    bool? ignoreDisabledButtons,
+    bool? recordAsToggle,
+    BigInt? thumbnailBudgetMs,
+    BigInt? maxThumbnailBytes,
+    ThumbnailMode? thumbnailMode,
+    ThumbnailTransition? thumbnailTransition,
+    MusicMetadata? defaultMetadata,
+    bool? traceRawEvents,
+    bool? reapplyOnRefresh,
+    BigInt? reconcileThresholdMs,
+    bool? dryRun,
+    EventSelection? events,
   }) {
     return SMTCConfig(
       playEnabled: playEnabled ?? this.playEnabled,
@@ -20,6 +52,38 @@ extension SMTCConfigCopy on SMTCConfig {
       prevEnabled: prevEnabled ?? this.prevEnabled,
       fastForwardEnabled: fastForwardEnabled ?? this.fastForwardEnabled,
       rewindEnabled: rewindEnabled ?? this.rewindEnabled,
+      channelUpEnabled: channelUpEnabled ?? this.channelUpEnabled,
+      channelDownEnabled: channelDownEnabled ?? this.channelDownEnabled,
+      thumbnailsEnabled: thumbnailsEnabled ?? this.thumbnailsEnabled,
+      mirrorPlaybackStatus: mirrorPlaybackStatus ?? this.mirrorPlaybackStatus,
+      strictMetadata: strictMetadata ?? this.strictMetadata,
+      optimisticSeek: optimisticSeek ?? this.optimisticSeek,
+      sanitizeMetadata: sanitizeMetadata ?? this.sanitizeMetadata,
+      transcodeThumbnails: transcodeThumbnails ?? this.transcodeThumbnails,
+      thumbnailContentType: thumbnailContentType ?? this.thumbnailContentType,
+      allowedThumbnailSchemes:
+          allowedThumbnailSchemes ?? this.allowedThumbnailSchemes,
+      thumbnailHeaders: thumbnailHeaders ?? this.thumbnailHeaders,
+      recordAsFavorite: recordAsFavorite ?? this.recordAsFavorite,
+      buttonDebounceMs: buttonDebounceMs ?? this.buttonDebounceMs,
+      eventCoalesceMs: eventCoalesceMs ?? this.eventCoalesceMs,
+      autoDisableAfterStopMs:
+          autoDisableAfterStopMs ?? this.autoDisableAfterStopMs,
+      thumbnailWorkers: thumbnailWorkers ?? this.thumbnailWorkers,
+      allowedRepeatModes: allowedRepeatModes ?? this.allowedRepeatModes,
+      ignoreDisabledButtons:
+          ignoreDisabledButtons ?? this.ignoreDisabledButtons,
+      recordAsToggle: recordAsToggle ?? this.recordAsToggle,
+      thumbnailBudgetMs: thumbnailBudgetMs ?? this.thumbnailBudgetMs,
+      maxThumbnailBytes: maxThumbnailBytes ?? this.maxThumbnailBytes,
+      thumbnailMode: thumbnailMode ?? this.thumbnailMode,
+      thumbnailTransition: thumbnailTransition ?? this.thumbnailTransition,
+      defaultMetadata: defaultMetadata ?? this.defaultMetadata,
+      traceRawEvents: traceRawEvents ?? this.traceRawEvents,
+      reapplyOnRefresh: reapplyOnRefresh ?? this.reapplyOnRefresh,
+      reconcileThresholdMs: reconcileThresholdMs ?? this.reconcileThresholdMs,
+      dryRun: dryRun ?? this.dryRun,
+      events: events ?? this.events,
     );
   }
 }
@@ -48,7 +112,12 @@ extension MusicMetadataCopy on MusicMetadata {
     String? artist,
     String? album,
     String? albumArtist,
-    String? thumbnail,
+    List<String>? thumbnail,
+    List<String>? genres,
+    int? trackNumber,
+    MediaType? mediaType,
+    String? titleLocale,
+    String? artistLocale,
   }) {
     return MusicMetadata(
       title: title ?? this.title,
@@ -56,6 +125,11 @@ extension MusicMetadataCopy on MusicMetadata {
       album: album ?? this.album,
       albumArtist: albumArtist ?? this.albumArtist,
       thumbnail: thumbnail ?? this.thumbnail,
+      genres: genres ?? this.genres,
+      trackNumber: trackNumber ?? this.trackNumber,
+      mediaType: mediaType ?? this.mediaType,
+      titleLocale: titleLocale ?? this.titleLocale,
+      artistLocale: artistLocale ?? this.artistLocale,
     );
   }
 }
@@ -5,14 +5,109 @@
 
 import '../frb_generated.dart';
 import '../internal/config.dart';
+import '../internal/diagnostics.dart';
+import '../internal/events.dart';
+import '../internal/handlers.dart';
+import '../internal/init.dart';
 import '../internal/metadata.dart';
 import '../internal/playback_status.dart';
+import '../internal/repeat_mode.dart';
+import '../internal/session_info.dart';
+import '../internal/thumbnail.dart';
 import '../internal/timeline.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 SmtcInternal smtcNew({bool? enabled}) =>
     RustLib.instance.api.crateApiApiSmtcNew(enabled: enabled);
 
+SmtcInternal smtcNewWithConfig({required SMTCConfig config, bool? enabled}) =>
+    RustLib.instance.api.crateApiApiSmtcNewWithConfig(
+      config: config,
+      enabled: enabled,
+    );
+
+/// Like `smtc_new_with_config`, but the player lives on, and is only called
+/// from, a dedicated STA thread.
+SmtcInternal smtcNewOnStaThread({required SMTCConfig config, bool? enabled}) =>
+    RustLib.instance.api.crateApiApiSmtcNewOnStaThread(
+      config: config,
+      enabled: enabled,
+    );
+
+/// The config a new control starts with, for building one from Dart.
+SMTCConfig smtcDefaultConfig() =>
+    RustLib.instance.api.crateApiApiSmtcDefaultConfig();
+
+SmtcInternal smtcNewWithInit({required SmtcInit init}) =>
+    RustLib.instance.api.crateApiApiSmtcNewWithInit(init: init);
+
+/// Calls `callback` with each button press until the returned subscription
+/// is cancelled or disposed, for setups that can't listen to a stream. Each
+/// call waits for the previous one to complete, on a thread of its own, so
+/// the press is never held up waiting on Dart.
+Future<EventSubscription> smtcOnButton({
+  required SmtcInternal internal,
+  required FutureOr<void> Function(SmtcButton) callback,
+}) => RustLib.instance.api.crateApiApiSmtcOnButton(
+  internal: internal,
+  callback: callback,
+);
+
+Future<EventSubscription> smtcOnPositionChange({
+  required SmtcInternal internal,
+  required FutureOr<void> Function(PositionChangeRequest) callback,
+}) => RustLib.instance.api.crateApiApiSmtcOnPositionChange(
+  internal: internal,
+  callback: callback,
+);
+
+Future<EventSubscription> smtcOnShuffleChange({
+  required SmtcInternal internal,
+  required FutureOr<void> Function(bool) callback,
+}) => RustLib.instance.api.crateApiApiSmtcOnShuffleChange(
+  internal: internal,
+  callback: callback,
+);
+
+Future<EventSubscription> smtcOnRepeatModeChange({
+  required SmtcInternal internal,
+  required FutureOr<void> Function(String) callback,
+}) => RustLib.instance.api.crateApiApiSmtcOnRepeatModeChange(
+  internal: internal,
+  callback: callback,
+);
+
+void smtcCancelSubscription({required EventSubscription subscription}) =>
+    RustLib.instance.api.crateApiApiSmtcCancelSubscription(
+      subscription: subscription,
+    );
+
+/// See `SMTCInternal::set_event_thread`.
+void smtcSetEventThread({
+  required SmtcInternal internal,
+  required bool dedicated,
+}) => RustLib.instance.api.crateApiApiSmtcSetEventThread(
+  internal: internal,
+  dedicated: dedicated,
+);
+
+SMTCConfig smtcCurrentConfig({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcCurrentConfig(internal: internal);
+
+void smtcFreeze({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcFreeze(internal: internal);
+
+Future<void> smtcThaw({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcThaw(internal: internal);
+
+Future<void> smtcSwitchSessionFull({
+  required SmtcInternal internal,
+  required SmtcInit init,
+}) => RustLib.instance.api.crateApiApiSmtcSwitchSessionFull(
+  internal: internal,
+  init: init,
+);
+
 Future<void> smtcUpdateConfig({
   required SmtcInternal internal,
   required SMTCConfig config,
@@ -31,9 +126,261 @@ Future<void> smtcUpdateMetadata({
   appId: appId,
 );
 
+/// See `SMTCInternal::update_metadata_silently`.
+Future<void> smtcUpdateMetadataSilently({
+  required SmtcInternal internal,
+  required MusicMetadata metadata,
+  String? appId,
+}) => RustLib.instance.api.crateApiApiSmtcUpdateMetadataSilently(
+  internal: internal,
+  metadata: metadata,
+  appId: appId,
+);
+
+Future<MetadataReport> smtcUpdateMetadataVerified({
+  required SmtcInternal internal,
+  required MusicMetadata metadata,
+  String? appId,
+}) => RustLib.instance.api.crateApiApiSmtcUpdateMetadataVerified(
+  internal: internal,
+  metadata: metadata,
+  appId: appId,
+);
+
+Future<void> smtcUpdateMetadataMap({
+  required SmtcInternal internal,
+  required Map<String, String> fields,
+  String? appId,
+}) => RustLib.instance.api.crateApiApiSmtcUpdateMetadataMap(
+  internal: internal,
+  fields: fields,
+  appId: appId,
+);
+
+void smtcSetQueue({
+  required SmtcInternal internal,
+  required List<MusicMetadata> queue,
+}) => RustLib.instance.api.crateApiApiSmtcSetQueue(
+  internal: internal,
+  queue: queue,
+);
+
+List<MusicMetadata> smtcQueue({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcQueue(internal: internal);
+
+Future<void> smtcSetPlaceholder({
+  required SmtcInternal internal,
+  required String title,
+  String? subtitle,
+  String? thumbnail,
+}) => RustLib.instance.api.crateApiApiSmtcSetPlaceholder(
+  internal: internal,
+  title: title,
+  subtitle: subtitle,
+  thumbnail: thumbnail,
+);
+
+/// Creates a token for cancelling `smtc_update_metadata_async`.
+CancellationToken smtcCancellationToken() =>
+    RustLib.instance.api.crateApiApiSmtcCancellationToken();
+
+void smtcCancel({required CancellationToken token}) =>
+    RustLib.instance.api.crateApiApiSmtcCancel(token: token);
+
+/// Resolves to `true` once the metadata is shown, or `false` if `token` or
+/// a later metadata update cancelled it first.
+Future<bool> smtcUpdateMetadataAsync({
+  required SmtcInternal internal,
+  required MusicMetadata metadata,
+  String? appId,
+  required CancellationToken token,
+}) => RustLib.instance.api.crateApiApiSmtcUpdateMetadataAsync(
+  internal: internal,
+  metadata: metadata,
+  appId: appId,
+  token: token,
+);
+
+Future<void> smtcUpdateMetadataWith({
+  required SmtcInternal internal,
+  required MusicMetadata metadata,
+  String? appId,
+  required ClearFlags clear,
+}) => RustLib.instance.api.crateApiApiSmtcUpdateMetadataWith(
+  internal: internal,
+  metadata: metadata,
+  appId: appId,
+  clear: clear,
+);
+
+Future<void> smtcSwitchSession({
+  required SmtcInternal internal,
+  required String appId,
+  required MusicMetadata metadata,
+}) => RustLib.instance.api.crateApiApiSmtcSwitchSession(
+  internal: internal,
+  appId: appId,
+  metadata: metadata,
+);
+
+Future<void> smtcPreviewMetadata({
+  required SmtcInternal internal,
+  required MusicMetadata metadata,
+  required BigInt revertAfterMs,
+}) => RustLib.instance.api.crateApiApiSmtcPreviewMetadata(
+  internal: internal,
+  metadata: metadata,
+  revertAfterMs: revertAfterMs,
+);
+
+bool smtcConfirmPreview({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcConfirmPreview(internal: internal);
+
+/// Returns the problems found in `metadata`, empty when it is valid.
+List<String> smtcValidateMetadata({required MusicMetadata metadata}) =>
+    RustLib.instance.api.crateApiApiSmtcValidateMetadata(metadata: metadata);
+
+/// Shuts down every SMTC instance, for calling on app exit. Safe to call
+/// more than once.
+void smtcShutdown() => RustLib.instance.api.crateApiApiSmtcShutdown();
+
+Capabilities smtcCapabilities() =>
+    RustLib.instance.api.crateApiApiSmtcCapabilities();
+
+/// Versions and capabilities to include in bug reports.
+Diagnostics smtcDiagnostics() =>
+    RustLib.instance.api.crateApiApiSmtcDiagnostics();
+
+/// MIME types of the image formats thumbnails can be decoded from on this
+/// machine.
+List<String> smtcSupportedThumbnailFormats() =>
+    RustLib.instance.api.crateApiApiSmtcSupportedThumbnailFormats();
+
+/// Sets the thumbnail from a raw Win32 file `HANDLE` opened with read access.
+///
+/// The handle is only borrowed: it stays open and owned by the caller, but
+/// its file pointer is moved to the end of the data. Handles that are
+/// closed or not disk files are rejected. Windows only.
+Future<void> smtcSetThumbnailFromHandle({
+  required SmtcInternal internal,
+  required PlatformInt64 handle,
+}) => RustLib.instance.api.crateApiApiSmtcSetThumbnailFromHandle(
+  internal: internal,
+  handle: handle,
+);
+
+/// Returns the average ARGB color of the art once shown, or `None` if it
+/// couldn't be loaded.
+Future<int?> smtcSetThumbnailWithColor({
+  required SmtcInternal internal,
+  required String source,
+}) => RustLib.instance.api.crateApiApiSmtcSetThumbnailWithColor(
+  internal: internal,
+  source: source,
+);
+
+void smtcShareThumbnailCache({
+  required SmtcInternal internal,
+  required SmtcInternal other,
+}) => RustLib.instance.api.crateApiApiSmtcShareThumbnailCache(
+  internal: internal,
+  other: other,
+);
+
+/// Loads every thumbnail through `resolver`, which returns the image bytes
+/// for a source or `None` if it can't load it. Art it doesn't return within
+/// `thumbnail_budget_ms`, or 10 seconds without a budget, isn't shown.
+Future<void> smtcSetThumbnailResolver({
+  required SmtcInternal internal,
+  required FutureOr<Uint8List?> Function(String) resolver,
+}) => RustLib.instance.api.crateApiApiSmtcSetThumbnailResolver(
+  internal: internal,
+  resolver: resolver,
+);
+
+void smtcClearThumbnailResolver({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcClearThumbnailResolver(
+      internal: internal,
+    );
+
+/// Resolves to `true` once the art is shown, or `false` if it couldn't be
+/// loaded.
+Future<bool> smtcSetThumbnailAsync({
+  required SmtcInternal internal,
+  required String source,
+}) => RustLib.instance.api.crateApiApiSmtcSetThumbnailAsync(
+  internal: internal,
+  source: source,
+);
+
+Future<bool> smtcPrefetchThumbnail({
+  required SmtcInternal internal,
+  required String source,
+}) => RustLib.instance.api.crateApiApiSmtcPrefetchThumbnail(
+  internal: internal,
+  source: source,
+);
+
+/// Always fails, see `SMTCInternal::set_session_icon`.
+Future<void> smtcSetSessionIcon({
+  required SmtcInternal internal,
+  required String source,
+}) => RustLib.instance.api.crateApiApiSmtcSetSessionIcon(
+  internal: internal,
+  source: source,
+);
+
+Stream<ThumbnailResult> smtcThumbnailResultEvent({
+  required SmtcInternal internal,
+}) => RustLib.instance.api.crateApiApiSmtcThumbnailResultEvent(
+  internal: internal,
+);
+
+Future<void> smtcPushTitleOverride({
+  required SmtcInternal internal,
+  required String title,
+}) => RustLib.instance.api.crateApiApiSmtcPushTitleOverride(
+  internal: internal,
+  title: title,
+);
+
+Future<void> smtcClearTitleOverride({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcClearTitleOverride(internal: internal);
+
+Future<void> smtcSetThumbnailFromToken({
+  required SmtcInternal internal,
+  required String token,
+}) => RustLib.instance.api.crateApiApiSmtcSetThumbnailFromToken(
+  internal: internal,
+  token: token,
+);
+
+Future<Uint8List?> smtcCurrentThumbnailBytes({
+  required SmtcInternal internal,
+}) => RustLib.instance.api.crateApiApiSmtcCurrentThumbnailBytes(
+  internal: internal,
+);
+
 Future<void> smtcClearMetadata({required SmtcInternal internal}) =>
     RustLib.instance.api.crateApiApiSmtcClearMetadata(internal: internal);
 
+Future<void> smtcClearMetadataFields({
+  required SmtcInternal internal,
+  required List<MetadataField> fields,
+}) => RustLib.instance.api.crateApiApiSmtcClearMetadataFields(
+  internal: internal,
+  fields: fields,
+);
+
+Future<void> smtcClearAppMediaId({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcClearAppMediaId(internal: internal);
+
+SessionInfo smtcSessionInfo({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcSessionInfo(internal: internal);
+
+Future<bool> smtcIsActiveSession({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcIsActiveSession(internal: internal);
+
 Future<void> smtcUpdateTimeline({
   required SmtcInternal internal,
   required PlaybackTimeline timeline,
@@ -42,6 +389,103 @@ Future<void> smtcUpdateTimeline({
   timeline: timeline,
 );
 
+Future<void> smtcClearTimeline({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcClearTimeline(internal: internal);
+
+Future<void> smtcSetDuration({
+  required SmtcInternal internal,
+  required PlatformInt64 durationMs,
+}) => RustLib.instance.api.crateApiApiSmtcSetDuration(
+  internal: internal,
+  durationMs: durationMs,
+);
+
+PlaybackTimeline? smtcCurrentTimeline({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcCurrentTimeline(internal: internal);
+
+Future<void> smtcSetPlaybackRate({
+  required SmtcInternal internal,
+  required double rate,
+}) => RustLib.instance.api.crateApiApiSmtcSetPlaybackRate(
+  internal: internal,
+  rate: rate,
+);
+
+/// Makes the position timer ask `provider` for the position on each tick.
+/// A tick `provider` doesn't answer within the timer interval is skipped.
+Future<void> smtcSetPositionProvider({
+  required SmtcInternal internal,
+  required FutureOr<PlatformInt64> Function() provider,
+}) => RustLib.instance.api.crateApiApiSmtcSetPositionProvider(
+  internal: internal,
+  provider: provider,
+);
+
+void smtcClearPositionProvider({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcClearPositionProvider(
+      internal: internal,
+    );
+
+Future<void> smtcStartPositionTimer({
+  required SmtcInternal internal,
+  required BigInt intervalMs,
+}) => RustLib.instance.api.crateApiApiSmtcStartPositionTimer(
+  internal: internal,
+  intervalMs: intervalMs,
+);
+
+void smtcStopPositionTimer({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcStopPositionTimer(internal: internal);
+
+Future<void> smtcSetSeekable({
+  required SmtcInternal internal,
+  required bool seekable,
+}) => RustLib.instance.api.crateApiApiSmtcSetSeekable(
+  internal: internal,
+  seekable: seekable,
+);
+
+Future<void> smtcUpdateTimelineSimple({
+  required SmtcInternal internal,
+  required PlatformInt64 positionMs,
+  required PlatformInt64 durationMs,
+}) => RustLib.instance.api.crateApiApiSmtcUpdateTimelineSimple(
+  internal: internal,
+  positionMs: positionMs,
+  durationMs: durationMs,
+);
+
+/// Resolves to whether the position was far enough off to be updated.
+Future<bool> smtcReconcilePosition({
+  required SmtcInternal internal,
+  required PlatformInt64 actualMs,
+}) => RustLib.instance.api.crateApiApiSmtcReconcilePosition(
+  internal: internal,
+  actualMs: actualMs,
+);
+
+Future<void> smtcSetElapsed({
+  required SmtcInternal internal,
+  required PlatformInt64 positionMs,
+}) => RustLib.instance.api.crateApiApiSmtcSetElapsed(
+  internal: internal,
+  positionMs: positionMs,
+);
+
+Future<void> smtcUpdatePosition({
+  required SmtcInternal internal,
+  required PlatformInt64 positionMs,
+}) => RustLib.instance.api.crateApiApiSmtcUpdatePosition(
+  internal: internal,
+  positionMs: positionMs,
+);
+
+void smtcMarkPlaybackStart({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcMarkPlaybackStart(internal: internal);
+
+PlatformInt64? smtcElapsedSinceStartMs({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcElapsedSinceStartMs(internal: internal);
+
 Future<void> smtcUpdatePlaybackStatus({
   required SmtcInternal internal,
   required PlaybackStatus status,
@@ -50,6 +494,9 @@ Future<void> smtcUpdatePlaybackStatus({
   status: status,
 );
 
+Future<void> smtcSetBuffering({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcSetBuffering(internal: internal);
+
 Future<void> smtcUpdateShuffle({
   required SmtcInternal internal,
   required bool shuffle,
@@ -66,21 +513,95 @@ Future<void> smtcUpdateRepeatMode({
   repeatMode: repeatMode,
 );
 
+Future<void> smtcUpdateShuffleRepeat({
+  required SmtcInternal internal,
+  required bool shuffle,
+  required RepeatMode repeat,
+}) => RustLib.instance.api.crateApiApiSmtcUpdateShuffleRepeat(
+  internal: internal,
+  shuffle: shuffle,
+  repeat: repeat,
+);
+
 Future<void> smtcEnableSmtc({required SmtcInternal internal}) =>
     RustLib.instance.api.crateApiApiSmtcEnableSmtc(internal: internal);
 
+bool smtcIsEnabled({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcIsEnabled(internal: internal);
+
+/// `System` changes are only noticed when `smtc_is_enabled` is called; see
+/// `SMTCInternal::enabled_change_event`.
+Stream<EnabledChange> smtcEnabledChangeEvent({
+  required SmtcInternal internal,
+}) => RustLib.instance.api.crateApiApiSmtcEnabledChangeEvent(
+  internal: internal,
+);
+
+Stream<bool> smtcRefreshEvent({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcRefreshEvent(internal: internal);
+
+Future<void> smtcReapplyState({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcReapplyState(internal: internal);
+
+/// See `SMTCInternal::refresh`.
+Future<void> smtcRefresh({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcRefresh(internal: internal);
+
+Stream<StateChange> smtcStateChangedEvent({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcStateChangedEvent(internal: internal);
+
 Future<void> smtcDisableSmtc({required SmtcInternal internal}) =>
     RustLib.instance.api.crateApiApiSmtcDisableSmtc(internal: internal);
 
+Future<void> smtcIdle({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcIdle(internal: internal);
+
 Stream<String> smtcButtonPressEvent({required SmtcInternal internal}) =>
     RustLib.instance.api.crateApiApiSmtcButtonPressEvent(internal: internal);
 
+Stream<bool> smtcRecordToggleEvent({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcRecordToggleEvent(internal: internal);
+
+void smtcSetRecording({
+  required SmtcInternal internal,
+  required bool recording,
+}) => RustLib.instance.api.crateApiApiSmtcSetRecording(
+  internal: internal,
+  recording: recording,
+);
+
+bool smtcIsRecording({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcIsRecording(internal: internal);
+
+Stream<int> smtcChannelChangeEvent({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcChannelChangeEvent(internal: internal);
+
+Stream<ButtonPress> smtcButtonPressEventTyped({
+  required SmtcInternal internal,
+}) => RustLib.instance.api.crateApiApiSmtcButtonPressEventTyped(
+  internal: internal,
+);
+
 Stream<PlatformInt64> smtcPositionChangeRequestEvent({
   required SmtcInternal internal,
 }) => RustLib.instance.api.crateApiApiSmtcPositionChangeRequestEvent(
   internal: internal,
 );
 
+/// Each event is the requested position in milliseconds as an 8 byte
+/// little-endian integer.
+Stream<Uint8List> smtcPositionChangeRequestEventPacked({
+  required SmtcInternal internal,
+}) => RustLib.instance.api.crateApiApiSmtcPositionChangeRequestEventPacked(
+  internal: internal,
+);
+
+Stream<PositionChangeRequest> smtcPositionChangeRequestEventTyped({
+  required SmtcInternal internal,
+}) => RustLib.instance.api.crateApiApiSmtcPositionChangeRequestEventTyped(
+  internal: internal,
+);
+
 Stream<bool> smtcShuffleRequestEvent({required SmtcInternal internal}) =>
     RustLib.instance.api.crateApiApiSmtcShuffleRequestEvent(internal: internal);
 
@@ -89,5 +610,46 @@ Stream<String> smtcRepeatModeRequestEvent({required SmtcInternal internal}) =>
       internal: internal,
     );
 
+Stream<SmtcEvent> smtcUnifiedEventStream({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcUnifiedEventStream(internal: internal);
+
+Stream<String> smtcMediaErrorEvent({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcMediaErrorEvent(internal: internal);
+
+Stream<SmtcEvent> smtcRegisterAllEvents({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcRegisterAllEvents(internal: internal);
+
+Stream<SmtcEvent> smtcReplayLastState({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcReplayLastState(internal: internal);
+
+Stream<String> smtcRawEventTrace({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcRawEventTrace(internal: internal);
+
+String smtcDebugDump({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcDebugDump(internal: internal);
+
+BigInt? smtcLastButtonPressMs({
+  required SmtcInternal internal,
+  required SmtcButton button,
+}) => RustLib.instance.api.crateApiApiSmtcLastButtonPressMs(
+  internal: internal,
+  button: button,
+);
+
+BigInt smtcHandlerCount({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcHandlerCount(internal: internal);
+
+HandlerCounts smtcHandlerCounts({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcHandlerCounts(internal: internal);
+
+Future<void> smtcRemoveHandlers({required SmtcInternal internal}) =>
+    RustLib.instance.api.crateApiApiSmtcRemoveHandlers(internal: internal);
+
+// Rust type: RustOpaqueMoi<CancellationToken>
+abstract class CancellationToken implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<EventSubscription>
+abstract class EventSubscription implements RustOpaqueInterface {}
+
 // Rust type: RustOpaqueMoi<SMTCInternal>
 abstract class SmtcInternal implements RustOpaqueInterface {}
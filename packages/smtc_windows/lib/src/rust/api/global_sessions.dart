@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../internal/global_sessions.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+GlobalSessions globalSessionsNew() =>
+    RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsNew();
+
+Future<List<GlobalSessionSnapshot>> globalSessionsList({
+  required GlobalSessions sessions,
+}) => RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsList(
+  sessions: sessions,
+);
+
+Future<GlobalSessionSnapshot?> globalSessionsCurrent({
+  required GlobalSessions sessions,
+}) => RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsCurrent(
+  sessions: sessions,
+);
+
+Stream<List<GlobalSessionSnapshot>> globalSessionsChangedEvent({
+  required GlobalSessions sessions,
+}) => RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsChangedEvent(
+  sessions: sessions,
+);
+
+Stream<GlobalSessionSnapshot?> globalSessionsMediaChangedEvent({
+  required GlobalSessions sessions,
+}) => RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsMediaChangedEvent(
+  sessions: sessions,
+);
+
+Future<bool> globalSessionsTryPlay({required GlobalSessions sessions}) =>
+    RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTryPlay(
+      sessions: sessions,
+    );
+
+Future<bool> globalSessionsTryPause({required GlobalSessions sessions}) =>
+    RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTryPause(
+      sessions: sessions,
+    );
+
+Future<bool> globalSessionsTryTogglePlayPause({
+  required GlobalSessions sessions,
+}) => RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTryTogglePlayPause(
+  sessions: sessions,
+);
+
+Future<bool> globalSessionsTryStop({required GlobalSessions sessions}) =>
+    RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTryStop(
+      sessions: sessions,
+    );
+
+Future<bool> globalSessionsTrySkipNext({required GlobalSessions sessions}) =>
+    RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTrySkipNext(
+      sessions: sessions,
+    );
+
+Future<bool> globalSessionsTrySkipPrevious({
+  required GlobalSessions sessions,
+}) => RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTrySkipPrevious(
+  sessions: sessions,
+);
+
+Future<bool> globalSessionsTryFastForward({required GlobalSessions sessions}) =>
+    RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTryFastForward(
+      sessions: sessions,
+    );
+
+Future<bool> globalSessionsTryRewind({required GlobalSessions sessions}) =>
+    RustLib.instance.api.crateApiGlobalSessionsGlobalSessionsTryRewind(
+      sessions: sessions,
+    );
+
+// Rust type: RustOpaqueMoi<GlobalSessions>
+abstract class GlobalSessions implements RustOpaqueInterface {}
@@ -4,14 +4,23 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/api.dart';
+import 'api/global_sessions.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'internal/config.dart';
+import 'internal/diagnostics.dart';
+import 'internal/events.dart';
+import 'internal/global_sessions.dart';
+import 'internal/handlers.dart';
+import 'internal/init.dart';
 import 'internal/metadata.dart';
 import 'internal/playback_status.dart';
+import 'internal/repeat_mode.dart';
+import 'internal/session_info.dart';
+import 'internal/thumbnail.dart';
 import 'internal/timeline.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
@@ -70,7 +79,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => -1299829031;
+  int get rustContentHash => 1440325745;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -88,26 +97,319 @@ abstract class RustLibApi extends BaseApi {
     required SmtcInternal internal,
   });
 
+  Stream<ButtonPress> crateApiApiSmtcButtonPressEventTyped({
+    required SmtcInternal internal,
+  });
+
+  void crateApiApiSmtcCancel({required CancellationToken token});
+
+  void crateApiApiSmtcCancelSubscription({
+    required EventSubscription subscription,
+  });
+
+  CancellationToken crateApiApiSmtcCancellationToken();
+
+  Capabilities crateApiApiSmtcCapabilities();
+
+  Stream<int> crateApiApiSmtcChannelChangeEvent({
+    required SmtcInternal internal,
+  });
+
+  Future<void> crateApiApiSmtcClearAppMediaId({required SmtcInternal internal});
+
   Future<void> crateApiApiSmtcClearMetadata({required SmtcInternal internal});
 
+  Future<void> crateApiApiSmtcClearMetadataFields({
+    required SmtcInternal internal,
+    required List<MetadataField> fields,
+  });
+
+  void crateApiApiSmtcClearPositionProvider({required SmtcInternal internal});
+
+  void crateApiApiSmtcClearThumbnailResolver({required SmtcInternal internal});
+
+  Future<void> crateApiApiSmtcClearTimeline({required SmtcInternal internal});
+
+  Future<void> crateApiApiSmtcClearTitleOverride({
+    required SmtcInternal internal,
+  });
+
+  bool crateApiApiSmtcConfirmPreview({required SmtcInternal internal});
+
+  SMTCConfig crateApiApiSmtcCurrentConfig({required SmtcInternal internal});
+
+  Future<Uint8List?> crateApiApiSmtcCurrentThumbnailBytes({
+    required SmtcInternal internal,
+  });
+
+  PlaybackTimeline? crateApiApiSmtcCurrentTimeline({
+    required SmtcInternal internal,
+  });
+
+  String crateApiApiSmtcDebugDump({required SmtcInternal internal});
+
+  SMTCConfig crateApiApiSmtcDefaultConfig();
+
+  Diagnostics crateApiApiSmtcDiagnostics();
+
   Future<void> crateApiApiSmtcDisableSmtc({required SmtcInternal internal});
 
+  PlatformInt64? crateApiApiSmtcElapsedSinceStartMs({
+    required SmtcInternal internal,
+  });
+
   Future<void> crateApiApiSmtcEnableSmtc({required SmtcInternal internal});
 
+  Stream<EnabledChange> crateApiApiSmtcEnabledChangeEvent({
+    required SmtcInternal internal,
+  });
+
+  void crateApiApiSmtcFreeze({required SmtcInternal internal});
+
+  BigInt crateApiApiSmtcHandlerCount({required SmtcInternal internal});
+
+  HandlerCounts crateApiApiSmtcHandlerCounts({required SmtcInternal internal});
+
+  Future<void> crateApiApiSmtcIdle({required SmtcInternal internal});
+
+  Future<bool> crateApiApiSmtcIsActiveSession({required SmtcInternal internal});
+
+  bool crateApiApiSmtcIsEnabled({required SmtcInternal internal});
+
+  bool crateApiApiSmtcIsRecording({required SmtcInternal internal});
+
+  BigInt? crateApiApiSmtcLastButtonPressMs({
+    required SmtcInternal internal,
+    required SmtcButton button,
+  });
+
+  void crateApiApiSmtcMarkPlaybackStart({required SmtcInternal internal});
+
+  Stream<String> crateApiApiSmtcMediaErrorEvent({
+    required SmtcInternal internal,
+  });
+
   SmtcInternal crateApiApiSmtcNew({bool? enabled});
 
+  SmtcInternal crateApiApiSmtcNewOnStaThread({
+    required SMTCConfig config,
+    bool? enabled,
+  });
+
+  SmtcInternal crateApiApiSmtcNewWithConfig({
+    required SMTCConfig config,
+    bool? enabled,
+  });
+
+  SmtcInternal crateApiApiSmtcNewWithInit({required SmtcInit init});
+
+  Future<EventSubscription> crateApiApiSmtcOnButton({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(SmtcButton) callback,
+  });
+
+  Future<EventSubscription> crateApiApiSmtcOnPositionChange({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(PositionChangeRequest) callback,
+  });
+
+  Future<EventSubscription> crateApiApiSmtcOnRepeatModeChange({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(String) callback,
+  });
+
+  Future<EventSubscription> crateApiApiSmtcOnShuffleChange({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(bool) callback,
+  });
+
   Stream<PlatformInt64> crateApiApiSmtcPositionChangeRequestEvent({
     required SmtcInternal internal,
   });
 
+  Stream<Uint8List> crateApiApiSmtcPositionChangeRequestEventPacked({
+    required SmtcInternal internal,
+  });
+
+  Stream<PositionChangeRequest> crateApiApiSmtcPositionChangeRequestEventTyped({
+    required SmtcInternal internal,
+  });
+
+  Future<bool> crateApiApiSmtcPrefetchThumbnail({
+    required SmtcInternal internal,
+    required String source,
+  });
+
+  Future<void> crateApiApiSmtcPreviewMetadata({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    required BigInt revertAfterMs,
+  });
+
+  Future<void> crateApiApiSmtcPushTitleOverride({
+    required SmtcInternal internal,
+    required String title,
+  });
+
+  List<MusicMetadata> crateApiApiSmtcQueue({required SmtcInternal internal});
+
+  Stream<String> crateApiApiSmtcRawEventTrace({required SmtcInternal internal});
+
+  Future<void> crateApiApiSmtcReapplyState({required SmtcInternal internal});
+
+  Future<bool> crateApiApiSmtcReconcilePosition({
+    required SmtcInternal internal,
+    required PlatformInt64 actualMs,
+  });
+
+  Stream<bool> crateApiApiSmtcRecordToggleEvent({
+    required SmtcInternal internal,
+  });
+
+  Future<void> crateApiApiSmtcRefresh({required SmtcInternal internal});
+
+  Stream<bool> crateApiApiSmtcRefreshEvent({required SmtcInternal internal});
+
+  Stream<SmtcEvent> crateApiApiSmtcRegisterAllEvents({
+    required SmtcInternal internal,
+  });
+
+  Future<void> crateApiApiSmtcRemoveHandlers({required SmtcInternal internal});
+
   Stream<String> crateApiApiSmtcRepeatModeRequestEvent({
     required SmtcInternal internal,
   });
 
+  Stream<SmtcEvent> crateApiApiSmtcReplayLastState({
+    required SmtcInternal internal,
+  });
+
+  SessionInfo crateApiApiSmtcSessionInfo({required SmtcInternal internal});
+
+  Future<void> crateApiApiSmtcSetBuffering({required SmtcInternal internal});
+
+  Future<void> crateApiApiSmtcSetDuration({
+    required SmtcInternal internal,
+    required PlatformInt64 durationMs,
+  });
+
+  Future<void> crateApiApiSmtcSetElapsed({
+    required SmtcInternal internal,
+    required PlatformInt64 positionMs,
+  });
+
+  void crateApiApiSmtcSetEventThread({
+    required SmtcInternal internal,
+    required bool dedicated,
+  });
+
+  Future<void> crateApiApiSmtcSetPlaceholder({
+    required SmtcInternal internal,
+    required String title,
+    String? subtitle,
+    String? thumbnail,
+  });
+
+  Future<void> crateApiApiSmtcSetPlaybackRate({
+    required SmtcInternal internal,
+    required double rate,
+  });
+
+  Future<void> crateApiApiSmtcSetPositionProvider({
+    required SmtcInternal internal,
+    required FutureOr<PlatformInt64> Function() provider,
+  });
+
+  void crateApiApiSmtcSetQueue({
+    required SmtcInternal internal,
+    required List<MusicMetadata> queue,
+  });
+
+  void crateApiApiSmtcSetRecording({
+    required SmtcInternal internal,
+    required bool recording,
+  });
+
+  Future<void> crateApiApiSmtcSetSeekable({
+    required SmtcInternal internal,
+    required bool seekable,
+  });
+
+  Future<void> crateApiApiSmtcSetSessionIcon({
+    required SmtcInternal internal,
+    required String source,
+  });
+
+  Future<bool> crateApiApiSmtcSetThumbnailAsync({
+    required SmtcInternal internal,
+    required String source,
+  });
+
+  Future<void> crateApiApiSmtcSetThumbnailFromHandle({
+    required SmtcInternal internal,
+    required PlatformInt64 handle,
+  });
+
+  Future<void> crateApiApiSmtcSetThumbnailFromToken({
+    required SmtcInternal internal,
+    required String token,
+  });
+
+  Future<void> crateApiApiSmtcSetThumbnailResolver({
+    required SmtcInternal internal,
+    required FutureOr<Uint8List?> Function(String) resolver,
+  });
+
+  Future<int?> crateApiApiSmtcSetThumbnailWithColor({
+    required SmtcInternal internal,
+    required String source,
+  });
+
+  void crateApiApiSmtcShareThumbnailCache({
+    required SmtcInternal internal,
+    required SmtcInternal other,
+  });
+
   Stream<bool> crateApiApiSmtcShuffleRequestEvent({
     required SmtcInternal internal,
   });
 
+  void crateApiApiSmtcShutdown();
+
+  Future<void> crateApiApiSmtcStartPositionTimer({
+    required SmtcInternal internal,
+    required BigInt intervalMs,
+  });
+
+  Stream<StateChange> crateApiApiSmtcStateChangedEvent({
+    required SmtcInternal internal,
+  });
+
+  void crateApiApiSmtcStopPositionTimer({required SmtcInternal internal});
+
+  List<String> crateApiApiSmtcSupportedThumbnailFormats();
+
+  Future<void> crateApiApiSmtcSwitchSession({
+    required SmtcInternal internal,
+    required String appId,
+    required MusicMetadata metadata,
+  });
+
+  Future<void> crateApiApiSmtcSwitchSessionFull({
+    required SmtcInternal internal,
+    required SmtcInit init,
+  });
+
+  Future<void> crateApiApiSmtcThaw({required SmtcInternal internal});
+
+  Stream<ThumbnailResult> crateApiApiSmtcThumbnailResultEvent({
+    required SmtcInternal internal,
+  });
+
+  Stream<SmtcEvent> crateApiApiSmtcUnifiedEventStream({
+    required SmtcInternal internal,
+  });
+
   Future<void> crateApiApiSmtcUpdateConfig({
     required SmtcInternal internal,
     required SMTCConfig config,
@@ -119,11 +421,48 @@ abstract class RustLibApi extends BaseApi {
     String? appId,
   });
 
+  Future<bool> crateApiApiSmtcUpdateMetadataAsync({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+    required CancellationToken token,
+  });
+
+  Future<void> crateApiApiSmtcUpdateMetadataMap({
+    required SmtcInternal internal,
+    required Map<String, String> fields,
+    String? appId,
+  });
+
+  Future<void> crateApiApiSmtcUpdateMetadataSilently({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+  });
+
+  Future<MetadataReport> crateApiApiSmtcUpdateMetadataVerified({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+  });
+
+  Future<void> crateApiApiSmtcUpdateMetadataWith({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+    required ClearFlags clear,
+  });
+
   Future<void> crateApiApiSmtcUpdatePlaybackStatus({
     required SmtcInternal internal,
     required PlaybackStatus status,
   });
 
+  Future<void> crateApiApiSmtcUpdatePosition({
+    required SmtcInternal internal,
+    required PlatformInt64 positionMs,
+  });
+
   Future<void> crateApiApiSmtcUpdateRepeatMode({
     required SmtcInternal internal,
     required String repeatMode,
@@ -134,11 +473,106 @@ abstract class RustLibApi extends BaseApi {
     required bool shuffle,
   });
 
+  Future<void> crateApiApiSmtcUpdateShuffleRepeat({
+    required SmtcInternal internal,
+    required bool shuffle,
+    required RepeatMode repeat,
+  });
+
   Future<void> crateApiApiSmtcUpdateTimeline({
     required SmtcInternal internal,
     required PlaybackTimeline timeline,
   });
 
+  Future<void> crateApiApiSmtcUpdateTimelineSimple({
+    required SmtcInternal internal,
+    required PlatformInt64 positionMs,
+    required PlatformInt64 durationMs,
+  });
+
+  List<String> crateApiApiSmtcValidateMetadata({
+    required MusicMetadata metadata,
+  });
+
+  Stream<List<GlobalSessionSnapshot>>
+  crateApiGlobalSessionsGlobalSessionsChangedEvent({
+    required GlobalSessions sessions,
+  });
+
+  Future<GlobalSessionSnapshot?> crateApiGlobalSessionsGlobalSessionsCurrent({
+    required GlobalSessions sessions,
+  });
+
+  Future<List<GlobalSessionSnapshot>> crateApiGlobalSessionsGlobalSessionsList({
+    required GlobalSessions sessions,
+  });
+
+  Stream<GlobalSessionSnapshot?>
+  crateApiGlobalSessionsGlobalSessionsMediaChangedEvent({
+    required GlobalSessions sessions,
+  });
+
+  GlobalSessions crateApiGlobalSessionsGlobalSessionsNew();
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryFastForward({
+    required GlobalSessions sessions,
+  });
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryPause({
+    required GlobalSessions sessions,
+  });
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryPlay({
+    required GlobalSessions sessions,
+  });
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryRewind({
+    required GlobalSessions sessions,
+  });
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTrySkipNext({
+    required GlobalSessions sessions,
+  });
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTrySkipPrevious({
+    required GlobalSessions sessions,
+  });
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryStop({
+    required GlobalSessions sessions,
+  });
+
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryTogglePlayPause({
+    required GlobalSessions sessions,
+  });
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CancellationToken;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CancellationToken;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_CancellationTokenPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_EventSubscription;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_EventSubscription;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_EventSubscriptionPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_GlobalSessions;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_GlobalSessions;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_GlobalSessionsPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_SmtcInternal;
 
@@ -222,169 +656,150 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiApiSmtcClearMetadata({required SmtcInternal internal}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+  Stream<ButtonPress> crateApiApiSmtcButtonPressEventTyped({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<ButtonPress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_button_press_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 3,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcButtonPressEventTypedConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
         ),
-        constMeta: kCrateApiApiSmtcClearMetadataConstMeta,
-        argValues: [internal],
-        apiImpl: this,
       ),
     );
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiApiSmtcClearMetadataConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcButtonPressEventTypedConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_clear_metadata",
-        argNames: ["internal"],
+        debugName: "smtc_button_press_event_typed",
+        argNames: ["internal", "sink"],
       );
 
   @override
-  Future<void> crateApiApiSmtcDisableSmtc({required SmtcInternal internal}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiApiSmtcCancel({required CancellationToken token}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
+          sse_encode_RustOpaque_CancellationToken(token, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiSmtcDisableSmtcConstMeta,
-        argValues: [internal],
+        constMeta: kCrateApiApiSmtcCancelConstMeta,
+        argValues: [token],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcDisableSmtcConstMeta => const TaskConstMeta(
-    debugName: "smtc_disable_smtc",
-    argNames: ["internal"],
-  );
+  TaskConstMeta get kCrateApiApiSmtcCancelConstMeta =>
+      const TaskConstMeta(debugName: "smtc_cancel", argNames: ["token"]);
 
   @override
-  Future<void> crateApiApiSmtcEnableSmtc({required SmtcInternal internal}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiApiSmtcCancelSubscription({
+    required EventSubscription subscription,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
+          sse_encode_RustOpaque_EventSubscription(subscription, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiSmtcEnableSmtcConstMeta,
-        argValues: [internal],
+        constMeta: kCrateApiApiSmtcCancelSubscriptionConstMeta,
+        argValues: [subscription],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcEnableSmtcConstMeta => const TaskConstMeta(
-    debugName: "smtc_enable_smtc",
-    argNames: ["internal"],
-  );
+  TaskConstMeta get kCrateApiApiSmtcCancelSubscriptionConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_cancel_subscription",
+        argNames: ["subscription"],
+      );
 
   @override
-  SmtcInternal crateApiApiSmtcNew({bool? enabled}) {
+  CancellationToken crateApiApiSmtcCancellationToken() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_box_autoadd_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_RustOpaque_SMTCInternal,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_RustOpaque_CancellationToken,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiSmtcNewConstMeta,
-        argValues: [enabled],
+        constMeta: kCrateApiApiSmtcCancellationTokenConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcNewConstMeta =>
-      const TaskConstMeta(debugName: "smtc_new", argNames: ["enabled"]);
+  TaskConstMeta get kCrateApiApiSmtcCancellationTokenConstMeta =>
+      const TaskConstMeta(debugName: "smtc_cancellation_token", argNames: []);
 
   @override
-  Stream<PlatformInt64> crateApiApiSmtcPositionChangeRequestEvent({
-    required SmtcInternal internal,
-  }) {
-    final sink = RustStreamSink<PlatformInt64>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-            sse_encode_StreamSink_i_64_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 7,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
-          ),
-          constMeta: kCrateApiApiSmtcPositionChangeRequestEventConstMeta,
-          argValues: [internal, sink],
-          apiImpl: this,
+  Capabilities crateApiApiSmtcCapabilities() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_capabilities,
+          decodeErrorData: null,
         ),
+        constMeta: kCrateApiApiSmtcCapabilitiesConstMeta,
+        argValues: [],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiApiSmtcPositionChangeRequestEventConstMeta =>
-      const TaskConstMeta(
-        debugName: "smtc_position_change_request_event",
-        argNames: ["internal", "sink"],
-      );
+  TaskConstMeta get kCrateApiApiSmtcCapabilitiesConstMeta =>
+      const TaskConstMeta(debugName: "smtc_capabilities", argNames: []);
 
   @override
-  Stream<String> crateApiApiSmtcRepeatModeRequestEvent({
+  Stream<int> crateApiApiSmtcChannelChangeEvent({
     required SmtcInternal internal,
   }) {
-    final sink = RustStreamSink<String>();
+    final sink = RustStreamSink<int>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
             final serializer = SseSerializer(generalizedFrbRustBinding);
             sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-            sse_encode_StreamSink_String_Sse(sink, serializer);
+            sse_encode_StreamSink_i_32_Sse(sink, serializer);
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
@@ -396,45 +811,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             decodeSuccessData: sse_decode_unit,
             decodeErrorData: sse_decode_AnyhowException,
           ),
-          constMeta: kCrateApiApiSmtcRepeatModeRequestEventConstMeta,
-          argValues: [internal, sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiApiSmtcRepeatModeRequestEventConstMeta =>
-      const TaskConstMeta(
-        debugName: "smtc_repeat_mode_request_event",
-        argNames: ["internal", "sink"],
-      );
-
-  @override
-  Stream<bool> crateApiApiSmtcShuffleRequestEvent({
-    required SmtcInternal internal,
-  }) {
-    final sink = RustStreamSink<bool>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-            sse_encode_StreamSink_bool_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 9,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
-          ),
-          constMeta: kCrateApiApiSmtcShuffleRequestEventConstMeta,
+          constMeta: kCrateApiApiSmtcChannelChangeEventConstMeta,
           argValues: [internal, sink],
           apiImpl: this,
         ),
@@ -443,27 +820,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiApiSmtcShuffleRequestEventConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcChannelChangeEventConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_shuffle_request_event",
+        debugName: "smtc_channel_change_event",
         argNames: ["internal", "sink"],
       );
 
   @override
-  Future<void> crateApiApiSmtcUpdateConfig({
+  Future<void> crateApiApiSmtcClearAppMediaId({
     required SmtcInternal internal,
-    required SMTCConfig config,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          sse_encode_box_autoadd_smtc_config(config, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 9,
             port: port_,
           );
         },
@@ -471,36 +846,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiSmtcUpdateConfigConstMeta,
-        argValues: [internal, config],
+        constMeta: kCrateApiApiSmtcClearAppMediaIdConstMeta,
+        argValues: [internal],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcUpdateConfigConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcClearAppMediaIdConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_update_config",
-        argNames: ["internal", "config"],
+        debugName: "smtc_clear_app_media_id",
+        argNames: ["internal"],
       );
 
   @override
-  Future<void> crateApiApiSmtcUpdateMetadata({
-    required SmtcInternal internal,
-    required MusicMetadata metadata,
-    String? appId,
-  }) {
+  Future<void> crateApiApiSmtcClearMetadata({required SmtcInternal internal}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          sse_encode_box_autoadd_music_metadata(metadata, serializer);
-          sse_encode_opt_String(appId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 10,
             port: port_,
           );
         },
@@ -508,34 +877,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiSmtcUpdateMetadataConstMeta,
-        argValues: [internal, metadata, appId],
+        constMeta: kCrateApiApiSmtcClearMetadataConstMeta,
+        argValues: [internal],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcUpdateMetadataConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcClearMetadataConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_update_metadata",
-        argNames: ["internal", "metadata", "appId"],
+        debugName: "smtc_clear_metadata",
+        argNames: ["internal"],
       );
 
   @override
-  Future<void> crateApiApiSmtcUpdatePlaybackStatus({
+  Future<void> crateApiApiSmtcClearMetadataFields({
     required SmtcInternal internal,
-    required PlaybackStatus status,
+    required List<MetadataField> fields,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          sse_encode_playback_status(status, serializer);
+          sse_encode_list_metadata_field(fields, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 11,
             port: port_,
           );
         },
@@ -543,66 +912,79 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiSmtcUpdatePlaybackStatusConstMeta,
-        argValues: [internal, status],
+        constMeta: kCrateApiApiSmtcClearMetadataFieldsConstMeta,
+        argValues: [internal, fields],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcUpdatePlaybackStatusConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcClearMetadataFieldsConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_update_playback_status",
-        argNames: ["internal", "status"],
+        debugName: "smtc_clear_metadata_fields",
+        argNames: ["internal", "fields"],
       );
 
   @override
-  Future<void> crateApiApiSmtcUpdateRepeatMode({
-    required SmtcInternal internal,
-    required String repeatMode,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiApiSmtcClearPositionProvider({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          sse_encode_String(repeatMode, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiSmtcUpdateRepeatModeConstMeta,
-        argValues: [internal, repeatMode],
+        constMeta: kCrateApiApiSmtcClearPositionProviderConstMeta,
+        argValues: [internal],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcUpdateRepeatModeConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcClearPositionProviderConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_update_repeat_mode",
-        argNames: ["internal", "repeatMode"],
+        debugName: "smtc_clear_position_provider",
+        argNames: ["internal"],
       );
 
   @override
-  Future<void> crateApiApiSmtcUpdateShuffle({
-    required SmtcInternal internal,
-    required bool shuffle,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiApiSmtcClearThumbnailResolver({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          sse_encode_bool(shuffle, serializer);
-          pdeCallFfi(
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcClearThumbnailResolverConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcClearThumbnailResolverConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_clear_thumbnail_resolver",
+        argNames: ["internal"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcClearTimeline({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
             funcId: 14,
@@ -613,30 +995,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiSmtcUpdateShuffleConstMeta,
-        argValues: [internal, shuffle],
+        constMeta: kCrateApiApiSmtcClearTimelineConstMeta,
+        argValues: [internal],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcUpdateShuffleConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcClearTimelineConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_update_shuffle",
-        argNames: ["internal", "shuffle"],
+        debugName: "smtc_clear_timeline",
+        argNames: ["internal"],
       );
 
   @override
-  Future<void> crateApiApiSmtcUpdateTimeline({
+  Future<void> crateApiApiSmtcClearTitleOverride({
     required SmtcInternal internal,
-    required PlaybackTimeline timeline,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_RustOpaque_SMTCInternal(internal, serializer);
-          sse_encode_box_autoadd_playback_timeline(timeline, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -648,620 +1028,6883 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiSmtcUpdateTimelineConstMeta,
-        argValues: [internal, timeline],
+        constMeta: kCrateApiApiSmtcClearTitleOverrideConstMeta,
+        argValues: [internal],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSmtcUpdateTimelineConstMeta =>
+  TaskConstMeta get kCrateApiApiSmtcClearTitleOverrideConstMeta =>
       const TaskConstMeta(
-        debugName: "smtc_update_timeline",
-        argNames: ["internal", "timeline"],
+        debugName: "smtc_clear_title_override",
+        argNames: ["internal"],
       );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SmtcInternal =>
-      wire.rust_arc_increment_strong_count_RustOpaque_SMTCInternal;
+  @override
+  bool crateApiApiSmtcConfirmPreview({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcConfirmPreviewConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SmtcInternal =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_SMTCInternal;
+  TaskConstMeta get kCrateApiApiSmtcConfirmPreviewConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_confirm_preview",
+        argNames: ["internal"],
+      );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  SMTCConfig crateApiApiSmtcCurrentConfig({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_smtc_config,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcCurrentConfigConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  SmtcInternal dco_decode_RustOpaque_SMTCInternal(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SmtcInternalImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
+  TaskConstMeta get kCrateApiApiSmtcCurrentConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_current_config",
+        argNames: ["internal"],
+      );
 
-  @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  @override
+  Future<Uint8List?> crateApiApiSmtcCurrentThumbnailBytes({
+    required SmtcInternal internal,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcCurrentThumbnailBytesConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  RustStreamSink<bool> dco_decode_StreamSink_bool_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  TaskConstMeta get kCrateApiApiSmtcCurrentThumbnailBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_current_thumbnail_bytes",
+        argNames: ["internal"],
+      );
 
-  @protected
-  RustStreamSink<PlatformInt64> dco_decode_StreamSink_i_64_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  @override
+  PlaybackTimeline? crateApiApiSmtcCurrentTimeline({
+    required SmtcInternal internal,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_playback_timeline,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcCurrentTimelineConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiApiSmtcCurrentTimelineConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_current_timeline",
+        argNames: ["internal"],
+      );
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  String crateApiApiSmtcDebugDump({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcDebugDumpConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiApiSmtcDebugDumpConstMeta =>
+      const TaskConstMeta(debugName: "smtc_debug_dump", argNames: ["internal"]);
 
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
+  @override
+  SMTCConfig crateApiApiSmtcDefaultConfig() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_smtc_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcDefaultConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  MusicMetadata dco_decode_box_autoadd_music_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_music_metadata(raw);
-  }
+  TaskConstMeta get kCrateApiApiSmtcDefaultConfigConstMeta =>
+      const TaskConstMeta(debugName: "smtc_default_config", argNames: []);
 
-  @protected
-  PlaybackTimeline dco_decode_box_autoadd_playback_timeline(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_playback_timeline(raw);
+  @override
+  Diagnostics crateApiApiSmtcDiagnostics() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_diagnostics,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcDiagnosticsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  SMTCConfig dco_decode_box_autoadd_smtc_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_smtc_config(raw);
-  }
+  TaskConstMeta get kCrateApiApiSmtcDiagnosticsConstMeta =>
+      const TaskConstMeta(debugName: "smtc_diagnostics", argNames: []);
 
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  @override
+  Future<void> crateApiApiSmtcDisableSmtc({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcDisableSmtcConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  TaskConstMeta get kCrateApiApiSmtcDisableSmtcConstMeta => const TaskConstMeta(
+    debugName: "smtc_disable_smtc",
+    argNames: ["internal"],
+  );
+
+  @override
+  PlatformInt64? crateApiApiSmtcElapsedSinceStartMs({
+    required SmtcInternal internal,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcElapsedSinceStartMsConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcElapsedSinceStartMsConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_elapsed_since_start_ms",
+        argNames: ["internal"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcEnableSmtc({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcEnableSmtcConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcEnableSmtcConstMeta => const TaskConstMeta(
+    debugName: "smtc_enable_smtc",
+    argNames: ["internal"],
+  );
+
+  @override
+  Stream<EnabledChange> crateApiApiSmtcEnabledChangeEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<EnabledChange>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_enabled_change_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 26,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiApiSmtcEnabledChangeEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcEnabledChangeEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_enabled_change_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  void crateApiApiSmtcFreeze({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcFreezeConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcFreezeConstMeta =>
+      const TaskConstMeta(debugName: "smtc_freeze", argNames: ["internal"]);
+
+  @override
+  BigInt crateApiApiSmtcHandlerCount({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcHandlerCountConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcHandlerCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_handler_count",
+        argNames: ["internal"],
+      );
+
+  @override
+  HandlerCounts crateApiApiSmtcHandlerCounts({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_handler_counts,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcHandlerCountsConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcHandlerCountsConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_handler_counts",
+        argNames: ["internal"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcIdle({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcIdleConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcIdleConstMeta =>
+      const TaskConstMeta(debugName: "smtc_idle", argNames: ["internal"]);
+
+  @override
+  Future<bool> crateApiApiSmtcIsActiveSession({
+    required SmtcInternal internal,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcIsActiveSessionConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcIsActiveSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_is_active_session",
+        argNames: ["internal"],
+      );
+
+  @override
+  bool crateApiApiSmtcIsEnabled({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcIsEnabledConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcIsEnabledConstMeta =>
+      const TaskConstMeta(debugName: "smtc_is_enabled", argNames: ["internal"]);
+
+  @override
+  bool crateApiApiSmtcIsRecording({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcIsRecordingConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcIsRecordingConstMeta => const TaskConstMeta(
+    debugName: "smtc_is_recording",
+    argNames: ["internal"],
+  );
+
+  @override
+  BigInt? crateApiApiSmtcLastButtonPressMs({
+    required SmtcInternal internal,
+    required SmtcButton button,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_smtc_button(button, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcLastButtonPressMsConstMeta,
+        argValues: [internal, button],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcLastButtonPressMsConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_last_button_press_ms",
+        argNames: ["internal", "button"],
+      );
+
+  @override
+  void crateApiApiSmtcMarkPlaybackStart({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcMarkPlaybackStartConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcMarkPlaybackStartConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_mark_playback_start",
+        argNames: ["internal"],
+      );
+
+  @override
+  Stream<String> crateApiApiSmtcMediaErrorEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 36,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcMediaErrorEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcMediaErrorEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_media_error_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  SmtcInternal crateApiApiSmtcNew({bool? enabled}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_bool(enabled, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_SMTCInternal,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcNewConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcNewConstMeta =>
+      const TaskConstMeta(debugName: "smtc_new", argNames: ["enabled"]);
+
+  @override
+  SmtcInternal crateApiApiSmtcNewOnStaThread({
+    required SMTCConfig config,
+    bool? enabled,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_smtc_config(config, serializer);
+          sse_encode_opt_box_autoadd_bool(enabled, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_SMTCInternal,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcNewOnStaThreadConstMeta,
+        argValues: [config, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcNewOnStaThreadConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_new_on_sta_thread",
+        argNames: ["config", "enabled"],
+      );
+
+  @override
+  SmtcInternal crateApiApiSmtcNewWithConfig({
+    required SMTCConfig config,
+    bool? enabled,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_smtc_config(config, serializer);
+          sse_encode_opt_box_autoadd_bool(enabled, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_SMTCInternal,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcNewWithConfigConstMeta,
+        argValues: [config, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcNewWithConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_new_with_config",
+        argNames: ["config", "enabled"],
+      );
+
+  @override
+  SmtcInternal crateApiApiSmtcNewWithInit({required SmtcInit init}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_smtc_init(init, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_SMTCInternal,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcNewWithInitConstMeta,
+        argValues: [init],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcNewWithInitConstMeta =>
+      const TaskConstMeta(debugName: "smtc_new_with_init", argNames: ["init"]);
+
+  @override
+  Future<EventSubscription> crateApiApiSmtcOnButton({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(SmtcButton) callback,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(
+            callback,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_EventSubscription,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcOnButtonConstMeta,
+        argValues: [internal, callback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcOnButtonConstMeta => const TaskConstMeta(
+    debugName: "smtc_on_button",
+    argNames: ["internal", "callback"],
+  );
+
+  @override
+  Future<EventSubscription> crateApiApiSmtcOnPositionChange({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(PositionChangeRequest) callback,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+            callback,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_EventSubscription,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcOnPositionChangeConstMeta,
+        argValues: [internal, callback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcOnPositionChangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_on_position_change",
+        argNames: ["internal", "callback"],
+      );
+
+  @override
+  Future<EventSubscription> crateApiApiSmtcOnRepeatModeChange({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(String) callback,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+            callback,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_EventSubscription,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcOnRepeatModeChangeConstMeta,
+        argValues: [internal, callback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcOnRepeatModeChangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_on_repeat_mode_change",
+        argNames: ["internal", "callback"],
+      );
+
+  @override
+  Future<EventSubscription> crateApiApiSmtcOnShuffleChange({
+    required SmtcInternal internal,
+    required FutureOr<void> Function(bool) callback,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
+            callback,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_EventSubscription,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcOnShuffleChangeConstMeta,
+        argValues: [internal, callback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcOnShuffleChangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_on_shuffle_change",
+        argNames: ["internal", "callback"],
+      );
+
+  @override
+  Stream<PlatformInt64> crateApiApiSmtcPositionChangeRequestEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<PlatformInt64>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_i_64_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 45,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcPositionChangeRequestEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcPositionChangeRequestEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_position_change_request_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Stream<Uint8List> crateApiApiSmtcPositionChangeRequestEventPacked({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<Uint8List>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 46,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcPositionChangeRequestEventPackedConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcPositionChangeRequestEventPackedConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_position_change_request_event_packed",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Stream<PositionChangeRequest> crateApiApiSmtcPositionChangeRequestEventTyped({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<PositionChangeRequest>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_position_change_request_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 47,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcPositionChangeRequestEventTypedConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcPositionChangeRequestEventTypedConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_position_change_request_event_typed",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Future<bool> crateApiApiSmtcPrefetchThumbnail({
+    required SmtcInternal internal,
+    required String source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcPrefetchThumbnailConstMeta,
+        argValues: [internal, source],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcPrefetchThumbnailConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_prefetch_thumbnail",
+        argNames: ["internal", "source"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcPreviewMetadata({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    required BigInt revertAfterMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          sse_encode_u_64(revertAfterMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcPreviewMetadataConstMeta,
+        argValues: [internal, metadata, revertAfterMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcPreviewMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_preview_metadata",
+        argNames: ["internal", "metadata", "revertAfterMs"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcPushTitleOverride({
+    required SmtcInternal internal,
+    required String title,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(title, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcPushTitleOverrideConstMeta,
+        argValues: [internal, title],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcPushTitleOverrideConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_push_title_override",
+        argNames: ["internal", "title"],
+      );
+
+  @override
+  List<MusicMetadata> crateApiApiSmtcQueue({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_music_metadata,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcQueueConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcQueueConstMeta =>
+      const TaskConstMeta(debugName: "smtc_queue", argNames: ["internal"]);
+
+  @override
+  Stream<String> crateApiApiSmtcRawEventTrace({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 52,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiApiSmtcRawEventTraceConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcRawEventTraceConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_raw_event_trace",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcReapplyState({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcReapplyStateConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcReapplyStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_reapply_state",
+        argNames: ["internal"],
+      );
+
+  @override
+  Future<bool> crateApiApiSmtcReconcilePosition({
+    required SmtcInternal internal,
+    required PlatformInt64 actualMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_i_64(actualMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcReconcilePositionConstMeta,
+        argValues: [internal, actualMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcReconcilePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_reconcile_position",
+        argNames: ["internal", "actualMs"],
+      );
+
+  @override
+  Stream<bool> crateApiApiSmtcRecordToggleEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<bool>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_bool_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 55,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcRecordToggleEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcRecordToggleEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_record_toggle_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcRefresh({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcRefreshConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcRefreshConstMeta =>
+      const TaskConstMeta(debugName: "smtc_refresh", argNames: ["internal"]);
+
+  @override
+  Stream<bool> crateApiApiSmtcRefreshEvent({required SmtcInternal internal}) {
+    final sink = RustStreamSink<bool>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_bool_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 57,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiApiSmtcRefreshEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcRefreshEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_refresh_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Stream<SmtcEvent> crateApiApiSmtcRegisterAllEvents({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<SmtcEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_smtc_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 58,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcRegisterAllEventsConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcRegisterAllEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_register_all_events",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcRemoveHandlers({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcRemoveHandlersConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcRemoveHandlersConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_remove_handlers",
+        argNames: ["internal"],
+      );
+
+  @override
+  Stream<String> crateApiApiSmtcRepeatModeRequestEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 60,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcRepeatModeRequestEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcRepeatModeRequestEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_repeat_mode_request_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Stream<SmtcEvent> crateApiApiSmtcReplayLastState({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<SmtcEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_smtc_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 61,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiApiSmtcReplayLastStateConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcReplayLastStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_replay_last_state",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  SessionInfo crateApiApiSmtcSessionInfo({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_session_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSessionInfoConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSessionInfoConstMeta => const TaskConstMeta(
+    debugName: "smtc_session_info",
+    argNames: ["internal"],
+  );
+
+  @override
+  Future<void> crateApiApiSmtcSetBuffering({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetBufferingConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetBufferingConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_buffering",
+        argNames: ["internal"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetDuration({
+    required SmtcInternal internal,
+    required PlatformInt64 durationMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_i_64(durationMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetDurationConstMeta,
+        argValues: [internal, durationMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetDurationConstMeta => const TaskConstMeta(
+    debugName: "smtc_set_duration",
+    argNames: ["internal", "durationMs"],
+  );
+
+  @override
+  Future<void> crateApiApiSmtcSetElapsed({
+    required SmtcInternal internal,
+    required PlatformInt64 positionMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_i_64(positionMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetElapsedConstMeta,
+        argValues: [internal, positionMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetElapsedConstMeta => const TaskConstMeta(
+    debugName: "smtc_set_elapsed",
+    argNames: ["internal", "positionMs"],
+  );
+
+  @override
+  void crateApiApiSmtcSetEventThread({
+    required SmtcInternal internal,
+    required bool dedicated,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_bool(dedicated, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetEventThreadConstMeta,
+        argValues: [internal, dedicated],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetEventThreadConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_event_thread",
+        argNames: ["internal", "dedicated"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetPlaceholder({
+    required SmtcInternal internal,
+    required String title,
+    String? subtitle,
+    String? thumbnail,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(title, serializer);
+          sse_encode_opt_String(subtitle, serializer);
+          sse_encode_opt_String(thumbnail, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetPlaceholderConstMeta,
+        argValues: [internal, title, subtitle, thumbnail],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetPlaceholderConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_placeholder",
+        argNames: ["internal", "title", "subtitle", "thumbnail"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetPlaybackRate({
+    required SmtcInternal internal,
+    required double rate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_f_64(rate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetPlaybackRateConstMeta,
+        argValues: [internal, rate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetPlaybackRateConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_playback_rate",
+        argNames: ["internal", "rate"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetPositionProvider({
+    required SmtcInternal internal,
+    required FutureOr<PlatformInt64> Function() provider,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_DartFn_Inputs__Output_i_64_AnyhowException(
+            provider,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcSetPositionProviderConstMeta,
+        argValues: [internal, provider],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetPositionProviderConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_position_provider",
+        argNames: ["internal", "provider"],
+      );
+
+  @override
+  void crateApiApiSmtcSetQueue({
+    required SmtcInternal internal,
+    required List<MusicMetadata> queue,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_list_music_metadata(queue, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcSetQueueConstMeta,
+        argValues: [internal, queue],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetQueueConstMeta => const TaskConstMeta(
+    debugName: "smtc_set_queue",
+    argNames: ["internal", "queue"],
+  );
+
+  @override
+  void crateApiApiSmtcSetRecording({
+    required SmtcInternal internal,
+    required bool recording,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_bool(recording, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcSetRecordingConstMeta,
+        argValues: [internal, recording],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_recording",
+        argNames: ["internal", "recording"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetSeekable({
+    required SmtcInternal internal,
+    required bool seekable,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_bool(seekable, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetSeekableConstMeta,
+        argValues: [internal, seekable],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetSeekableConstMeta => const TaskConstMeta(
+    debugName: "smtc_set_seekable",
+    argNames: ["internal", "seekable"],
+  );
+
+  @override
+  Future<void> crateApiApiSmtcSetSessionIcon({
+    required SmtcInternal internal,
+    required String source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetSessionIconConstMeta,
+        argValues: [internal, source],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetSessionIconConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_session_icon",
+        argNames: ["internal", "source"],
+      );
+
+  @override
+  Future<bool> crateApiApiSmtcSetThumbnailAsync({
+    required SmtcInternal internal,
+    required String source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetThumbnailAsyncConstMeta,
+        argValues: [internal, source],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetThumbnailAsyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_thumbnail_async",
+        argNames: ["internal", "source"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetThumbnailFromHandle({
+    required SmtcInternal internal,
+    required PlatformInt64 handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_i_64(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetThumbnailFromHandleConstMeta,
+        argValues: [internal, handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetThumbnailFromHandleConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_thumbnail_from_handle",
+        argNames: ["internal", "handle"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetThumbnailFromToken({
+    required SmtcInternal internal,
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetThumbnailFromTokenConstMeta,
+        argValues: [internal, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetThumbnailFromTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_thumbnail_from_token",
+        argNames: ["internal", "token"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSetThumbnailResolver({
+    required SmtcInternal internal,
+    required FutureOr<Uint8List?> Function(String) resolver,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+            resolver,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcSetThumbnailResolverConstMeta,
+        argValues: [internal, resolver],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetThumbnailResolverConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_thumbnail_resolver",
+        argNames: ["internal", "resolver"],
+      );
+
+  @override
+  Future<int?> crateApiApiSmtcSetThumbnailWithColor({
+    required SmtcInternal internal,
+    required String source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSetThumbnailWithColorConstMeta,
+        argValues: [internal, source],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSetThumbnailWithColorConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_set_thumbnail_with_color",
+        argNames: ["internal", "source"],
+      );
+
+  @override
+  void crateApiApiSmtcShareThumbnailCache({
+    required SmtcInternal internal,
+    required SmtcInternal other,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_RustOpaque_SMTCInternal(other, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcShareThumbnailCacheConstMeta,
+        argValues: [internal, other],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcShareThumbnailCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_share_thumbnail_cache",
+        argNames: ["internal", "other"],
+      );
+
+  @override
+  Stream<bool> crateApiApiSmtcShuffleRequestEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<bool>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_bool_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 80,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcShuffleRequestEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcShuffleRequestEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_shuffle_request_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  void crateApiApiSmtcShutdown() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcShutdownConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcShutdownConstMeta =>
+      const TaskConstMeta(debugName: "smtc_shutdown", argNames: []);
+
+  @override
+  Future<void> crateApiApiSmtcStartPositionTimer({
+    required SmtcInternal internal,
+    required BigInt intervalMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_u_64(intervalMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcStartPositionTimerConstMeta,
+        argValues: [internal, intervalMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcStartPositionTimerConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_start_position_timer",
+        argNames: ["internal", "intervalMs"],
+      );
+
+  @override
+  Stream<StateChange> crateApiApiSmtcStateChangedEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<StateChange>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_state_change_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 83,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiApiSmtcStateChangedEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcStateChangedEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_state_changed_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  void crateApiApiSmtcStopPositionTimer({required SmtcInternal internal}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcStopPositionTimerConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcStopPositionTimerConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_stop_position_timer",
+        argNames: ["internal"],
+      );
+
+  @override
+  List<String> crateApiApiSmtcSupportedThumbnailFormats() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSupportedThumbnailFormatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSupportedThumbnailFormatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_supported_thumbnail_formats",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSwitchSession({
+    required SmtcInternal internal,
+    required String appId,
+    required MusicMetadata metadata,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(appId, serializer);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSwitchSessionConstMeta,
+        argValues: [internal, appId, metadata],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSwitchSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_switch_session",
+        argNames: ["internal", "appId", "metadata"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcSwitchSessionFull({
+    required SmtcInternal internal,
+    required SmtcInit init,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_smtc_init(init, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcSwitchSessionFullConstMeta,
+        argValues: [internal, init],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcSwitchSessionFullConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_switch_session_full",
+        argNames: ["internal", "init"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcThaw({required SmtcInternal internal}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcThawConstMeta,
+        argValues: [internal],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcThawConstMeta =>
+      const TaskConstMeta(debugName: "smtc_thaw", argNames: ["internal"]);
+
+  @override
+  Stream<ThumbnailResult> crateApiApiSmtcThumbnailResultEvent({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<ThumbnailResult>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_thumbnail_result_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 89,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiApiSmtcThumbnailResultEventConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcThumbnailResultEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_thumbnail_result_event",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Stream<SmtcEvent> crateApiApiSmtcUnifiedEventStream({
+    required SmtcInternal internal,
+  }) {
+    final sink = RustStreamSink<SmtcEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+            sse_encode_StreamSink_smtc_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 90,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiSmtcUnifiedEventStreamConstMeta,
+          argValues: [internal, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUnifiedEventStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_unified_event_stream",
+        argNames: ["internal", "sink"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateConfig({
+    required SmtcInternal internal,
+    required SMTCConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_smtc_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateConfigConstMeta,
+        argValues: [internal, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_config",
+        argNames: ["internal", "config"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateMetadata({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          sse_encode_opt_String(appId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateMetadataConstMeta,
+        argValues: [internal, metadata, appId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_metadata",
+        argNames: ["internal", "metadata", "appId"],
+      );
+
+  @override
+  Future<bool> crateApiApiSmtcUpdateMetadataAsync({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+    required CancellationToken token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          sse_encode_opt_String(appId, serializer);
+          sse_encode_RustOpaque_CancellationToken(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateMetadataAsyncConstMeta,
+        argValues: [internal, metadata, appId, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateMetadataAsyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_metadata_async",
+        argNames: ["internal", "metadata", "appId", "token"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateMetadataMap({
+    required SmtcInternal internal,
+    required Map<String, String> fields,
+    String? appId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_Map_String_String_None(fields, serializer);
+          sse_encode_opt_String(appId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateMetadataMapConstMeta,
+        argValues: [internal, fields, appId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateMetadataMapConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_metadata_map",
+        argNames: ["internal", "fields", "appId"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateMetadataSilently({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          sse_encode_opt_String(appId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateMetadataSilentlyConstMeta,
+        argValues: [internal, metadata, appId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateMetadataSilentlyConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_metadata_silently",
+        argNames: ["internal", "metadata", "appId"],
+      );
+
+  @override
+  Future<MetadataReport> crateApiApiSmtcUpdateMetadataVerified({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          sse_encode_opt_String(appId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_metadata_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateMetadataVerifiedConstMeta,
+        argValues: [internal, metadata, appId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateMetadataVerifiedConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_metadata_verified",
+        argNames: ["internal", "metadata", "appId"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateMetadataWith({
+    required SmtcInternal internal,
+    required MusicMetadata metadata,
+    String? appId,
+    required ClearFlags clear,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          sse_encode_opt_String(appId, serializer);
+          sse_encode_box_autoadd_clear_flags(clear, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateMetadataWithConstMeta,
+        argValues: [internal, metadata, appId, clear],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateMetadataWithConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_metadata_with",
+        argNames: ["internal", "metadata", "appId", "clear"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdatePlaybackStatus({
+    required SmtcInternal internal,
+    required PlaybackStatus status,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_playback_status(status, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdatePlaybackStatusConstMeta,
+        argValues: [internal, status],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdatePlaybackStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_playback_status",
+        argNames: ["internal", "status"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdatePosition({
+    required SmtcInternal internal,
+    required PlatformInt64 positionMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_i_64(positionMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdatePositionConstMeta,
+        argValues: [internal, positionMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdatePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_position",
+        argNames: ["internal", "positionMs"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateRepeatMode({
+    required SmtcInternal internal,
+    required String repeatMode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_String(repeatMode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateRepeatModeConstMeta,
+        argValues: [internal, repeatMode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateRepeatModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_repeat_mode",
+        argNames: ["internal", "repeatMode"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateShuffle({
+    required SmtcInternal internal,
+    required bool shuffle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_bool(shuffle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateShuffleConstMeta,
+        argValues: [internal, shuffle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateShuffleConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_shuffle",
+        argNames: ["internal", "shuffle"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateShuffleRepeat({
+    required SmtcInternal internal,
+    required bool shuffle,
+    required RepeatMode repeat,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_bool(shuffle, serializer);
+          sse_encode_repeat_mode(repeat, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateShuffleRepeatConstMeta,
+        argValues: [internal, shuffle, repeat],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateShuffleRepeatConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_shuffle_repeat",
+        argNames: ["internal", "shuffle", "repeat"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateTimeline({
+    required SmtcInternal internal,
+    required PlaybackTimeline timeline,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_box_autoadd_playback_timeline(timeline, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateTimelineConstMeta,
+        argValues: [internal, timeline],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateTimelineConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_timeline",
+        argNames: ["internal", "timeline"],
+      );
+
+  @override
+  Future<void> crateApiApiSmtcUpdateTimelineSimple({
+    required SmtcInternal internal,
+    required PlatformInt64 positionMs,
+    required PlatformInt64 durationMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_SMTCInternal(internal, serializer);
+          sse_encode_i_64(positionMs, serializer);
+          sse_encode_i_64(durationMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiSmtcUpdateTimelineSimpleConstMeta,
+        argValues: [internal, positionMs, durationMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcUpdateTimelineSimpleConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_update_timeline_simple",
+        argNames: ["internal", "positionMs", "durationMs"],
+      );
+
+  @override
+  List<String> crateApiApiSmtcValidateMetadata({
+    required MusicMetadata metadata,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_music_metadata(metadata, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiSmtcValidateMetadataConstMeta,
+        argValues: [metadata],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiSmtcValidateMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "smtc_validate_metadata",
+        argNames: ["metadata"],
+      );
+
+  @override
+  Stream<List<GlobalSessionSnapshot>>
+  crateApiGlobalSessionsGlobalSessionsChangedEvent({
+    required GlobalSessions sessions,
+  }) {
+    final sink = RustStreamSink<List<GlobalSessionSnapshot>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+            sse_encode_StreamSink_list_global_session_snapshot_Sse(
+              sink,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 106,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiGlobalSessionsGlobalSessionsChangedEventConstMeta,
+          argValues: [sessions, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsChangedEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_changed_event",
+        argNames: ["sessions", "sink"],
+      );
+
+  @override
+  Future<GlobalSessionSnapshot?> crateApiGlobalSessionsGlobalSessionsCurrent({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_global_session_snapshot,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsCurrentConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsCurrentConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_current",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<List<GlobalSessionSnapshot>> crateApiGlobalSessionsGlobalSessionsList({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_global_session_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsListConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsListConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_list",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Stream<GlobalSessionSnapshot?>
+  crateApiGlobalSessionsGlobalSessionsMediaChangedEvent({
+    required GlobalSessions sessions,
+  }) {
+    final sink = RustStreamSink<GlobalSessionSnapshot?>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+            sse_encode_StreamSink_opt_box_autoadd_global_session_snapshot_Sse(
+              sink,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 109,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiGlobalSessionsGlobalSessionsMediaChangedEventConstMeta,
+          argValues: [sessions, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsMediaChangedEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_media_changed_event",
+        argNames: ["sessions", "sink"],
+      );
+
+  @override
+  GlobalSessions crateApiGlobalSessionsGlobalSessionsNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_RustOpaque_GlobalSessions,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsNewConstMeta =>
+      const TaskConstMeta(debugName: "global_sessions_new", argNames: []);
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryFastForward({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTryFastForwardConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTryFastForwardConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_fast_forward",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryPause({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTryPauseConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTryPauseConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_pause",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryPlay({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTryPlayConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTryPlayConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_play",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryRewind({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTryRewindConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTryRewindConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_rewind",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTrySkipNext({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTrySkipNextConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTrySkipNextConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_skip_next",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTrySkipPrevious({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTrySkipPreviousConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTrySkipPreviousConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_skip_previous",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryStop({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTryStopConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTryStopConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_stop",
+        argNames: ["sessions"],
+      );
+
+  @override
+  Future<bool> crateApiGlobalSessionsGlobalSessionsTryTogglePlayPause({
+    required GlobalSessions sessions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_RustOpaque_GlobalSessions(sessions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGlobalSessionsGlobalSessionsTryTogglePlayPauseConstMeta,
+        argValues: [sessions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGlobalSessionsGlobalSessionsTryTogglePlayPauseConstMeta =>
+      const TaskConstMeta(
+        debugName: "global_sessions_try_toggle_play_pause",
+        argNames: ["sessions"],
+      );
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List?> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<Uint8List?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_list_prim_u_8_strict(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs__Output_i_64_AnyhowException(
+    FutureOr<PlatformInt64> Function() raw,
+  ) {
+    return (callId) async {
+      Box<PlatformInt64>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw());
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_i_64(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
+    FutureOr<void> Function(bool) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_bool(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+    FutureOr<void> Function(PositionChangeRequest) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_position_change_request(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(
+    FutureOr<void> Function(SmtcButton) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_smtc_button(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CancellationToken =>
+      wire.rust_arc_increment_strong_count_RustOpaque_CancellationToken;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CancellationToken =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_CancellationToken;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_EventSubscription =>
+      wire.rust_arc_increment_strong_count_RustOpaque_EventSubscription;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_EventSubscription =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_EventSubscription;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_GlobalSessions =>
+      wire.rust_arc_increment_strong_count_RustOpaque_GlobalSessions;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_GlobalSessions =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_GlobalSessions;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SmtcInternal =>
+      wire.rust_arc_increment_strong_count_RustOpaque_SMTCInternal;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SmtcInternal =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_SMTCInternal;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  FutureOr<Uint8List?> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<PlatformInt64> Function()
+  dco_decode_DartFn_Inputs__Output_i_64_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(bool)
+  dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(PositionChangeRequest)
+  dco_decode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(SmtcButton)
+  dco_decode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return decodeDartOpaque(raw, generalizedFrbRustBinding);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  CancellationToken dco_decode_RustOpaque_CancellationToken(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  EventSubscription dco_decode_RustOpaque_EventSubscription(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EventSubscriptionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  GlobalSessions dco_decode_RustOpaque_GlobalSessions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GlobalSessionsImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SmtcInternal dco_decode_RustOpaque_SMTCInternal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SmtcInternalImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<bool> dco_decode_StreamSink_bool_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ButtonPress> dco_decode_StreamSink_button_press_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<EnabledChange> dco_decode_StreamSink_enabled_change_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<int> dco_decode_StreamSink_i_32_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PlatformInt64> dco_decode_StreamSink_i_64_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<GlobalSessionSnapshot>>
+  dco_decode_StreamSink_list_global_session_snapshot_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<GlobalSessionSnapshot?>
+  dco_decode_StreamSink_opt_box_autoadd_global_session_snapshot_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PositionChangeRequest>
+  dco_decode_StreamSink_position_change_request_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<SmtcEvent> dco_decode_StreamSink_smtc_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<StateChange> dco_decode_StreamSink_state_change_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ThumbnailResult> dco_decode_StreamSink_thumbnail_result_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ClearFlags dco_decode_box_autoadd_clear_flags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_clear_flags(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  GlobalSessionSnapshot dco_decode_box_autoadd_global_session_snapshot(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_global_session_snapshot(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  MusicMetadata dco_decode_box_autoadd_music_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_music_metadata(raw);
+  }
+
+  @protected
+  PlaybackStatus dco_decode_box_autoadd_playback_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_playback_status(raw);
+  }
+
+  @protected
+  PlaybackTimeline dco_decode_box_autoadd_playback_timeline(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_playback_timeline(raw);
+  }
+
+  @protected
+  PositionChangeRequest dco_decode_box_autoadd_position_change_request(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_position_change_request(raw);
+  }
+
+  @protected
+  RepeatMode dco_decode_box_autoadd_repeat_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_repeat_mode(raw);
+  }
+
+  @protected
+  SMTCConfig dco_decode_box_autoadd_smtc_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_smtc_config(raw);
+  }
+
+  @protected
+  SmtcInit dco_decode_box_autoadd_smtc_init(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_smtc_init(raw);
+  }
+
+  @protected
+  StateSnapshot dco_decode_box_autoadd_state_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_state_snapshot(raw);
+  }
+
+  @protected
+  ThumbnailError dco_decode_box_autoadd_thumbnail_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_thumbnail_error(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  ButtonPress dco_decode_button_press(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ButtonPress(
+      button: dco_decode_smtc_button(arr[0]),
+      source: dco_decode_button_source(arr[1]),
+    );
+  }
+
+  @protected
+  ButtonSource dco_decode_button_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ButtonSource.values[raw as int];
+  }
+
+  @protected
+  Capabilities dco_decode_capabilities(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Capabilities(
+      timeline: dco_decode_bool(arr[0]),
+      shuffle: dco_decode_bool(arr[1]),
+      repeatMode: dco_decode_bool(arr[2]),
+      playbackRate: dco_decode_bool(arr[3]),
+      globalSessions: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ClearFlags dco_decode_clear_flags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ClearFlags(field0: dco_decode_u_32(arr[0]));
+  }
+
+  @protected
+  Diagnostics dco_decode_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Diagnostics(
+      crateVersion: dco_decode_String(arr[0]),
+      osVersion: dco_decode_opt_String(arr[1]),
+      osBuild: dco_decode_u_32(arr[2]),
+      capabilities: dco_decode_capabilities(arr[3]),
+    );
+  }
+
+  @protected
+  EnabledChange dco_decode_enabled_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EnabledChange(
+      enabled: dco_decode_bool(arr[0]),
+      source: dco_decode_enabled_change_source(arr[1]),
+    );
+  }
+
+  @protected
+  EnabledChangeSource dco_decode_enabled_change_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EnabledChangeSource.values[raw as int];
+  }
+
+  @protected
+  EventSelection dco_decode_event_selection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return EventSelection(field0: dco_decode_u_32(arr[0]));
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FieldMismatch dco_decode_field_mismatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FieldMismatch(
+      field: dco_decode_String(arr[0]),
+      expected: dco_decode_String(arr[1]),
+      actual: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  GlobalSessionSnapshot dco_decode_global_session_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return GlobalSessionSnapshot(
+      sourceAppId: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      artist: dco_decode_opt_String(arr[2]),
+      album: dco_decode_opt_String(arr[3]),
+      albumArtist: dco_decode_opt_String(arr[4]),
+      playbackStatus: dco_decode_opt_box_autoadd_playback_status(arr[5]),
+    );
+  }
+
+  @protected
+  HandlerCounts dco_decode_handler_counts(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HandlerCounts(
+      buttonPressed: dco_decode_usize(arr[0]),
+      positionChangeRequest: dco_decode_usize(arr[1]),
+      shuffleRequest: dco_decode_usize(arr[2]),
+      repeatModeRequest: dco_decode_usize(arr[3]),
+      mediaFailed: dco_decode_usize(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<FieldMismatch> dco_decode_list_field_mismatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_mismatch).toList();
+  }
+
+  @protected
+  List<GlobalSessionSnapshot> dco_decode_list_global_session_snapshot(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_global_session_snapshot).toList();
+  }
+
+  @protected
+  List<MetadataField> dco_decode_list_metadata_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_metadata_field).toList();
+  }
+
+  @protected
+  List<MusicMetadata> dco_decode_list_music_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_music_metadata).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<RepeatMode> dco_decode_list_repeat_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_repeat_mode).toList();
+  }
+
+  @protected
+  List<ThumbnailScheme> dco_decode_list_thumbnail_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_thumbnail_scheme).toList();
+  }
+
+  @protected
+  MediaType dco_decode_media_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MediaType.values[raw as int];
+  }
+
+  @protected
+  MetadataField dco_decode_metadata_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MetadataField.values[raw as int];
+  }
+
+  @protected
+  MetadataReport dco_decode_metadata_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return MetadataReport(mismatches: dco_decode_list_field_mismatch(arr[0]));
+  }
+
+  @protected
+  MusicMetadata dco_decode_music_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return MusicMetadata(
+      title: dco_decode_opt_String(arr[0]),
+      artist: dco_decode_opt_String(arr[1]),
+      album: dco_decode_opt_String(arr[2]),
+      albumArtist: dco_decode_opt_String(arr[3]),
+      thumbnail: dco_decode_list_String(arr[4]),
+      genres: dco_decode_list_String(arr[5]),
+      trackNumber: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      mediaType: dco_decode_media_type(arr[7]),
+      titleLocale: dco_decode_opt_String(arr[8]),
+      artistLocale: dco_decode_opt_String(arr[9]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  GlobalSessionSnapshot? dco_decode_opt_box_autoadd_global_session_snapshot(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_global_session_snapshot(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  MusicMetadata? dco_decode_opt_box_autoadd_music_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_music_metadata(raw);
+  }
+
+  @protected
+  PlaybackStatus? dco_decode_opt_box_autoadd_playback_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_playback_status(raw);
+  }
+
+  @protected
+  PlaybackTimeline? dco_decode_opt_box_autoadd_playback_timeline(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_playback_timeline(raw);
+  }
+
+  @protected
+  RepeatMode? dco_decode_opt_box_autoadd_repeat_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_repeat_mode(raw);
+  }
+
+  @protected
+  SMTCConfig? dco_decode_opt_box_autoadd_smtc_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_smtc_config(raw);
+  }
+
+  @protected
+  ThumbnailError? dco_decode_opt_box_autoadd_thumbnail_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_thumbnail_error(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  PlaybackStatus dco_decode_playback_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PlaybackStatus.values[raw as int];
+  }
+
+  @protected
+  PlaybackTimeline dco_decode_playback_timeline(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PlaybackTimeline(
+      startTimeMs: dco_decode_i_64(arr[0]),
+      endTimeMs: dco_decode_i_64(arr[1]),
+      positionMs: dco_decode_i_64(arr[2]),
+      minSeekTimeMs: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      maxSeekTimeMs: dco_decode_opt_box_autoadd_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  PositionChangeRequest dco_decode_position_change_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PositionChangeRequest(
+      positionMs: dco_decode_i_64(arr[0]),
+      ticks: dco_decode_i_64(arr[1]),
+      playbackRate: dco_decode_opt_box_autoadd_f_64(arr[2]),
+      outOfRange: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  RepeatMode dco_decode_repeat_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RepeatMode.values[raw as int];
+  }
+
+  @protected
+  SessionInfo dco_decode_session_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SessionInfo(
+      appMediaId: dco_decode_opt_String(arr[0]),
+      enabled: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  SmtcButton dco_decode_smtc_button(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SmtcButton.values[raw as int];
+  }
+
+  @protected
+  SMTCConfig dco_decode_smtc_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 36)
+      throw Exception('unexpected arr length: expect 36 but see ${arr.length}');
+    return SMTCConfig(
+      playEnabled: dco_decode_bool(arr[0]),
+      pauseEnabled: dco_decode_bool(arr[1]),
+      stopEnabled: dco_decode_bool(arr[2]),
+      nextEnabled: dco_decode_bool(arr[3]),
+      prevEnabled: dco_decode_bool(arr[4]),
+      fastForwardEnabled: dco_decode_bool(arr[5]),
+      rewindEnabled: dco_decode_bool(arr[6]),
+      channelUpEnabled: dco_decode_bool(arr[7]),
+      channelDownEnabled: dco_decode_bool(arr[8]),
+      thumbnailsEnabled: dco_decode_bool(arr[9]),
+      mirrorPlaybackStatus: dco_decode_bool(arr[10]),
+      strictMetadata: dco_decode_bool(arr[11]),
+      optimisticSeek: dco_decode_bool(arr[12]),
+      sanitizeMetadata: dco_decode_bool(arr[13]),
+      transcodeThumbnails: dco_decode_bool(arr[14]),
+      thumbnailContentType: dco_decode_opt_String(arr[15]),
+      allowedThumbnailSchemes: dco_decode_list_thumbnail_scheme(arr[16]),
+      thumbnailHeaders: dco_decode_list_record_string_string(arr[17]),
+      recordAsFavorite: dco_decode_bool(arr[18]),
+      buttonDebounceMs: dco_decode_u_64(arr[19]),
+      eventCoalesceMs: dco_decode_u_64(arr[20]),
+      autoDisableAfterStopMs: dco_decode_u_64(arr[21]),
+      thumbnailWorkers: dco_decode_usize(arr[22]),
+      allowedRepeatModes: dco_decode_list_repeat_mode(arr[23]),
+      ignoreDisabledButtons: dco_decode_bool(arr[24]),
+      recordAsToggle: dco_decode_bool(arr[25]),
+      thumbnailBudgetMs: dco_decode_u_64(arr[26]),
+      maxThumbnailBytes: dco_decode_u_64(arr[27]),
+      thumbnailMode: dco_decode_thumbnail_mode(arr[28]),
+      thumbnailTransition: dco_decode_thumbnail_transition(arr[29]),
+      defaultMetadata: dco_decode_opt_box_autoadd_music_metadata(arr[30]),
+      traceRawEvents: dco_decode_bool(arr[31]),
+      reapplyOnRefresh: dco_decode_bool(arr[32]),
+      reconcileThresholdMs: dco_decode_u_64(arr[33]),
+      dryRun: dco_decode_bool(arr[34]),
+      events: dco_decode_event_selection(arr[35]),
+    );
+  }
+
+  @protected
+  SmtcEvent dco_decode_smtc_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SmtcEvent(
+      kind: dco_decode_smtc_event_kind(arr[0]),
+      timestampMs: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  SmtcEventKind dco_decode_smtc_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SmtcEventKind_Button(dco_decode_smtc_button(raw[1]));
+      case 1:
+        return SmtcEventKind_PositionChangeRequest(
+          dco_decode_box_autoadd_position_change_request(raw[1]),
+        );
+      case 2:
+        return SmtcEventKind_ShuffleRequest(dco_decode_bool(raw[1]));
+      case 3:
+        return SmtcEventKind_RepeatModeRequest(dco_decode_String(raw[1]));
+      case 4:
+        return SmtcEventKind_Snapshot(
+          dco_decode_box_autoadd_state_snapshot(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SmtcInit dco_decode_smtc_init(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SmtcInit(
+      config: dco_decode_opt_box_autoadd_smtc_config(arr[0]),
+      metadata: dco_decode_opt_box_autoadd_music_metadata(arr[1]),
+      appId: dco_decode_opt_String(arr[2]),
+      timeline: dco_decode_opt_box_autoadd_playback_timeline(arr[3]),
+      status: dco_decode_opt_box_autoadd_playback_status(arr[4]),
+      enabled: dco_decode_opt_box_autoadd_bool(arr[5]),
+    );
+  }
+
+  @protected
+  StateChange dco_decode_state_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return StateChange(
+      metadata: dco_decode_opt_box_autoadd_music_metadata(arr[0]),
+      status: dco_decode_opt_box_autoadd_playback_status(arr[1]),
+      timeline: dco_decode_opt_box_autoadd_playback_timeline(arr[2]),
+      shuffle: dco_decode_opt_box_autoadd_bool(arr[3]),
+      repeatMode: dco_decode_opt_box_autoadd_repeat_mode(arr[4]),
+    );
+  }
+
+  @protected
+  StateSnapshot dco_decode_state_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StateSnapshot(
+      metadata: dco_decode_opt_box_autoadd_music_metadata(arr[0]),
+      status: dco_decode_opt_box_autoadd_playback_status(arr[1]),
+      timeline: dco_decode_opt_box_autoadd_playback_timeline(arr[2]),
+    );
+  }
+
+  @protected
+  ThumbnailError dco_decode_thumbnail_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ThumbnailError_SchemeNotAllowed(
+          dco_decode_thumbnail_scheme(raw[1]),
+        );
+      case 1:
+        return ThumbnailError_FileNotFound(dco_decode_String(raw[1]));
+      case 2:
+        return ThumbnailError_NetworkTimeout(dco_decode_String(raw[1]));
+      case 3:
+        return ThumbnailError_FetchFailed(dco_decode_String(raw[1]));
+      case 4:
+        return ThumbnailError_DecodeFailed(dco_decode_String(raw[1]));
+      case 5:
+        return ThumbnailError_StreamCreateFailed(dco_decode_String(raw[1]));
+      case 6:
+        return ThumbnailError_InvalidToken(dco_decode_String(raw[1]));
+      case 7:
+        return ThumbnailError_StaleToken(dco_decode_String(raw[1]));
+      case 8:
+        return ThumbnailError_TooLarge(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ThumbnailMode dco_decode_thumbnail_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ThumbnailMode.values[raw as int];
+  }
+
+  @protected
+  ThumbnailResult dco_decode_thumbnail_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ThumbnailResult(
+      source: dco_decode_String(arr[0]),
+      error: dco_decode_opt_box_autoadd_thumbnail_error(arr[1]),
+      fromCache: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  ThumbnailScheme dco_decode_thumbnail_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ThumbnailScheme.values[raw as int];
+  }
+
+  @protected
+  ThumbnailTransition dco_decode_thumbnail_transition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ThumbnailTransition.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  FutureOr<Uint8List?> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  FutureOr<void> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  FutureOr<PlatformInt64> Function()
+  sse_decode_DartFn_Inputs__Output_i_64_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  FutureOr<void> Function(bool)
+  sse_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  FutureOr<void> Function(PositionChangeRequest)
+  sse_decode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  FutureOr<void> Function(SmtcButton)
+  sse_decode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_usize(deserializer);
+    return decodeDartOpaque(inner, generalizedFrbRustBinding);
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  CancellationToken sse_decode_RustOpaque_CancellationToken(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CancellationTokenImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  EventSubscription sse_decode_RustOpaque_EventSubscription(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return EventSubscriptionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  GlobalSessions sse_decode_RustOpaque_GlobalSessions(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return GlobalSessionsImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SmtcInternal sse_decode_RustOpaque_SMTCInternal(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SmtcInternalImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<bool> sse_decode_StreamSink_bool_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ButtonPress> sse_decode_StreamSink_button_press_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<EnabledChange> sse_decode_StreamSink_enabled_change_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<int> sse_decode_StreamSink_i_32_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<PlatformInt64> sse_decode_StreamSink_i_64_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<GlobalSessionSnapshot>>
+  sse_decode_StreamSink_list_global_session_snapshot_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<GlobalSessionSnapshot?>
+  sse_decode_StreamSink_opt_box_autoadd_global_session_snapshot_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<PositionChangeRequest>
+  sse_decode_StreamSink_position_change_request_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<SmtcEvent> sse_decode_StreamSink_smtc_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<StateChange> sse_decode_StreamSink_state_change_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ThumbnailResult> sse_decode_StreamSink_thumbnail_result_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  ClearFlags sse_decode_box_autoadd_clear_flags(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_clear_flags(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  GlobalSessionSnapshot sse_decode_box_autoadd_global_session_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_global_session_snapshot(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  MusicMetadata sse_decode_box_autoadd_music_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_music_metadata(deserializer));
+  }
+
+  @protected
+  PlaybackStatus sse_decode_box_autoadd_playback_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_playback_status(deserializer));
+  }
+
+  @protected
+  PlaybackTimeline sse_decode_box_autoadd_playback_timeline(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_playback_timeline(deserializer));
+  }
+
+  @protected
+  PositionChangeRequest sse_decode_box_autoadd_position_change_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_position_change_request(deserializer));
+  }
+
+  @protected
+  RepeatMode sse_decode_box_autoadd_repeat_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_repeat_mode(deserializer));
+  }
+
+  @protected
+  SMTCConfig sse_decode_box_autoadd_smtc_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_smtc_config(deserializer));
+  }
+
+  @protected
+  SmtcInit sse_decode_box_autoadd_smtc_init(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_smtc_init(deserializer));
+  }
+
+  @protected
+  StateSnapshot sse_decode_box_autoadd_state_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_state_snapshot(deserializer));
+  }
+
+  @protected
+  ThumbnailError sse_decode_box_autoadd_thumbnail_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_thumbnail_error(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  ButtonPress sse_decode_button_press(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_button = sse_decode_smtc_button(deserializer);
+    var var_source = sse_decode_button_source(deserializer);
+    return ButtonPress(button: var_button, source: var_source);
+  }
+
+  @protected
+  ButtonSource sse_decode_button_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ButtonSource.values[inner];
+  }
+
+  @protected
+  Capabilities sse_decode_capabilities(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeline = sse_decode_bool(deserializer);
+    var var_shuffle = sse_decode_bool(deserializer);
+    var var_repeatMode = sse_decode_bool(deserializer);
+    var var_playbackRate = sse_decode_bool(deserializer);
+    var var_globalSessions = sse_decode_bool(deserializer);
+    return Capabilities(
+      timeline: var_timeline,
+      shuffle: var_shuffle,
+      repeatMode: var_repeatMode,
+      playbackRate: var_playbackRate,
+      globalSessions: var_globalSessions,
+    );
+  }
+
+  @protected
+  ClearFlags sse_decode_clear_flags(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_32(deserializer);
+    return ClearFlags(field0: var_field0);
+  }
+
+  @protected
+  Diagnostics sse_decode_diagnostics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_crateVersion = sse_decode_String(deserializer);
+    var var_osVersion = sse_decode_opt_String(deserializer);
+    var var_osBuild = sse_decode_u_32(deserializer);
+    var var_capabilities = sse_decode_capabilities(deserializer);
+    return Diagnostics(
+      crateVersion: var_crateVersion,
+      osVersion: var_osVersion,
+      osBuild: var_osBuild,
+      capabilities: var_capabilities,
+    );
+  }
+
+  @protected
+  EnabledChange sse_decode_enabled_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_source = sse_decode_enabled_change_source(deserializer);
+    return EnabledChange(enabled: var_enabled, source: var_source);
+  }
+
+  @protected
+  EnabledChangeSource sse_decode_enabled_change_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EnabledChangeSource.values[inner];
+  }
+
+  @protected
+  EventSelection sse_decode_event_selection(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_32(deserializer);
+    return EventSelection(field0: var_field0);
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FieldMismatch sse_decode_field_mismatch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_String(deserializer);
+    var var_expected = sse_decode_String(deserializer);
+    var var_actual = sse_decode_String(deserializer);
+    return FieldMismatch(
+      field: var_field,
+      expected: var_expected,
+      actual: var_actual,
+    );
+  }
+
+  @protected
+  GlobalSessionSnapshot sse_decode_global_session_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sourceAppId = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_albumArtist = sse_decode_opt_String(deserializer);
+    var var_playbackStatus = sse_decode_opt_box_autoadd_playback_status(
+      deserializer,
+    );
+    return GlobalSessionSnapshot(
+      sourceAppId: var_sourceAppId,
+      title: var_title,
+      artist: var_artist,
+      album: var_album,
+      albumArtist: var_albumArtist,
+      playbackStatus: var_playbackStatus,
+    );
+  }
+
+  @protected
+  HandlerCounts sse_decode_handler_counts(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_buttonPressed = sse_decode_usize(deserializer);
+    var var_positionChangeRequest = sse_decode_usize(deserializer);
+    var var_shuffleRequest = sse_decode_usize(deserializer);
+    var var_repeatModeRequest = sse_decode_usize(deserializer);
+    var var_mediaFailed = sse_decode_usize(deserializer);
+    return HandlerCounts(
+      buttonPressed: var_buttonPressed,
+      positionChangeRequest: var_positionChangeRequest,
+      shuffleRequest: var_shuffleRequest,
+      repeatModeRequest: var_repeatModeRequest,
+      mediaFailed: var_mediaFailed,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldMismatch> sse_decode_list_field_mismatch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldMismatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_mismatch(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GlobalSessionSnapshot> sse_decode_list_global_session_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GlobalSessionSnapshot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_global_session_snapshot(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MetadataField> sse_decode_list_metadata_field(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MetadataField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_metadata_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MusicMetadata> sse_decode_list_music_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MusicMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_music_metadata(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RepeatMode> sse_decode_list_repeat_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RepeatMode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_repeat_mode(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ThumbnailScheme> sse_decode_list_thumbnail_scheme(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ThumbnailScheme>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_thumbnail_scheme(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MediaType sse_decode_media_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MediaType.values[inner];
+  }
+
+  @protected
+  MetadataField sse_decode_metadata_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MetadataField.values[inner];
+  }
+
+  @protected
+  MetadataReport sse_decode_metadata_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mismatches = sse_decode_list_field_mismatch(deserializer);
+    return MetadataReport(mismatches: var_mismatches);
+  }
+
+  @protected
+  MusicMetadata sse_decode_music_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_albumArtist = sse_decode_opt_String(deserializer);
+    var var_thumbnail = sse_decode_list_String(deserializer);
+    var var_genres = sse_decode_list_String(deserializer);
+    var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_mediaType = sse_decode_media_type(deserializer);
+    var var_titleLocale = sse_decode_opt_String(deserializer);
+    var var_artistLocale = sse_decode_opt_String(deserializer);
+    return MusicMetadata(
+      title: var_title,
+      artist: var_artist,
+      album: var_album,
+      albumArtist: var_albumArtist,
+      thumbnail: var_thumbnail,
+      genres: var_genres,
+      trackNumber: var_trackNumber,
+      mediaType: var_mediaType,
+      titleLocale: var_titleLocale,
+      artistLocale: var_artistLocale,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GlobalSessionSnapshot? sse_decode_opt_box_autoadd_global_session_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_global_session_snapshot(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MusicMetadata? sse_decode_opt_box_autoadd_music_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_music_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlaybackStatus? sse_decode_opt_box_autoadd_playback_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_playback_status(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlaybackTimeline? sse_decode_opt_box_autoadd_playback_timeline(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_playback_timeline(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RepeatMode? sse_decode_opt_box_autoadd_repeat_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_repeat_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SMTCConfig? sse_decode_opt_box_autoadd_smtc_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_smtc_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ThumbnailError? sse_decode_opt_box_autoadd_thumbnail_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_thumbnail_error(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlaybackStatus sse_decode_playback_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PlaybackStatus.values[inner];
+  }
+
+  @protected
+  PlaybackTimeline sse_decode_playback_timeline(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startTimeMs = sse_decode_i_64(deserializer);
+    var var_endTimeMs = sse_decode_i_64(deserializer);
+    var var_positionMs = sse_decode_i_64(deserializer);
+    var var_minSeekTimeMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_maxSeekTimeMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return PlaybackTimeline(
+      startTimeMs: var_startTimeMs,
+      endTimeMs: var_endTimeMs,
+      positionMs: var_positionMs,
+      minSeekTimeMs: var_minSeekTimeMs,
+      maxSeekTimeMs: var_maxSeekTimeMs,
+    );
+  }
+
+  @protected
+  PositionChangeRequest sse_decode_position_change_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_positionMs = sse_decode_i_64(deserializer);
+    var var_ticks = sse_decode_i_64(deserializer);
+    var var_playbackRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_outOfRange = sse_decode_bool(deserializer);
+    return PositionChangeRequest(
+      positionMs: var_positionMs,
+      ticks: var_ticks,
+      playbackRate: var_playbackRate,
+      outOfRange: var_outOfRange,
+    );
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RepeatMode sse_decode_repeat_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RepeatMode.values[inner];
+  }
+
+  @protected
+  SessionInfo sse_decode_session_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_appMediaId = sse_decode_opt_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    return SessionInfo(appMediaId: var_appMediaId, enabled: var_enabled);
+  }
+
+  @protected
+  SmtcButton sse_decode_smtc_button(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SmtcButton.values[inner];
+  }
+
+  @protected
+  SMTCConfig sse_decode_smtc_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_playEnabled = sse_decode_bool(deserializer);
+    var var_pauseEnabled = sse_decode_bool(deserializer);
+    var var_stopEnabled = sse_decode_bool(deserializer);
+    var var_nextEnabled = sse_decode_bool(deserializer);
+    var var_prevEnabled = sse_decode_bool(deserializer);
+    var var_fastForwardEnabled = sse_decode_bool(deserializer);
+    var var_rewindEnabled = sse_decode_bool(deserializer);
+    var var_channelUpEnabled = sse_decode_bool(deserializer);
+    var var_channelDownEnabled = sse_decode_bool(deserializer);
+    var var_thumbnailsEnabled = sse_decode_bool(deserializer);
+    var var_mirrorPlaybackStatus = sse_decode_bool(deserializer);
+    var var_strictMetadata = sse_decode_bool(deserializer);
+    var var_optimisticSeek = sse_decode_bool(deserializer);
+    var var_sanitizeMetadata = sse_decode_bool(deserializer);
+    var var_transcodeThumbnails = sse_decode_bool(deserializer);
+    var var_thumbnailContentType = sse_decode_opt_String(deserializer);
+    var var_allowedThumbnailSchemes = sse_decode_list_thumbnail_scheme(
+      deserializer,
+    );
+    var var_thumbnailHeaders = sse_decode_list_record_string_string(
+      deserializer,
+    );
+    var var_recordAsFavorite = sse_decode_bool(deserializer);
+    var var_buttonDebounceMs = sse_decode_u_64(deserializer);
+    var var_eventCoalesceMs = sse_decode_u_64(deserializer);
+    var var_autoDisableAfterStopMs = sse_decode_u_64(deserializer);
+    var var_thumbnailWorkers = sse_decode_usize(deserializer);
+    var var_allowedRepeatModes = sse_decode_list_repeat_mode(deserializer);
+    var var_ignoreDisabledButtons = sse_decode_bool(deserializer);
+    var var_recordAsToggle = sse_decode_bool(deserializer);
+    var var_thumbnailBudgetMs = sse_decode_u_64(deserializer);
+    var var_maxThumbnailBytes = sse_decode_u_64(deserializer);
+    var var_thumbnailMode = sse_decode_thumbnail_mode(deserializer);
+    var var_thumbnailTransition = sse_decode_thumbnail_transition(deserializer);
+    var var_defaultMetadata = sse_decode_opt_box_autoadd_music_metadata(
+      deserializer,
+    );
+    var var_traceRawEvents = sse_decode_bool(deserializer);
+    var var_reapplyOnRefresh = sse_decode_bool(deserializer);
+    var var_reconcileThresholdMs = sse_decode_u_64(deserializer);
+    var var_dryRun = sse_decode_bool(deserializer);
+    var var_events = sse_decode_event_selection(deserializer);
+    return SMTCConfig(
+      playEnabled: var_playEnabled,
+      pauseEnabled: var_pauseEnabled,
+      stopEnabled: var_stopEnabled,
+      nextEnabled: var_nextEnabled,
+      prevEnabled: var_prevEnabled,
+      fastForwardEnabled: var_fastForwardEnabled,
+      rewindEnabled: var_rewindEnabled,
+      channelUpEnabled: var_channelUpEnabled,
+      channelDownEnabled: var_channelDownEnabled,
+      thumbnailsEnabled: var_thumbnailsEnabled,
+      mirrorPlaybackStatus: var_mirrorPlaybackStatus,
+      strictMetadata: var_strictMetadata,
+      optimisticSeek: var_optimisticSeek,
+      sanitizeMetadata: var_sanitizeMetadata,
+      transcodeThumbnails: var_transcodeThumbnails,
+      thumbnailContentType: var_thumbnailContentType,
+      allowedThumbnailSchemes: var_allowedThumbnailSchemes,
+      thumbnailHeaders: var_thumbnailHeaders,
+      recordAsFavorite: var_recordAsFavorite,
+      buttonDebounceMs: var_buttonDebounceMs,
+      eventCoalesceMs: var_eventCoalesceMs,
+      autoDisableAfterStopMs: var_autoDisableAfterStopMs,
+      thumbnailWorkers: var_thumbnailWorkers,
+      allowedRepeatModes: var_allowedRepeatModes,
+      ignoreDisabledButtons: var_ignoreDisabledButtons,
+      recordAsToggle: var_recordAsToggle,
+      thumbnailBudgetMs: var_thumbnailBudgetMs,
+      maxThumbnailBytes: var_maxThumbnailBytes,
+      thumbnailMode: var_thumbnailMode,
+      thumbnailTransition: var_thumbnailTransition,
+      defaultMetadata: var_defaultMetadata,
+      traceRawEvents: var_traceRawEvents,
+      reapplyOnRefresh: var_reapplyOnRefresh,
+      reconcileThresholdMs: var_reconcileThresholdMs,
+      dryRun: var_dryRun,
+      events: var_events,
+    );
+  }
+
+  @protected
+  SmtcEvent sse_decode_smtc_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_smtc_event_kind(deserializer);
+    var var_timestampMs = sse_decode_u_64(deserializer);
+    return SmtcEvent(kind: var_kind, timestampMs: var_timestampMs);
+  }
+
+  @protected
+  SmtcEventKind sse_decode_smtc_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_smtc_button(deserializer);
+        return SmtcEventKind_Button(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_position_change_request(
+          deserializer,
+        );
+        return SmtcEventKind_PositionChangeRequest(var_field0);
+      case 2:
+        var var_field0 = sse_decode_bool(deserializer);
+        return SmtcEventKind_ShuffleRequest(var_field0);
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return SmtcEventKind_RepeatModeRequest(var_field0);
+      case 4:
+        var var_field0 = sse_decode_box_autoadd_state_snapshot(deserializer);
+        return SmtcEventKind_Snapshot(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SmtcInit sse_decode_smtc_init(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_config = sse_decode_opt_box_autoadd_smtc_config(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_music_metadata(deserializer);
+    var var_appId = sse_decode_opt_String(deserializer);
+    var var_timeline = sse_decode_opt_box_autoadd_playback_timeline(
+      deserializer,
+    );
+    var var_status = sse_decode_opt_box_autoadd_playback_status(deserializer);
+    var var_enabled = sse_decode_opt_box_autoadd_bool(deserializer);
+    return SmtcInit(
+      config: var_config,
+      metadata: var_metadata,
+      appId: var_appId,
+      timeline: var_timeline,
+      status: var_status,
+      enabled: var_enabled,
+    );
+  }
+
+  @protected
+  StateChange sse_decode_state_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_opt_box_autoadd_music_metadata(deserializer);
+    var var_status = sse_decode_opt_box_autoadd_playback_status(deserializer);
+    var var_timeline = sse_decode_opt_box_autoadd_playback_timeline(
+      deserializer,
+    );
+    var var_shuffle = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_repeatMode = sse_decode_opt_box_autoadd_repeat_mode(deserializer);
+    return StateChange(
+      metadata: var_metadata,
+      status: var_status,
+      timeline: var_timeline,
+      shuffle: var_shuffle,
+      repeatMode: var_repeatMode,
+    );
+  }
+
+  @protected
+  StateSnapshot sse_decode_state_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_opt_box_autoadd_music_metadata(deserializer);
+    var var_status = sse_decode_opt_box_autoadd_playback_status(deserializer);
+    var var_timeline = sse_decode_opt_box_autoadd_playback_timeline(
+      deserializer,
+    );
+    return StateSnapshot(
+      metadata: var_metadata,
+      status: var_status,
+      timeline: var_timeline,
+    );
+  }
+
+  @protected
+  ThumbnailError sse_decode_thumbnail_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_thumbnail_scheme(deserializer);
+        return ThumbnailError_SchemeNotAllowed(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_FileNotFound(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_NetworkTimeout(var_field0);
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_FetchFailed(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_DecodeFailed(var_field0);
+      case 5:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_StreamCreateFailed(var_field0);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_InvalidToken(var_field0);
+      case 7:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_StaleToken(var_field0);
+      case 8:
+        var var_field0 = sse_decode_String(deserializer);
+        return ThumbnailError_TooLarge(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ThumbnailMode sse_decode_thumbnail_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ThumbnailMode.values[inner];
+  }
+
+  @protected
+  ThumbnailResult sse_decode_thumbnail_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_String(deserializer);
+    var var_error = sse_decode_opt_box_autoadd_thumbnail_error(deserializer);
+    var var_fromCache = sse_decode_bool(deserializer);
+    return ThumbnailResult(
+      source: var_source,
+      error: var_error,
+      fromCache: var_fromCache,
+    );
+  }
+
+  @protected
+  ThumbnailScheme sse_decode_thumbnail_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ThumbnailScheme.values[inner];
+  }
+
+  @protected
+  ThumbnailTransition sse_decode_thumbnail_transition(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ThumbnailTransition.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List?> Function(String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+        self,
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs__Output_i_64_AnyhowException(
+    FutureOr<PlatformInt64> Function() self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs__Output_i_64_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
+    FutureOr<void> Function(bool) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_bool_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+    FutureOr<void> Function(PositionChangeRequest) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+        self,
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(
+    FutureOr<void> Function(SmtcButton) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_isize(
+      PlatformPointerUtil.ptrToPlatformInt64(
+        encodeDartOpaque(
+          self,
+          portManager.dartHandlerPort,
+          generalizedFrbRustBinding,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_CancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CancellationTokenImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_EventSubscription(
+    EventSubscription self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as EventSubscriptionImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_GlobalSessions(
+    GlobalSessions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as GlobalSessionsImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_SMTCInternal(
+    SmtcInternal self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SmtcInternalImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_bool_Sse(
+    RustStreamSink<bool> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_button_press_Sse(
+    RustStreamSink<ButtonPress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_button_press,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_enabled_change_Sse(
+    RustStreamSink<EnabledChange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_enabled_change,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_i_32_Sse(
+    RustStreamSink<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_i_64_Sse(
+    RustStreamSink<PlatformInt64> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_global_session_snapshot_Sse(
+    RustStreamSink<List<GlobalSessionSnapshot>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_global_session_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_opt_box_autoadd_global_session_snapshot_Sse(
+    RustStreamSink<GlobalSessionSnapshot?> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_global_session_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_position_change_request_Sse(
+    RustStreamSink<PositionChangeRequest> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_position_change_request,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_smtc_event_Sse(
+    RustStreamSink<SmtcEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_smtc_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_state_change_Sse(
+    RustStreamSink<StateChange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_state_change,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_thumbnail_result_Sse(
+    RustStreamSink<ThumbnailResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_thumbnail_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_clear_flags(
+    ClearFlags self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_clear_flags(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_global_session_snapshot(
+    GlobalSessionSnapshot self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_global_session_snapshot(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_music_metadata(
+    MusicMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_music_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_playback_status(
+    PlaybackStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_playback_status(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_playback_timeline(
+    PlaybackTimeline self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_playback_timeline(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_position_change_request(
+    PositionChangeRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_position_change_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_repeat_mode(
+    RepeatMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_repeat_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_smtc_config(
+    SMTCConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_smtc_config(self, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_box_autoadd_smtc_init(
+    SmtcInit self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_smtc_init(self, serializer);
   }
 
   @protected
-  MusicMetadata dco_decode_music_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return MusicMetadata(
-      title: dco_decode_opt_String(arr[0]),
-      artist: dco_decode_opt_String(arr[1]),
-      album: dco_decode_opt_String(arr[2]),
-      albumArtist: dco_decode_opt_String(arr[3]),
-      thumbnail: dco_decode_opt_String(arr[4]),
-    );
+  void sse_encode_box_autoadd_state_snapshot(
+    StateSnapshot self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_state_snapshot(self, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_box_autoadd_thumbnail_error(
+    ThumbnailError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_thumbnail_error(self, serializer);
   }
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  PlaybackStatus dco_decode_playback_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PlaybackStatus.values[raw as int];
+  void sse_encode_button_press(ButtonPress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_smtc_button(self.button, serializer);
+    sse_encode_button_source(self.source, serializer);
   }
 
   @protected
-  PlaybackTimeline dco_decode_playback_timeline(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return PlaybackTimeline(
-      startTimeMs: dco_decode_i_64(arr[0]),
-      endTimeMs: dco_decode_i_64(arr[1]),
-      positionMs: dco_decode_i_64(arr[2]),
-      minSeekTimeMs: dco_decode_opt_box_autoadd_i_64(arr[3]),
-      maxSeekTimeMs: dco_decode_opt_box_autoadd_i_64(arr[4]),
-    );
+  void sse_encode_button_source(ButtonSource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  SMTCConfig dco_decode_smtc_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return SMTCConfig(
-      playEnabled: dco_decode_bool(arr[0]),
-      pauseEnabled: dco_decode_bool(arr[1]),
-      stopEnabled: dco_decode_bool(arr[2]),
-      nextEnabled: dco_decode_bool(arr[3]),
-      prevEnabled: dco_decode_bool(arr[4]),
-      fastForwardEnabled: dco_decode_bool(arr[5]),
-      rewindEnabled: dco_decode_bool(arr[6]),
-    );
+  void sse_encode_capabilities(Capabilities self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.timeline, serializer);
+    sse_encode_bool(self.shuffle, serializer);
+    sse_encode_bool(self.repeatMode, serializer);
+    sse_encode_bool(self.playbackRate, serializer);
+    sse_encode_bool(self.globalSessions, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_clear_flags(ClearFlags self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.field0, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_diagnostics(Diagnostics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.crateVersion, serializer);
+    sse_encode_opt_String(self.osVersion, serializer);
+    sse_encode_u_32(self.osBuild, serializer);
+    sse_encode_capabilities(self.capabilities, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_enabled_change(EnabledChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_enabled_change_source(self.source, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_enabled_change_source(
+    EnabledChangeSource self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  SmtcInternal sse_decode_RustOpaque_SMTCInternal(
-    SseDeserializer deserializer,
+  void sse_encode_event_selection(
+    EventSelection self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SmtcInternalImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_u_32(self.field0, serializer);
   }
 
   @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  RustStreamSink<bool> sse_decode_StreamSink_bool_Sse(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_field_mismatch(FieldMismatch self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_String(self.field, serializer);
+    sse_encode_String(self.expected, serializer);
+    sse_encode_String(self.actual, serializer);
   }
 
   @protected
-  RustStreamSink<PlatformInt64> sse_decode_StreamSink_i_64_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_global_session_snapshot(
+    GlobalSessionSnapshot self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_String(self.sourceAppId, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.albumArtist, serializer);
+    sse_encode_opt_box_autoadd_playback_status(self.playbackStatus, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_handler_counts(HandlerCounts self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_usize(self.buttonPressed, serializer);
+    sse_encode_usize(self.positionChangeRequest, serializer);
+    sse_encode_usize(self.shuffleRequest, serializer);
+    sse_encode_usize(self.repeatModeRequest, serializer);
+    sse_encode_usize(self.mediaFailed, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_64(deserializer));
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  MusicMetadata sse_decode_box_autoadd_music_metadata(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_music_metadata(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  PlaybackTimeline sse_decode_box_autoadd_playback_timeline(
-    SseDeserializer deserializer,
+  void sse_encode_list_field_mismatch(
+    List<FieldMismatch> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_playback_timeline(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_mismatch(item, serializer);
+    }
   }
 
   @protected
-  SMTCConfig sse_decode_box_autoadd_smtc_config(SseDeserializer deserializer) {
+  void sse_encode_list_global_session_snapshot(
+    List<GlobalSessionSnapshot> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_smtc_config(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_global_session_snapshot(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_metadata_field(
+    List<MetadataField> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_metadata_field(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_music_metadata(
+    List<MusicMetadata> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_music_metadata(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  MusicMetadata sse_decode_music_metadata(SseDeserializer deserializer) {
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_opt_String(deserializer);
-    var var_artist = sse_decode_opt_String(deserializer);
-    var var_album = sse_decode_opt_String(deserializer);
-    var var_albumArtist = sse_decode_opt_String(deserializer);
-    var var_thumbnail = sse_decode_opt_String(deserializer);
-    return MusicMetadata(
-      title: var_title,
-      artist: var_artist,
-      album: var_album,
-      albumArtist: var_albumArtist,
-      thumbnail: var_thumbnail,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_list_repeat_mode(
+    List<RepeatMode> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_repeat_mode(item, serializer);
     }
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_list_thumbnail_scheme(
+    List<ThumbnailScheme> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_thumbnail_scheme(item, serializer);
     }
   }
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_media_type(MediaType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_metadata_field(MetadataField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  PlaybackStatus sse_decode_playback_status(SseDeserializer deserializer) {
+  void sse_encode_metadata_report(
+    MetadataReport self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return PlaybackStatus.values[inner];
+    sse_encode_list_field_mismatch(self.mismatches, serializer);
   }
 
   @protected
-  PlaybackTimeline sse_decode_playback_timeline(SseDeserializer deserializer) {
+  void sse_encode_music_metadata(MusicMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_startTimeMs = sse_decode_i_64(deserializer);
-    var var_endTimeMs = sse_decode_i_64(deserializer);
-    var var_positionMs = sse_decode_i_64(deserializer);
-    var var_minSeekTimeMs = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_maxSeekTimeMs = sse_decode_opt_box_autoadd_i_64(deserializer);
-    return PlaybackTimeline(
-      startTimeMs: var_startTimeMs,
-      endTimeMs: var_endTimeMs,
-      positionMs: var_positionMs,
-      minSeekTimeMs: var_minSeekTimeMs,
-      maxSeekTimeMs: var_maxSeekTimeMs,
-    );
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.albumArtist, serializer);
+    sse_encode_list_String(self.thumbnail, serializer);
+    sse_encode_list_String(self.genres, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+    sse_encode_media_type(self.mediaType, serializer);
+    sse_encode_opt_String(self.titleLocale, serializer);
+    sse_encode_opt_String(self.artistLocale, serializer);
   }
 
   @protected
-  SMTCConfig sse_decode_smtc_config(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_playEnabled = sse_decode_bool(deserializer);
-    var var_pauseEnabled = sse_decode_bool(deserializer);
-    var var_stopEnabled = sse_decode_bool(deserializer);
-    var var_nextEnabled = sse_decode_bool(deserializer);
-    var var_prevEnabled = sse_decode_bool(deserializer);
-    var var_fastForwardEnabled = sse_decode_bool(deserializer);
-    var var_rewindEnabled = sse_decode_bool(deserializer);
-    return SMTCConfig(
-      playEnabled: var_playEnabled,
-      pauseEnabled: var_pauseEnabled,
-      stopEnabled: var_stopEnabled,
-      nextEnabled: var_nextEnabled,
-      prevEnabled: var_prevEnabled,
-      fastForwardEnabled: var_fastForwardEnabled,
-      rewindEnabled: var_rewindEnabled,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_global_session_snapshot(
+    GlobalSessionSnapshot? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_global_session_snapshot(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_box_autoadd_music_metadata(
+    MusicMetadata? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_music_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_RustOpaque_SMTCInternal(
-    SmtcInternal self,
+  void sse_encode_opt_box_autoadd_playback_status(
+    PlaybackStatus? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SmtcInternalImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_playback_status(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_String_Sse(
-    RustStreamSink<String> self,
+  void sse_encode_opt_box_autoadd_playback_timeline(
+    PlaybackTimeline? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_playback_timeline(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_bool_Sse(
-    RustStreamSink<bool> self,
+  void sse_encode_opt_box_autoadd_repeat_mode(
+    RepeatMode? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_repeat_mode(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_i_64_Sse(
-    RustStreamSink<PlatformInt64> self,
+  void sse_encode_opt_box_autoadd_smtc_config(
+    SMTCConfig? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_i_64,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_smtc_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_thumbnail_error(
+    ThumbnailError? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_thumbnail_error(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_music_metadata(
-    MusicMetadata self,
+  void sse_encode_playback_status(
+    PlaybackStatus self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_music_metadata(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_playback_timeline(
+  void sse_encode_playback_timeline(
     PlaybackTimeline self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_playback_timeline(self, serializer);
+    sse_encode_i_64(self.startTimeMs, serializer);
+    sse_encode_i_64(self.endTimeMs, serializer);
+    sse_encode_i_64(self.positionMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.minSeekTimeMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.maxSeekTimeMs, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_smtc_config(
-    SMTCConfig self,
+  void sse_encode_position_change_request(
+    PositionChangeRequest self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_smtc_config(self, serializer);
+    sse_encode_i_64(self.positionMs, serializer);
+    sse_encode_i_64(self.ticks, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.playbackRate, serializer);
+    sse_encode_bool(self.outOfRange, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_repeat_mode(RepeatMode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_session_info(SessionInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_opt_String(self.appMediaId, serializer);
+    sse_encode_bool(self.enabled, serializer);
   }
 
   @protected
-  void sse_encode_music_metadata(MusicMetadata self, SseSerializer serializer) {
+  void sse_encode_smtc_button(SmtcButton self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.title, serializer);
-    sse_encode_opt_String(self.artist, serializer);
-    sse_encode_opt_String(self.album, serializer);
-    sse_encode_opt_String(self.albumArtist, serializer);
-    sse_encode_opt_String(self.thumbnail, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_smtc_config(SMTCConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.playEnabled, serializer);
+    sse_encode_bool(self.pauseEnabled, serializer);
+    sse_encode_bool(self.stopEnabled, serializer);
+    sse_encode_bool(self.nextEnabled, serializer);
+    sse_encode_bool(self.prevEnabled, serializer);
+    sse_encode_bool(self.fastForwardEnabled, serializer);
+    sse_encode_bool(self.rewindEnabled, serializer);
+    sse_encode_bool(self.channelUpEnabled, serializer);
+    sse_encode_bool(self.channelDownEnabled, serializer);
+    sse_encode_bool(self.thumbnailsEnabled, serializer);
+    sse_encode_bool(self.mirrorPlaybackStatus, serializer);
+    sse_encode_bool(self.strictMetadata, serializer);
+    sse_encode_bool(self.optimisticSeek, serializer);
+    sse_encode_bool(self.sanitizeMetadata, serializer);
+    sse_encode_bool(self.transcodeThumbnails, serializer);
+    sse_encode_opt_String(self.thumbnailContentType, serializer);
+    sse_encode_list_thumbnail_scheme(self.allowedThumbnailSchemes, serializer);
+    sse_encode_list_record_string_string(self.thumbnailHeaders, serializer);
+    sse_encode_bool(self.recordAsFavorite, serializer);
+    sse_encode_u_64(self.buttonDebounceMs, serializer);
+    sse_encode_u_64(self.eventCoalesceMs, serializer);
+    sse_encode_u_64(self.autoDisableAfterStopMs, serializer);
+    sse_encode_usize(self.thumbnailWorkers, serializer);
+    sse_encode_list_repeat_mode(self.allowedRepeatModes, serializer);
+    sse_encode_bool(self.ignoreDisabledButtons, serializer);
+    sse_encode_bool(self.recordAsToggle, serializer);
+    sse_encode_u_64(self.thumbnailBudgetMs, serializer);
+    sse_encode_u_64(self.maxThumbnailBytes, serializer);
+    sse_encode_thumbnail_mode(self.thumbnailMode, serializer);
+    sse_encode_thumbnail_transition(self.thumbnailTransition, serializer);
+    sse_encode_opt_box_autoadd_music_metadata(self.defaultMetadata, serializer);
+    sse_encode_bool(self.traceRawEvents, serializer);
+    sse_encode_bool(self.reapplyOnRefresh, serializer);
+    sse_encode_u_64(self.reconcileThresholdMs, serializer);
+    sse_encode_bool(self.dryRun, serializer);
+    sse_encode_event_selection(self.events, serializer);
+  }
+
+  @protected
+  void sse_encode_smtc_event(SmtcEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_smtc_event_kind(self.kind, serializer);
+    sse_encode_u_64(self.timestampMs, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
+  @protected
+  void sse_encode_smtc_event_kind(
+    SmtcEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SmtcEventKind_Button(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_smtc_button(field0, serializer);
+      case SmtcEventKind_PositionChangeRequest(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_position_change_request(field0, serializer);
+      case SmtcEventKind_ShuffleRequest(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_bool(field0, serializer);
+      case SmtcEventKind_RepeatModeRequest(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+      case SmtcEventKind_Snapshot(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_box_autoadd_state_snapshot(field0, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+  void sse_encode_smtc_init(SmtcInit self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_smtc_config(self.config, serializer);
+    sse_encode_opt_box_autoadd_music_metadata(self.metadata, serializer);
+    sse_encode_opt_String(self.appId, serializer);
+    sse_encode_opt_box_autoadd_playback_timeline(self.timeline, serializer);
+    sse_encode_opt_box_autoadd_playback_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_bool(self.enabled, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
-    }
+  @protected
+  void sse_encode_state_change(StateChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_music_metadata(self.metadata, serializer);
+    sse_encode_opt_box_autoadd_playback_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_playback_timeline(self.timeline, serializer);
+    sse_encode_opt_box_autoadd_bool(self.shuffle, serializer);
+    sse_encode_opt_box_autoadd_repeat_mode(self.repeatMode, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
+  void sse_encode_state_snapshot(StateSnapshot self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_music_metadata(self.metadata, serializer);
+    sse_encode_opt_box_autoadd_playback_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_playback_timeline(self.timeline, serializer);
+  }
+
+  @protected
+  void sse_encode_thumbnail_error(
+    ThumbnailError self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
+    switch (self) {
+      case ThumbnailError_SchemeNotAllowed(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_thumbnail_scheme(field0, serializer);
+      case ThumbnailError_FileNotFound(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case ThumbnailError_NetworkTimeout(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+      case ThumbnailError_FetchFailed(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+      case ThumbnailError_DecodeFailed(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+      case ThumbnailError_StreamCreateFailed(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(field0, serializer);
+      case ThumbnailError_InvalidToken(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case ThumbnailError_StaleToken(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(field0, serializer);
+      case ThumbnailError_TooLarge(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(field0, serializer);
     }
   }
 
   @protected
-  void sse_encode_playback_status(
-    PlaybackStatus self,
+  void sse_encode_thumbnail_mode(ThumbnailMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_thumbnail_result(
+    ThumbnailResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.source, serializer);
+    sse_encode_opt_box_autoadd_thumbnail_error(self.error, serializer);
+    sse_encode_bool(self.fromCache, serializer);
+  }
+
+  @protected
+  void sse_encode_thumbnail_scheme(
+    ThumbnailScheme self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1269,28 +7912,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_playback_timeline(
-    PlaybackTimeline self,
+  void sse_encode_thumbnail_transition(
+    ThumbnailTransition self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.startTimeMs, serializer);
-    sse_encode_i_64(self.endTimeMs, serializer);
-    sse_encode_i_64(self.positionMs, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.minSeekTimeMs, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.maxSeekTimeMs, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_smtc_config(SMTCConfig self, SseSerializer serializer) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.playEnabled, serializer);
-    sse_encode_bool(self.pauseEnabled, serializer);
-    sse_encode_bool(self.stopEnabled, serializer);
-    sse_encode_bool(self.nextEnabled, serializer);
-    sse_encode_bool(self.prevEnabled, serializer);
-    sse_encode_bool(self.fastForwardEnabled, serializer);
-    sse_encode_bool(self.rewindEnabled, serializer);
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
@@ -1311,6 +7950,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 }
 
+@sealed
+class CancellationTokenImpl extends RustOpaque implements CancellationToken {
+  // Not to be used by end users
+  CancellationTokenImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  CancellationTokenImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  )
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_CancellationToken,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CancellationToken,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CancellationTokenPtr,
+  );
+}
+
+@sealed
+class EventSubscriptionImpl extends RustOpaque implements EventSubscription {
+  // Not to be used by end users
+  EventSubscriptionImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  EventSubscriptionImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  )
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_EventSubscription,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_EventSubscription,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_EventSubscriptionPtr,
+  );
+}
+
+@sealed
+class GlobalSessionsImpl extends RustOpaque implements GlobalSessions {
+  // Not to be used by end users
+  GlobalSessionsImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  GlobalSessionsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_GlobalSessions,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_GlobalSessions,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_GlobalSessionsPtr,
+  );
+}
+
 @sealed
 class SmtcInternalImpl extends RustOpaque implements SmtcInternal {
   // Not to be used by end users
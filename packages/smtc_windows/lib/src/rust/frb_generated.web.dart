@@ -7,12 +7,21 @@
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/api.dart';
+import 'api/global_sessions.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'internal/config.dart';
+import 'internal/diagnostics.dart';
+import 'internal/events.dart';
+import 'internal/global_sessions.dart';
+import 'internal/handlers.dart';
+import 'internal/init.dart';
 import 'internal/metadata.dart';
 import 'internal/playback_status.dart';
+import 'internal/repeat_mode.dart';
+import 'internal/session_info.dart';
+import 'internal/thumbnail.dart';
 import 'internal/timeline.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
@@ -24,6 +33,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_CancellationTokenPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_CancellationToken;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_EventSubscriptionPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_EventSubscription;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_GlobalSessionsPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_GlobalSessions;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_SmtcInternalPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_SMTCInternal;
@@ -32,249 +53,1225 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
   @protected
-  SmtcInternal dco_decode_RustOpaque_SMTCInternal(dynamic raw);
+  FutureOr<Uint8List?> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
+  );
+
+  @protected
+  FutureOr<void> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<PlatformInt64> Function()
+  dco_decode_DartFn_Inputs__Output_i_64_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<void> Function(bool)
+  dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<void> Function(PositionChangeRequest)
+  dco_decode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+    dynamic raw,
+  );
+
+  @protected
+  FutureOr<void> Function(SmtcButton)
+  dco_decode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(dynamic raw);
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw);
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+  @protected
+  CancellationToken dco_decode_RustOpaque_CancellationToken(dynamic raw);
+
+  @protected
+  EventSubscription dco_decode_RustOpaque_EventSubscription(dynamic raw);
+
+  @protected
+  GlobalSessions dco_decode_RustOpaque_GlobalSessions(dynamic raw);
+
+  @protected
+  SmtcInternal dco_decode_RustOpaque_SMTCInternal(dynamic raw);
+
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<bool> dco_decode_StreamSink_bool_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<ButtonPress> dco_decode_StreamSink_button_press_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<EnabledChange> dco_decode_StreamSink_enabled_change_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<int> dco_decode_StreamSink_i_32_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<PlatformInt64> dco_decode_StreamSink_i_64_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<List<GlobalSessionSnapshot>>
+  dco_decode_StreamSink_list_global_session_snapshot_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<GlobalSessionSnapshot?>
+  dco_decode_StreamSink_opt_box_autoadd_global_session_snapshot_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<PositionChangeRequest>
+  dco_decode_StreamSink_position_change_request_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<SmtcEvent> dco_decode_StreamSink_smtc_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<StateChange> dco_decode_StreamSink_state_change_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ThumbnailResult> dco_decode_StreamSink_thumbnail_result_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  ClearFlags dco_decode_box_autoadd_clear_flags(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  GlobalSessionSnapshot dco_decode_box_autoadd_global_session_snapshot(
+    dynamic raw,
+  );
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  MusicMetadata dco_decode_box_autoadd_music_metadata(dynamic raw);
+
+  @protected
+  PlaybackStatus dco_decode_box_autoadd_playback_status(dynamic raw);
+
+  @protected
+  PlaybackTimeline dco_decode_box_autoadd_playback_timeline(dynamic raw);
+
+  @protected
+  PositionChangeRequest dco_decode_box_autoadd_position_change_request(
+    dynamic raw,
+  );
+
+  @protected
+  RepeatMode dco_decode_box_autoadd_repeat_mode(dynamic raw);
+
+  @protected
+  SMTCConfig dco_decode_box_autoadd_smtc_config(dynamic raw);
+
+  @protected
+  SmtcInit dco_decode_box_autoadd_smtc_init(dynamic raw);
+
+  @protected
+  StateSnapshot dco_decode_box_autoadd_state_snapshot(dynamic raw);
+
+  @protected
+  ThumbnailError dco_decode_box_autoadd_thumbnail_error(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  ButtonPress dco_decode_button_press(dynamic raw);
+
+  @protected
+  ButtonSource dco_decode_button_source(dynamic raw);
+
+  @protected
+  Capabilities dco_decode_capabilities(dynamic raw);
+
+  @protected
+  ClearFlags dco_decode_clear_flags(dynamic raw);
+
+  @protected
+  Diagnostics dco_decode_diagnostics(dynamic raw);
+
+  @protected
+  EnabledChange dco_decode_enabled_change(dynamic raw);
+
+  @protected
+  EnabledChangeSource dco_decode_enabled_change_source(dynamic raw);
+
+  @protected
+  EventSelection dco_decode_event_selection(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FieldMismatch dco_decode_field_mismatch(dynamic raw);
+
+  @protected
+  GlobalSessionSnapshot dco_decode_global_session_snapshot(dynamic raw);
+
+  @protected
+  HandlerCounts dco_decode_handler_counts(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<FieldMismatch> dco_decode_list_field_mismatch(dynamic raw);
+
+  @protected
+  List<GlobalSessionSnapshot> dco_decode_list_global_session_snapshot(
+    dynamic raw,
+  );
+
+  @protected
+  List<MetadataField> dco_decode_list_metadata_field(dynamic raw);
+
+  @protected
+  List<MusicMetadata> dco_decode_list_music_metadata(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<RepeatMode> dco_decode_list_repeat_mode(dynamic raw);
+
+  @protected
+  List<ThumbnailScheme> dco_decode_list_thumbnail_scheme(dynamic raw);
+
+  @protected
+  MediaType dco_decode_media_type(dynamic raw);
+
+  @protected
+  MetadataField dco_decode_metadata_field(dynamic raw);
+
+  @protected
+  MetadataReport dco_decode_metadata_report(dynamic raw);
+
+  @protected
+  MusicMetadata dco_decode_music_metadata(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  GlobalSessionSnapshot? dco_decode_opt_box_autoadd_global_session_snapshot(
+    dynamic raw,
+  );
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  MusicMetadata? dco_decode_opt_box_autoadd_music_metadata(dynamic raw);
+
+  @protected
+  PlaybackStatus? dco_decode_opt_box_autoadd_playback_status(dynamic raw);
+
+  @protected
+  PlaybackTimeline? dco_decode_opt_box_autoadd_playback_timeline(dynamic raw);
+
+  @protected
+  RepeatMode? dco_decode_opt_box_autoadd_repeat_mode(dynamic raw);
+
+  @protected
+  SMTCConfig? dco_decode_opt_box_autoadd_smtc_config(dynamic raw);
+
+  @protected
+  ThumbnailError? dco_decode_opt_box_autoadd_thumbnail_error(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  PlaybackStatus dco_decode_playback_status(dynamic raw);
+
+  @protected
+  PlaybackTimeline dco_decode_playback_timeline(dynamic raw);
+
+  @protected
+  PositionChangeRequest dco_decode_position_change_request(dynamic raw);
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw);
+
+  @protected
+  RepeatMode dco_decode_repeat_mode(dynamic raw);
+
+  @protected
+  SessionInfo dco_decode_session_info(dynamic raw);
+
+  @protected
+  SmtcButton dco_decode_smtc_button(dynamic raw);
+
+  @protected
+  SMTCConfig dco_decode_smtc_config(dynamic raw);
+
+  @protected
+  SmtcEvent dco_decode_smtc_event(dynamic raw);
+
+  @protected
+  SmtcEventKind dco_decode_smtc_event_kind(dynamic raw);
+
+  @protected
+  SmtcInit dco_decode_smtc_init(dynamic raw);
+
+  @protected
+  StateChange dco_decode_state_change(dynamic raw);
+
+  @protected
+  StateSnapshot dco_decode_state_snapshot(dynamic raw);
+
+  @protected
+  ThumbnailError dco_decode_thumbnail_error(dynamic raw);
+
+  @protected
+  ThumbnailMode dco_decode_thumbnail_mode(dynamic raw);
+
+  @protected
+  ThumbnailResult dco_decode_thumbnail_result(dynamic raw);
+
+  @protected
+  ThumbnailScheme dco_decode_thumbnail_scheme(dynamic raw);
+
+  @protected
+  ThumbnailTransition dco_decode_thumbnail_transition(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  FutureOr<Uint8List?> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<void> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<PlatformInt64> Function()
+  sse_decode_DartFn_Inputs__Output_i_64_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<void> Function(bool)
+  sse_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<void> Function(PositionChangeRequest)
+  sse_decode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<void> Function(SmtcButton)
+  sse_decode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CancellationToken sse_decode_RustOpaque_CancellationToken(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EventSubscription sse_decode_RustOpaque_EventSubscription(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GlobalSessions sse_decode_RustOpaque_GlobalSessions(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SmtcInternal sse_decode_RustOpaque_SMTCInternal(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<bool> sse_decode_StreamSink_bool_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ButtonPress> sse_decode_StreamSink_button_press_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<EnabledChange> sse_decode_StreamSink_enabled_change_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<int> sse_decode_StreamSink_i_32_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<PlatformInt64> sse_decode_StreamSink_i_64_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<List<GlobalSessionSnapshot>>
+  sse_decode_StreamSink_list_global_session_snapshot_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<GlobalSessionSnapshot?>
+  sse_decode_StreamSink_opt_box_autoadd_global_session_snapshot_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<PositionChangeRequest>
+  sse_decode_StreamSink_position_change_request_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<SmtcEvent> sse_decode_StreamSink_smtc_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<StateChange> sse_decode_StreamSink_state_change_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ThumbnailResult> sse_decode_StreamSink_thumbnail_result_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  ClearFlags sse_decode_box_autoadd_clear_flags(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  GlobalSessionSnapshot sse_decode_box_autoadd_global_session_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  MusicMetadata sse_decode_box_autoadd_music_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlaybackStatus sse_decode_box_autoadd_playback_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlaybackTimeline sse_decode_box_autoadd_playback_timeline(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PositionChangeRequest sse_decode_box_autoadd_position_change_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RepeatMode sse_decode_box_autoadd_repeat_mode(SseDeserializer deserializer);
+
+  @protected
+  SMTCConfig sse_decode_box_autoadd_smtc_config(SseDeserializer deserializer);
+
+  @protected
+  SmtcInit sse_decode_box_autoadd_smtc_init(SseDeserializer deserializer);
+
+  @protected
+  StateSnapshot sse_decode_box_autoadd_state_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailError sse_decode_box_autoadd_thumbnail_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  ButtonPress sse_decode_button_press(SseDeserializer deserializer);
+
+  @protected
+  ButtonSource sse_decode_button_source(SseDeserializer deserializer);
+
+  @protected
+  Capabilities sse_decode_capabilities(SseDeserializer deserializer);
+
+  @protected
+  ClearFlags sse_decode_clear_flags(SseDeserializer deserializer);
+
+  @protected
+  Diagnostics sse_decode_diagnostics(SseDeserializer deserializer);
+
+  @protected
+  EnabledChange sse_decode_enabled_change(SseDeserializer deserializer);
+
+  @protected
+  EnabledChangeSource sse_decode_enabled_change_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EventSelection sse_decode_event_selection(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FieldMismatch sse_decode_field_mismatch(SseDeserializer deserializer);
+
+  @protected
+  GlobalSessionSnapshot sse_decode_global_session_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HandlerCounts sse_decode_handler_counts(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<FieldMismatch> sse_decode_list_field_mismatch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<GlobalSessionSnapshot> sse_decode_list_global_session_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MetadataField> sse_decode_list_metadata_field(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MusicMetadata> sse_decode_list_music_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RepeatMode> sse_decode_list_repeat_mode(SseDeserializer deserializer);
+
+  @protected
+  List<ThumbnailScheme> sse_decode_list_thumbnail_scheme(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MediaType sse_decode_media_type(SseDeserializer deserializer);
+
+  @protected
+  MetadataField sse_decode_metadata_field(SseDeserializer deserializer);
+
+  @protected
+  MetadataReport sse_decode_metadata_report(SseDeserializer deserializer);
+
+  @protected
+  MusicMetadata sse_decode_music_metadata(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  GlobalSessionSnapshot? sse_decode_opt_box_autoadd_global_session_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  MusicMetadata? sse_decode_opt_box_autoadd_music_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlaybackStatus? sse_decode_opt_box_autoadd_playback_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlaybackTimeline? sse_decode_opt_box_autoadd_playback_timeline(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RepeatMode? sse_decode_opt_box_autoadd_repeat_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SMTCConfig? sse_decode_opt_box_autoadd_smtc_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailError? sse_decode_opt_box_autoadd_thumbnail_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  PlaybackStatus sse_decode_playback_status(SseDeserializer deserializer);
+
+  @protected
+  PlaybackTimeline sse_decode_playback_timeline(SseDeserializer deserializer);
+
+  @protected
+  PositionChangeRequest sse_decode_position_change_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RepeatMode sse_decode_repeat_mode(SseDeserializer deserializer);
+
+  @protected
+  SessionInfo sse_decode_session_info(SseDeserializer deserializer);
+
+  @protected
+  SmtcButton sse_decode_smtc_button(SseDeserializer deserializer);
+
+  @protected
+  SMTCConfig sse_decode_smtc_config(SseDeserializer deserializer);
+
+  @protected
+  SmtcEvent sse_decode_smtc_event(SseDeserializer deserializer);
+
+  @protected
+  SmtcEventKind sse_decode_smtc_event_kind(SseDeserializer deserializer);
+
+  @protected
+  SmtcInit sse_decode_smtc_init(SseDeserializer deserializer);
+
+  @protected
+  StateChange sse_decode_state_change(SseDeserializer deserializer);
+
+  @protected
+  StateSnapshot sse_decode_state_snapshot(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailError sse_decode_thumbnail_error(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailMode sse_decode_thumbnail_mode(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailResult sse_decode_thumbnail_result(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailScheme sse_decode_thumbnail_scheme(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailTransition sse_decode_thumbnail_transition(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List?> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs__Output_i_64_AnyhowException(
+    FutureOr<PlatformInt64> Function() self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
+    FutureOr<void> Function(bool) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_position_change_request_Output_unit_AnyhowException(
+    FutureOr<void> Function(PositionChangeRequest) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_smtc_button_Output_unit_AnyhowException(
+    FutureOr<void> Function(SmtcButton) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_CancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_EventSubscription(
+    EventSubscription self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_GlobalSessions(
+    GlobalSessions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_SMTCInternal(
+    SmtcInternal self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_bool_Sse(
+    RustStreamSink<bool> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+  void sse_encode_StreamSink_button_press_Sse(
+    RustStreamSink<ButtonPress> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  RustStreamSink<bool> dco_decode_StreamSink_bool_Sse(dynamic raw);
+  void sse_encode_StreamSink_enabled_change_Sse(
+    RustStreamSink<EnabledChange> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  RustStreamSink<PlatformInt64> dco_decode_StreamSink_i_64_Sse(dynamic raw);
+  void sse_encode_StreamSink_i_32_Sse(
+    RustStreamSink<int> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String dco_decode_String(dynamic raw);
+  void sse_encode_StreamSink_i_64_Sse(
+    RustStreamSink<PlatformInt64> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool dco_decode_bool(dynamic raw);
+  void sse_encode_StreamSink_list_global_session_snapshot_Sse(
+    RustStreamSink<List<GlobalSessionSnapshot>> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw);
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+  void sse_encode_StreamSink_opt_box_autoadd_global_session_snapshot_Sse(
+    RustStreamSink<GlobalSessionSnapshot?> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MusicMetadata dco_decode_box_autoadd_music_metadata(dynamic raw);
+  void sse_encode_StreamSink_position_change_request_Sse(
+    RustStreamSink<PositionChangeRequest> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlaybackTimeline dco_decode_box_autoadd_playback_timeline(dynamic raw);
+  void sse_encode_StreamSink_smtc_event_Sse(
+    RustStreamSink<SmtcEvent> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SMTCConfig dco_decode_box_autoadd_smtc_config(dynamic raw);
+  void sse_encode_StreamSink_state_change_Sse(
+    RustStreamSink<StateChange> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  void sse_encode_StreamSink_thumbnail_result_Sse(
+    RustStreamSink<ThumbnailResult> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  MusicMetadata dco_decode_music_metadata(dynamic raw);
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_box_autoadd_clear_flags(
+    ClearFlags self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+  void sse_encode_box_autoadd_global_session_snapshot(
+    GlobalSessionSnapshot self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlaybackStatus dco_decode_playback_status(dynamic raw);
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlaybackTimeline dco_decode_playback_timeline(dynamic raw);
+  void sse_encode_box_autoadd_music_metadata(
+    MusicMetadata self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SMTCConfig dco_decode_smtc_config(dynamic raw);
+  void sse_encode_box_autoadd_playback_status(
+    PlaybackStatus self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_box_autoadd_playback_timeline(
+    PlaybackTimeline self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_box_autoadd_position_change_request(
+    PositionChangeRequest self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_box_autoadd_repeat_mode(
+    RepeatMode self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_smtc_config(
+    SMTCConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  SmtcInternal sse_decode_RustOpaque_SMTCInternal(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_smtc_init(
+    SmtcInit self,
+    SseSerializer serializer,
+  );
 
   @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_state_snapshot(
+    StateSnapshot self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<bool> sse_decode_StreamSink_bool_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_thumbnail_error(
+    ThumbnailError self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<PlatformInt64> sse_decode_StreamSink_i_64_Sse(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_button_press(ButtonPress self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+  void sse_encode_button_source(ButtonSource self, SseSerializer serializer);
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+  void sse_encode_capabilities(Capabilities self, SseSerializer serializer);
 
   @protected
-  MusicMetadata sse_decode_box_autoadd_music_metadata(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_clear_flags(ClearFlags self, SseSerializer serializer);
 
   @protected
-  PlaybackTimeline sse_decode_box_autoadd_playback_timeline(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_diagnostics(Diagnostics self, SseSerializer serializer);
 
   @protected
-  SMTCConfig sse_decode_box_autoadd_smtc_config(SseDeserializer deserializer);
+  void sse_encode_enabled_change(EnabledChange self, SseSerializer serializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_enabled_change_source(
+    EnabledChangeSource self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_event_selection(
+    EventSelection self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  MusicMetadata sse_decode_music_metadata(SseDeserializer deserializer);
+  void sse_encode_field_mismatch(FieldMismatch self, SseSerializer serializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_global_session_snapshot(
+    GlobalSessionSnapshot self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+  void sse_encode_handler_counts(HandlerCounts self, SseSerializer serializer);
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  PlaybackStatus sse_decode_playback_status(SseDeserializer deserializer);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  PlaybackTimeline sse_decode_playback_timeline(SseDeserializer deserializer);
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  SMTCConfig sse_decode_smtc_config(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_field_mismatch(
+    List<FieldMismatch> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_global_session_snapshot(
+    List<GlobalSessionSnapshot> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_list_metadata_field(
+    List<MetadataField> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_music_metadata(
+    List<MusicMetadata> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_RustOpaque_SMTCInternal(
-    SmtcInternal self,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_String_Sse(
-    RustStreamSink<String> self,
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_bool_Sse(
-    RustStreamSink<bool> self,
+  void sse_encode_list_repeat_mode(
+    List<RepeatMode> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_i_64_Sse(
-    RustStreamSink<PlatformInt64> self,
+  void sse_encode_list_thumbnail_scheme(
+    List<ThumbnailScheme> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_media_type(MediaType self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_metadata_field(MetadataField self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+  void sse_encode_metadata_report(
+    MetadataReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
+  void sse_encode_music_metadata(MusicMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_global_session_snapshot(
+    GlobalSessionSnapshot? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_music_metadata(
-    MusicMetadata self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_playback_timeline(
-    PlaybackTimeline self,
+  void sse_encode_opt_box_autoadd_music_metadata(
+    MusicMetadata? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_smtc_config(
-    SMTCConfig self,
+  void sse_encode_opt_box_autoadd_playback_status(
+    PlaybackStatus? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_playback_timeline(
+    PlaybackTimeline? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_repeat_mode(
+    RepeatMode? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_smtc_config(
+    SMTCConfig? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_music_metadata(MusicMetadata self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_thumbnail_error(
+    ThumbnailError? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
     SseSerializer serializer,
   );
 
@@ -290,9 +1287,78 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_position_change_request(
+    PositionChangeRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_repeat_mode(RepeatMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_session_info(SessionInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_smtc_button(SmtcButton self, SseSerializer serializer);
+
   @protected
   void sse_encode_smtc_config(SMTCConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_smtc_event(SmtcEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_smtc_event_kind(SmtcEventKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_smtc_init(SmtcInit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_state_change(StateChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_state_snapshot(StateSnapshot self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_thumbnail_error(
+    ThumbnailError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_thumbnail_mode(ThumbnailMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_thumbnail_result(
+    ThumbnailResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_thumbnail_scheme(
+    ThumbnailScheme self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_thumbnail_transition(
+    ThumbnailTransition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -308,6 +1374,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
+  void rust_arc_increment_strong_count_RustOpaque_CancellationToken(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_CancellationToken(
+        ptr,
+      );
+
+  void rust_arc_decrement_strong_count_RustOpaque_CancellationToken(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_CancellationToken(
+        ptr,
+      );
+
+  void rust_arc_increment_strong_count_RustOpaque_EventSubscription(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_EventSubscription(
+        ptr,
+      );
+
+  void rust_arc_decrement_strong_count_RustOpaque_EventSubscription(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_EventSubscription(
+        ptr,
+      );
+
+  void rust_arc_increment_strong_count_RustOpaque_GlobalSessions(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_GlobalSessions(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_GlobalSessions(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_GlobalSessions(ptr);
+
   void rust_arc_increment_strong_count_RustOpaque_SMTCInternal(int ptr) =>
       wasmModule.rust_arc_increment_strong_count_RustOpaque_SMTCInternal(ptr);
 
@@ -321,6 +1413,30 @@ external RustLibWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void rust_arc_increment_strong_count_RustOpaque_CancellationToken(
+    int ptr,
+  );
+
+  external void rust_arc_decrement_strong_count_RustOpaque_CancellationToken(
+    int ptr,
+  );
+
+  external void rust_arc_increment_strong_count_RustOpaque_EventSubscription(
+    int ptr,
+  );
+
+  external void rust_arc_decrement_strong_count_RustOpaque_EventSubscription(
+    int ptr,
+  );
+
+  external void rust_arc_increment_strong_count_RustOpaque_GlobalSessions(
+    int ptr,
+  );
+
+  external void rust_arc_decrement_strong_count_RustOpaque_GlobalSessions(
+    int ptr,
+  );
+
   external void rust_arc_increment_strong_count_RustOpaque_SMTCInternal(
     int ptr,
   );
@@ -4,8 +4,14 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'events.dart';
+import 'metadata.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'repeat_mode.dart';
+import 'thumbnail.dart';
 
+/// Not `Copy` since `allowed_thumbnail_schemes` became a list; clone it
+/// instead.
 class SMTCConfig {
   final bool playEnabled;
   final bool pauseEnabled;
@@ -14,6 +20,100 @@ class SMTCConfig {
   final bool prevEnabled;
   final bool fastForwardEnabled;
   final bool rewindEnabled;
+  /// Channel buttons, off by default. Radio-style apps often use them to
+  /// switch stations, see `channel_change_event`.
+  final bool channelUpEnabled;
+  final bool channelDownEnabled;
+  /// When `false`, `update_metadata` skips resolving thumbnails and clears
+  /// any art already shown. Useful for low-power modes.
+  final bool thumbnailsEnabled;
+  /// Drives the underlying `MediaPlayer` (`Play`/`Pause`) from
+  /// `update_playback_status` so its `PlaybackSession` follows the status
+  /// shown in the overlay. Only observable once the player has a source.
+  final bool mirrorPlaybackStatus;
+  /// Makes `update_metadata` reject metadata that fails `validate_metadata`
+  /// instead of applying it.
+  final bool strictMetadata;
+  /// On a seek request from the OS, immediately shows the requested position
+  /// with a `Changing` status so the seek bar doesn't jump back while the app
+  /// seeks. The next `update_timeline`/`update_position` call replaces the
+  /// optimistic position and restores the last status. Off by default.
+  final bool optimisticSeek;
+  /// Strips control characters (newlines, tabs, ...) from the text fields
+  /// passed to `update_metadata`.
+  final bool sanitizeMetadata;
+  /// Re-encodes every thumbnail as PNG before handing it to the overlay.
+  /// Costs an extra decode/encode per update, so it is off by default.
+  final bool transcodeThumbnails;
+  /// The content type every thumbnail stream reports, e.g. `image/png`, for
+  /// art the overlay doesn't show because its type is detected wrongly
+  /// (such as a CDN sending `application/octet-stream`). Applies to every
+  /// source, after any transcoding. `None` keeps the detected type.
+  final String? thumbnailContentType;
+  /// Thumbnail sources `update_metadata` may load. Sources using any other
+  /// scheme are skipped (and logged), leaving the track without art.
+  final List<ThumbnailScheme> allowedThumbnailSchemes;
+  /// Headers sent when fetching http(s) thumbnails, e.g. for art behind
+  /// auth. When empty the overlay loads the url itself.
+  final List<(String, String)> thumbnailHeaders;
+  /// Enables the Record button and reports its presses as
+  /// `SmtcButton::Favorite`. This is a convention some apps use for a
+  /// like action, not something the OS knows about.
+  final bool recordAsFavorite;
+  /// Presses of the same button closer together than this are reported
+  /// once. `0` turns debouncing off.
+  final BigInt buttonDebounceMs;
+  /// On `unified_event_stream` and `register_all_events`, drops an event
+  /// identical to the previous one if it arrives within this many
+  /// milliseconds. `0` turns coalescing off.
+  final BigInt eventCoalesceMs;
+  /// Disables the control this many milliseconds after the status becomes
+  /// `Stopped`, unless another status or new metadata arrives first. Keeps
+  /// the session around briefly for a quick resume. `0` turns it off.
+  final BigInt autoDisableAfterStopMs;
+  /// How many thumbnails `set_thumbnail_async` loads at once; further loads
+  /// wait in a queue.
+  final BigInt thumbnailWorkers;
+  /// Repeat modes the app supports. Requests from the overlay are clamped
+  /// to these (`List` becomes `Track`), and `update_repeat_mode` rejects the
+  /// rest. `None` is always allowed.
+  final List<RepeatMode> allowedRepeatModes;
+  /// Drops presses of buttons this config disables, which the OS can still
+  /// send briefly after a button is turned off. Off by default.
+  final bool ignoreDisabledButtons;
+  /// Enables the Record button and treats its presses as a toggle, reported
+  /// through `record_toggle_event`. Like `record_as_favorite` this is only
+  /// an app convention; `record_as_favorite` wins if both are set.
+  final bool recordAsToggle;
+  /// Longest `update_metadata` waits for the art, in milliseconds. Text is
+  /// applied right away once this runs out, and the art follows when it
+  /// has loaded. `0` waits for the art.
+  final BigInt thumbnailBudgetMs;
+  /// Art over this many bytes is skipped, and reported as `TooLarge`,
+  /// before it is decoded or shown. `0` allows any size.
+  final BigInt maxThumbnailBytes;
+  final ThumbnailMode thumbnailMode;
+  final ThumbnailTransition thumbnailTransition;
+  /// Shown when the control is first enabled without any metadata set,
+  /// e.g. a "Loading..." title. It isn't treated as real metadata, and the
+  /// first `update_metadata` replaces it.
+  final MusicMetadata? defaultMetadata;
+  /// Feeds `raw_event_trace`. For debugging only; off by default.
+  final bool traceRawEvents;
+  /// Re-applies the last state, see `reapply_state`, whenever
+  /// `refresh_event` fires, instead of leaving it to the app.
+  final bool reapplyOnRefresh;
+  /// How far `reconcile_position` lets the reported position drift from
+  /// the expected one before correcting it.
+  final BigInt reconcileThresholdMs;
+  /// Makes every call that changes the control log the backend calls it
+  /// would make instead of making them, for debugging integrations.
+  /// Nothing is shown, but the state is still recorded as if it were, so
+  /// snapshots and `debug_dump` show what the control would hold.
+  final bool dryRun;
+  /// The requests the unified event streams register for. All of them by
+  /// default.
+  final EventSelection events;
 
   const SMTCConfig({
     required this.playEnabled,
@@ -23,6 +123,35 @@ class SMTCConfig {
     required this.prevEnabled,
     required this.fastForwardEnabled,
     required this.rewindEnabled,
+    required this.channelUpEnabled,
+    required this.channelDownEnabled,
+    required this.thumbnailsEnabled,
+    required this.mirrorPlaybackStatus,
+    required this.strictMetadata,
+    required this.optimisticSeek,
+    required this.sanitizeMetadata,
+    required this.transcodeThumbnails,
+    this.thumbnailContentType,
+    required this.allowedThumbnailSchemes,
+    required this.thumbnailHeaders,
+    required this.recordAsFavorite,
+    required this.buttonDebounceMs,
+    required this.eventCoalesceMs,
+    required this.autoDisableAfterStopMs,
+    required this.thumbnailWorkers,
+    required this.allowedRepeatModes,
+    required this.ignoreDisabledButtons,
+    required this.recordAsToggle,
+    required this.thumbnailBudgetMs,
+    required this.maxThumbnailBytes,
+    required this.thumbnailMode,
+    required this.thumbnailTransition,
+    this.defaultMetadata,
+    required this.traceRawEvents,
+    required this.reapplyOnRefresh,
+    required this.reconcileThresholdMs,
+    required this.dryRun,
+    required this.events,
   });
 
   @override
@@ -33,7 +162,36 @@ class SMTCConfig {
       nextEnabled.hashCode ^
       prevEnabled.hashCode ^
       fastForwardEnabled.hashCode ^
-      rewindEnabled.hashCode;
+      rewindEnabled.hashCode ^
+      channelUpEnabled.hashCode ^
+      channelDownEnabled.hashCode ^
+      thumbnailsEnabled.hashCode ^
+      mirrorPlaybackStatus.hashCode ^
+      strictMetadata.hashCode ^
+      optimisticSeek.hashCode ^
+      sanitizeMetadata.hashCode ^
+      transcodeThumbnails.hashCode ^
+      thumbnailContentType.hashCode ^
+      allowedThumbnailSchemes.hashCode ^
+      thumbnailHeaders.hashCode ^
+      recordAsFavorite.hashCode ^
+      buttonDebounceMs.hashCode ^
+      eventCoalesceMs.hashCode ^
+      autoDisableAfterStopMs.hashCode ^
+      thumbnailWorkers.hashCode ^
+      allowedRepeatModes.hashCode ^
+      ignoreDisabledButtons.hashCode ^
+      recordAsToggle.hashCode ^
+      thumbnailBudgetMs.hashCode ^
+      maxThumbnailBytes.hashCode ^
+      thumbnailMode.hashCode ^
+      thumbnailTransition.hashCode ^
+      defaultMetadata.hashCode ^
+      traceRawEvents.hashCode ^
+      reapplyOnRefresh.hashCode ^
+      reconcileThresholdMs.hashCode ^
+      dryRun.hashCode ^
+      events.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -46,5 +204,34 @@ class SMTCConfig {
           nextEnabled == other.nextEnabled &&
           prevEnabled == other.prevEnabled &&
           fastForwardEnabled == other.fastForwardEnabled &&
-          rewindEnabled == other.rewindEnabled;
+          rewindEnabled == other.rewindEnabled &&
+          channelUpEnabled == other.channelUpEnabled &&
+          channelDownEnabled == other.channelDownEnabled &&
+          thumbnailsEnabled == other.thumbnailsEnabled &&
+          mirrorPlaybackStatus == other.mirrorPlaybackStatus &&
+          strictMetadata == other.strictMetadata &&
+          optimisticSeek == other.optimisticSeek &&
+          sanitizeMetadata == other.sanitizeMetadata &&
+          transcodeThumbnails == other.transcodeThumbnails &&
+          thumbnailContentType == other.thumbnailContentType &&
+          allowedThumbnailSchemes == other.allowedThumbnailSchemes &&
+          thumbnailHeaders == other.thumbnailHeaders &&
+          recordAsFavorite == other.recordAsFavorite &&
+          buttonDebounceMs == other.buttonDebounceMs &&
+          eventCoalesceMs == other.eventCoalesceMs &&
+          autoDisableAfterStopMs == other.autoDisableAfterStopMs &&
+          thumbnailWorkers == other.thumbnailWorkers &&
+          allowedRepeatModes == other.allowedRepeatModes &&
+          ignoreDisabledButtons == other.ignoreDisabledButtons &&
+          recordAsToggle == other.recordAsToggle &&
+          thumbnailBudgetMs == other.thumbnailBudgetMs &&
+          maxThumbnailBytes == other.maxThumbnailBytes &&
+          thumbnailMode == other.thumbnailMode &&
+          thumbnailTransition == other.thumbnailTransition &&
+          defaultMetadata == other.defaultMetadata &&
+          traceRawEvents == other.traceRawEvents &&
+          reapplyOnRefresh == other.reapplyOnRefresh &&
+          reconcileThresholdMs == other.reconcileThresholdMs &&
+          dryRun == other.dryRun &&
+          events == other.events;
 }
@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Optional parts of the SMTC API the running Windows build provides.
+class Capabilities {
+  /// `UpdateTimelineProperties`, for the seek bar.
+  final bool timeline;
+  final bool shuffle;
+  final bool repeatMode;
+  final bool playbackRate;
+  /// `GlobalSystemMediaTransportControlsSessionManager`, for reading other
+  /// apps' sessions.
+  final bool globalSessions;
+
+  const Capabilities({
+    required this.timeline,
+    required this.shuffle,
+    required this.repeatMode,
+    required this.playbackRate,
+    required this.globalSessions,
+  });
+
+  @override
+  int get hashCode =>
+      timeline.hashCode ^
+      shuffle.hashCode ^
+      repeatMode.hashCode ^
+      playbackRate.hashCode ^
+      globalSessions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Capabilities &&
+          runtimeType == other.runtimeType &&
+          timeline == other.timeline &&
+          shuffle == other.shuffle &&
+          repeatMode == other.repeatMode &&
+          playbackRate == other.playbackRate &&
+          globalSessions == other.globalSessions;
+}
+
+/// What a bug report needs to know about the environment.
+class Diagnostics {
+  final String crateVersion;
+  /// The Windows version as `major.minor.build.revision`, `None` if it
+  /// couldn't be read.
+  final String? osVersion;
+  /// The build part of `os_version`, e.g. `22631`. `0` if unknown.
+  final int osBuild;
+  final Capabilities capabilities;
+
+  const Diagnostics({
+    required this.crateVersion,
+    this.osVersion,
+    required this.osBuild,
+    required this.capabilities,
+  });
+
+  @override
+  int get hashCode =>
+      crateVersion.hashCode ^
+      osVersion.hashCode ^
+      osBuild.hashCode ^
+      capabilities.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Diagnostics &&
+          runtimeType == other.runtimeType &&
+          crateVersion == other.crateVersion &&
+          osVersion == other.osVersion &&
+          osBuild == other.osBuild &&
+          capabilities == other.capabilities;
+}
@@ -0,0 +1,244 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'metadata.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'playback_status.dart';
+import 'repeat_mode.dart';
+import 'timeline.dart';
+part 'events.freezed.dart';
+
+/// A button press with what is known about its origin.
+class ButtonPress {
+  final SmtcButton button;
+  /// WinRT's `ButtonPressed` args only carry the button, so this is
+  /// currently always `Unknown`. Kept so callers don't break once the
+  /// platform exposes more.
+  final ButtonSource source;
+
+  const ButtonPress({required this.button, required this.source});
+
+  @override
+  int get hashCode => button.hashCode ^ source.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ButtonPress &&
+          runtimeType == other.runtimeType &&
+          button == other.button &&
+          source == other.source;
+}
+
+/// Where a button press came from.
+enum ButtonSource {
+  /// A media key or remote.
+  hardware,
+  /// The OS overlay or another software client.
+  software,
+  unknown,
+}
+
+class EnabledChange {
+  final bool enabled;
+  final EnabledChangeSource source;
+
+  const EnabledChange({required this.enabled, required this.source});
+
+  @override
+  int get hashCode => enabled.hashCode ^ source.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EnabledChange &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          source == other.source;
+}
+
+/// Who changed whether the control is enabled.
+enum EnabledChangeSource {
+  /// `enable_smtc`, `disable_smtc` or `auto_disable_after_stop_ms`.
+  app,
+  /// Something outside the crate. WinRT raises no event for this, so it's
+  /// only noticed when `is_enabled` reads a different value than the one
+  /// last set.
+  system,
+}
+
+/// Which requests `unified_event_stream` and `register_all_events` register
+/// handlers for, as a set of bits combined with `|`. Leaving one out saves
+/// its handler when the app ignores it. Unknown bits are ignored.
+class EventSelection {
+  final int field0;
+
+  const EventSelection({required this.field0});
+
+  @override
+  int get hashCode => field0.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventSelection &&
+          runtimeType == other.runtimeType &&
+          field0 == other.field0;
+}
+
+/// A seek request coming from the OS overlay.
+class PositionChangeRequest {
+  final PlatformInt64 positionMs;
+  /// The raw requested position in 100ns ticks, for callers that can't
+  /// afford the rounding to milliseconds.
+  final PlatformInt64 ticks;
+  /// The rate last passed to `set_playback_rate` when the request came in,
+  /// for extrapolating the position after the seek. `None` if never set.
+  final double? playbackRate;
+  /// The position is outside the seekable range of the last timeline
+  /// (or seeking is turned off with `set_seekable`). Such requests are
+  /// forwarded as is, not clamped, and skip `optimistic_seek`.
+  final bool outOfRange;
+
+  const PositionChangeRequest({
+    required this.positionMs,
+    required this.ticks,
+    this.playbackRate,
+    required this.outOfRange,
+  });
+
+  @override
+  int get hashCode =>
+      positionMs.hashCode ^
+      ticks.hashCode ^
+      playbackRate.hashCode ^
+      outOfRange.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PositionChangeRequest &&
+          runtimeType == other.runtimeType &&
+          positionMs == other.positionMs &&
+          ticks == other.ticks &&
+          playbackRate == other.playbackRate &&
+          outOfRange == other.outOfRange;
+}
+
+enum SmtcButton {
+  play,
+  pause,
+  stop,
+  record,
+  fastForward,
+  rewind,
+  next,
+  previous,
+  channelUp,
+  channelDown,
+  /// Record, reported as a like/favorite action when
+  /// `SMTCConfig::record_as_favorite` is set. Windows has no favorite
+  /// button; repurposing Record is only an app convention.
+  favorite,
+}
+
+class SmtcEvent {
+  final SmtcEventKind kind;
+  /// Milliseconds since the owning `SMTCInternal` was created, taken from a
+  /// monotonic clock when the OS request was received.
+  final BigInt timestampMs;
+
+  const SmtcEvent({required this.kind, required this.timestampMs});
+
+  @override
+  int get hashCode => kind.hashCode ^ timestampMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmtcEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          timestampMs == other.timestampMs;
+}
+
+@freezed
+sealed class SmtcEventKind with _$SmtcEventKind {
+  const SmtcEventKind._();
+
+  const factory SmtcEventKind.button(SmtcButton field0) = SmtcEventKind_Button;
+  const factory SmtcEventKind.positionChangeRequest(
+    PositionChangeRequest field0,
+  ) = SmtcEventKind_PositionChangeRequest;
+  const factory SmtcEventKind.shuffleRequest(bool field0) =
+      SmtcEventKind_ShuffleRequest;
+  const factory SmtcEventKind.repeatModeRequest(String field0) =
+      SmtcEventKind_RepeatModeRequest;
+  /// What the control currently shows. Not a request: sent first on every
+  /// new unified stream, and by `replay_last_state`.
+  const factory SmtcEventKind.snapshot(StateSnapshot field0) =
+      SmtcEventKind_Snapshot;
+}
+
+/// Sent by `state_changed_event` after each successful update: the values
+/// that update set, `None` for everything it left alone.
+class StateChange {
+  final MusicMetadata? metadata;
+  final PlaybackStatus? status;
+  final PlaybackTimeline? timeline;
+  final bool? shuffle;
+  final RepeatMode? repeatMode;
+
+  const StateChange({
+    this.metadata,
+    this.status,
+    this.timeline,
+    this.shuffle,
+    this.repeatMode,
+  });
+
+  @override
+  int get hashCode =>
+      metadata.hashCode ^
+      status.hashCode ^
+      timeline.hashCode ^
+      shuffle.hashCode ^
+      repeatMode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StateChange &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          status == other.status &&
+          timeline == other.timeline &&
+          shuffle == other.shuffle &&
+          repeatMode == other.repeatMode;
+}
+
+/// The last metadata, status and timeline passed to the control, `None`
+/// for whatever hasn't been set (or was cleared) yet.
+class StateSnapshot {
+  final MusicMetadata? metadata;
+  final PlaybackStatus? status;
+  final PlaybackTimeline? timeline;
+
+  const StateSnapshot({this.metadata, this.status, this.timeline});
+
+  @override
+  int get hashCode => metadata.hashCode ^ status.hashCode ^ timeline.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StateSnapshot &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          status == other.status &&
+          timeline == other.timeline;
+}
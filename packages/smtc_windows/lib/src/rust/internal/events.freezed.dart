@@ -0,0 +1,375 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'events.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$SmtcEventKind {
+
+ Object get field0;
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SmtcEventKind&&const DeepCollectionEquality().equals(other.field0, field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+
+@override
+String toString() {
+  return 'SmtcEventKind(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+class $SmtcEventKindCopyWith<$Res>  {
+$SmtcEventKindCopyWith(SmtcEventKind _, $Res Function(SmtcEventKind) __);
+}
+
+
+/// @nodoc
+
+
+class SmtcEventKind_Button extends SmtcEventKind {
+  const SmtcEventKind_Button(this.field0): super._();
+
+
+@override final  SmtcButton field0;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SmtcEventKind_ButtonCopyWith<SmtcEventKind_Button> get copyWith => _$SmtcEventKind_ButtonCopyWithImpl<SmtcEventKind_Button>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SmtcEventKind_Button&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'SmtcEventKind.button(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SmtcEventKind_ButtonCopyWith<$Res> implements $SmtcEventKindCopyWith<$Res> {
+  factory $SmtcEventKind_ButtonCopyWith(SmtcEventKind_Button value, $Res Function(SmtcEventKind_Button) _then) = _$SmtcEventKind_ButtonCopyWithImpl;
+@useResult
+$Res call({
+ SmtcButton field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$SmtcEventKind_ButtonCopyWithImpl<$Res>
+    implements $SmtcEventKind_ButtonCopyWith<$Res> {
+  _$SmtcEventKind_ButtonCopyWithImpl(this._self, this._then);
+
+  final SmtcEventKind_Button _self;
+  final $Res Function(SmtcEventKind_Button) _then;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(SmtcEventKind_Button(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as SmtcButton,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SmtcEventKind_PositionChangeRequest extends SmtcEventKind {
+  const SmtcEventKind_PositionChangeRequest(this.field0): super._();
+
+
+@override final  PositionChangeRequest field0;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SmtcEventKind_PositionChangeRequestCopyWith<SmtcEventKind_PositionChangeRequest> get copyWith => _$SmtcEventKind_PositionChangeRequestCopyWithImpl<SmtcEventKind_PositionChangeRequest>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SmtcEventKind_PositionChangeRequest&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'SmtcEventKind.positionChangeRequest(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SmtcEventKind_PositionChangeRequestCopyWith<$Res> implements $SmtcEventKindCopyWith<$Res> {
+  factory $SmtcEventKind_PositionChangeRequestCopyWith(SmtcEventKind_PositionChangeRequest value, $Res Function(SmtcEventKind_PositionChangeRequest) _then) = _$SmtcEventKind_PositionChangeRequestCopyWithImpl;
+@useResult
+$Res call({
+ PositionChangeRequest field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$SmtcEventKind_PositionChangeRequestCopyWithImpl<$Res>
+    implements $SmtcEventKind_PositionChangeRequestCopyWith<$Res> {
+  _$SmtcEventKind_PositionChangeRequestCopyWithImpl(this._self, this._then);
+
+  final SmtcEventKind_PositionChangeRequest _self;
+  final $Res Function(SmtcEventKind_PositionChangeRequest) _then;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(SmtcEventKind_PositionChangeRequest(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as PositionChangeRequest,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SmtcEventKind_ShuffleRequest extends SmtcEventKind {
+  const SmtcEventKind_ShuffleRequest(this.field0): super._();
+
+
+@override final  bool field0;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SmtcEventKind_ShuffleRequestCopyWith<SmtcEventKind_ShuffleRequest> get copyWith => _$SmtcEventKind_ShuffleRequestCopyWithImpl<SmtcEventKind_ShuffleRequest>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SmtcEventKind_ShuffleRequest&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'SmtcEventKind.shuffleRequest(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SmtcEventKind_ShuffleRequestCopyWith<$Res> implements $SmtcEventKindCopyWith<$Res> {
+  factory $SmtcEventKind_ShuffleRequestCopyWith(SmtcEventKind_ShuffleRequest value, $Res Function(SmtcEventKind_ShuffleRequest) _then) = _$SmtcEventKind_ShuffleRequestCopyWithImpl;
+@useResult
+$Res call({
+ bool field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$SmtcEventKind_ShuffleRequestCopyWithImpl<$Res>
+    implements $SmtcEventKind_ShuffleRequestCopyWith<$Res> {
+  _$SmtcEventKind_ShuffleRequestCopyWithImpl(this._self, this._then);
+
+  final SmtcEventKind_ShuffleRequest _self;
+  final $Res Function(SmtcEventKind_ShuffleRequest) _then;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(SmtcEventKind_ShuffleRequest(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SmtcEventKind_RepeatModeRequest extends SmtcEventKind {
+  const SmtcEventKind_RepeatModeRequest(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SmtcEventKind_RepeatModeRequestCopyWith<SmtcEventKind_RepeatModeRequest> get copyWith => _$SmtcEventKind_RepeatModeRequestCopyWithImpl<SmtcEventKind_RepeatModeRequest>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SmtcEventKind_RepeatModeRequest&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'SmtcEventKind.repeatModeRequest(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SmtcEventKind_RepeatModeRequestCopyWith<$Res> implements $SmtcEventKindCopyWith<$Res> {
+  factory $SmtcEventKind_RepeatModeRequestCopyWith(SmtcEventKind_RepeatModeRequest value, $Res Function(SmtcEventKind_RepeatModeRequest) _then) = _$SmtcEventKind_RepeatModeRequestCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$SmtcEventKind_RepeatModeRequestCopyWithImpl<$Res>
+    implements $SmtcEventKind_RepeatModeRequestCopyWith<$Res> {
+  _$SmtcEventKind_RepeatModeRequestCopyWithImpl(this._self, this._then);
+
+  final SmtcEventKind_RepeatModeRequest _self;
+  final $Res Function(SmtcEventKind_RepeatModeRequest) _then;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(SmtcEventKind_RepeatModeRequest(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SmtcEventKind_Snapshot extends SmtcEventKind {
+  const SmtcEventKind_Snapshot(this.field0): super._();
+
+
+@override final  StateSnapshot field0;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SmtcEventKind_SnapshotCopyWith<SmtcEventKind_Snapshot> get copyWith => _$SmtcEventKind_SnapshotCopyWithImpl<SmtcEventKind_Snapshot>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SmtcEventKind_Snapshot&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'SmtcEventKind.snapshot(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SmtcEventKind_SnapshotCopyWith<$Res> implements $SmtcEventKindCopyWith<$Res> {
+  factory $SmtcEventKind_SnapshotCopyWith(SmtcEventKind_Snapshot value, $Res Function(SmtcEventKind_Snapshot) _then) = _$SmtcEventKind_SnapshotCopyWithImpl;
+@useResult
+$Res call({
+ StateSnapshot field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$SmtcEventKind_SnapshotCopyWithImpl<$Res>
+    implements $SmtcEventKind_SnapshotCopyWith<$Res> {
+  _$SmtcEventKind_SnapshotCopyWithImpl(this._self, this._then);
+
+  final SmtcEventKind_Snapshot _self;
+  final $Res Function(SmtcEventKind_Snapshot) _then;
+
+/// Create a copy of SmtcEventKind
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(SmtcEventKind_Snapshot(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as StateSnapshot,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'playback_status.dart';
+
+/// What another app's media session is currently showing.
+class GlobalSessionSnapshot {
+  final String sourceAppId;
+  final String? title;
+  final String? artist;
+  final String? album;
+  final String? albumArtist;
+  final PlaybackStatus? playbackStatus;
+
+  const GlobalSessionSnapshot({
+    required this.sourceAppId,
+    this.title,
+    this.artist,
+    this.album,
+    this.albumArtist,
+    this.playbackStatus,
+  });
+
+  @override
+  int get hashCode =>
+      sourceAppId.hashCode ^
+      title.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      albumArtist.hashCode ^
+      playbackStatus.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GlobalSessionSnapshot &&
+          runtimeType == other.runtimeType &&
+          sourceAppId == other.sourceAppId &&
+          title == other.title &&
+          artist == other.artist &&
+          album == other.album &&
+          albumArtist == other.albumArtist &&
+          playbackStatus == other.playbackStatus;
+}
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Number of live handlers per event.
+class HandlerCounts {
+  final BigInt buttonPressed;
+  final BigInt positionChangeRequest;
+  final BigInt shuffleRequest;
+  final BigInt repeatModeRequest;
+  final BigInt mediaFailed;
+
+  const HandlerCounts({
+    required this.buttonPressed,
+    required this.positionChangeRequest,
+    required this.shuffleRequest,
+    required this.repeatModeRequest,
+    required this.mediaFailed,
+  });
+
+  @override
+  int get hashCode =>
+      buttonPressed.hashCode ^
+      positionChangeRequest.hashCode ^
+      shuffleRequest.hashCode ^
+      repeatModeRequest.hashCode ^
+      mediaFailed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HandlerCounts &&
+          runtimeType == other.runtimeType &&
+          buttonPressed == other.buttonPressed &&
+          positionChangeRequest == other.positionChangeRequest &&
+          shuffleRequest == other.shuffleRequest &&
+          repeatModeRequest == other.repeatModeRequest &&
+          mediaFailed == other.mediaFailed;
+}
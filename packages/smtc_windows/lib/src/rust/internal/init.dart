@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'config.dart';
+import 'metadata.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'playback_status.dart';
+import 'timeline.dart';
+
+/// Everything `SMTCInternal::with_init` applies before enabling the control,
+/// so simple apps can set up the overlay in one call.
+class SmtcInit {
+  /// `None` applies no config: the defaults for a new control, the current
+  /// config for `switch_session_full`.
+  final SMTCConfig? config;
+  final MusicMetadata? metadata;
+  /// Passed to `update_metadata` along with `metadata`.
+  final String? appId;
+  final PlaybackTimeline? timeline;
+  final PlaybackStatus? status;
+  /// Defaults to `true`, like `SMTCInternal::new`.
+  final bool? enabled;
+
+  const SmtcInit({
+    this.config,
+    this.metadata,
+    this.appId,
+    this.timeline,
+    this.status,
+    this.enabled,
+  });
+
+  @override
+  int get hashCode =>
+      config.hashCode ^
+      metadata.hashCode ^
+      appId.hashCode ^
+      timeline.hashCode ^
+      status.hashCode ^
+      enabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmtcInit &&
+          runtimeType == other.runtimeType &&
+          config == other.config &&
+          metadata == other.metadata &&
+          appId == other.appId &&
+          timeline == other.timeline &&
+          status == other.status &&
+          enabled == other.enabled;
+}
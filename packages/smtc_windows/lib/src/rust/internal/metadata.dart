@@ -6,19 +6,121 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+/// Which groups of display properties `update_metadata_with` clears before
+/// applying new values, as a set of bits combined with `|`. Groups that
+/// aren't cleared keep their current values for any field the new metadata
+/// leaves as `None`. Unknown bits are ignored.
+class ClearFlags {
+  final int field0;
+
+  const ClearFlags({required this.field0});
+
+  @override
+  int get hashCode => field0.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClearFlags &&
+          runtimeType == other.runtimeType &&
+          field0 == other.field0;
+}
+
+/// A value the control holds differently from what was set.
+class FieldMismatch {
+  final String field;
+  final String expected;
+  final String actual;
+
+  const FieldMismatch({
+    required this.field,
+    required this.expected,
+    required this.actual,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ expected.hashCode ^ actual.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldMismatch &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          expected == other.expected &&
+          actual == other.actual;
+}
+
+/// Which kind of content the overlay is told it shows. For `Video` and
+/// `Image` the title and artist are shown as title and subtitle, and the
+/// album fields, genres and track number are dropped.
+enum MediaType {
+  music,
+  video,
+  image,
+  /// Content that fits none of the others. The overlay shows no text for
+  /// it, only the app name (or `app_id`) and the thumbnail.
+  unknown,
+}
+
+/// A single field `clear_metadata_fields` can blank.
+enum MetadataField {
+  title,
+  artist,
+  album,
+  albumArtist,
+  genres,
+  trackNumber,
+  thumbnail,
+}
+
+/// What `update_metadata_verified` read back. Empty when everything stuck.
+class MetadataReport {
+  final List<FieldMismatch> mismatches;
+
+  const MetadataReport({required this.mismatches});
+
+  @override
+  int get hashCode => mismatches.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MetadataReport &&
+          runtimeType == other.runtimeType &&
+          mismatches == other.mismatches;
+}
+
 class MusicMetadata {
   final String? title;
   final String? artist;
   final String? album;
   final String? albumArtist;
-  final String? thumbnail;
+  /// Art sources, tried in order; the first one that loads is shown.
+  /// `validate_metadata` only checks the first, since the ones after it
+  /// are fallbacks expected to fail at times.
+  final List<String> thumbnail;
+  final List<String> genres;
+  final int? trackNumber;
+  final MediaType mediaType;
+  /// BCP-47 language tags for `title` and `artist`, e.g. `ja-JP`. The
+  /// display properties WinRT exposes have no language qualifier, so these
+  /// are only kept with the metadata (and show up wherever it is read
+  /// back, like snapshots) for the app's own use; the overlay ignores them.
+  final String? titleLocale;
+  final String? artistLocale;
 
   const MusicMetadata({
     this.title,
     this.artist,
     this.album,
     this.albumArtist,
-    this.thumbnail,
+    required this.thumbnail,
+    required this.genres,
+    this.trackNumber,
+    required this.mediaType,
+    this.titleLocale,
+    this.artistLocale,
   });
 
   @override
@@ -27,7 +129,12 @@ class MusicMetadata {
       artist.hashCode ^
       album.hashCode ^
       albumArtist.hashCode ^
-      thumbnail.hashCode;
+      thumbnail.hashCode ^
+      genres.hashCode ^
+      trackNumber.hashCode ^
+      mediaType.hashCode ^
+      titleLocale.hashCode ^
+      artistLocale.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -38,5 +145,10 @@ class MusicMetadata {
           artist == other.artist &&
           album == other.album &&
           albumArtist == other.albumArtist &&
-          thumbnail == other.thumbnail;
+          thumbnail == other.thumbnail &&
+          genres == other.genres &&
+          trackNumber == other.trackNumber &&
+          mediaType == other.mediaType &&
+          titleLocale == other.titleLocale &&
+          artistLocale == other.artistLocale;
 }
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Live values read back from the control.
+class SessionInfo {
+  /// `None` when no app media id is set.
+  final String? appMediaId;
+  final bool enabled;
+
+  const SessionInfo({this.appMediaId, required this.enabled});
+
+  @override
+  int get hashCode => appMediaId.hashCode ^ enabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SessionInfo &&
+          runtimeType == other.runtimeType &&
+          appMediaId == other.appMediaId &&
+          enabled == other.enabled;
+}
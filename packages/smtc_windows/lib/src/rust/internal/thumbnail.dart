@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'thumbnail.freezed.dart';
+
+/// Why a thumbnail couldn't be shown. The payloads are human readable details.
+@freezed
+sealed class ThumbnailError with _$ThumbnailError {
+  const ThumbnailError._();
+
+  /// `SMTCConfig::allowed_thumbnail_schemes` doesn't include the scheme.
+  const factory ThumbnailError.schemeNotAllowed(ThumbnailScheme field0) =
+      ThumbnailError_SchemeNotAllowed;
+  const factory ThumbnailError.fileNotFound(String field0) =
+      ThumbnailError_FileNotFound;
+  const factory ThumbnailError.networkTimeout(String field0) =
+      ThumbnailError_NetworkTimeout;
+  /// The request failed for any other reason, e.g. a non-success status.
+  const factory ThumbnailError.fetchFailed(String field0) =
+      ThumbnailError_FetchFailed;
+  /// The image couldn't be decoded for transcoding.
+  const factory ThumbnailError.decodeFailed(String field0) =
+      ThumbnailError_DecodeFailed;
+  /// WinRT couldn't open the source or wrap it in a stream.
+  const factory ThumbnailError.streamCreateFailed(String field0) =
+      ThumbnailError_StreamCreateFailed;
+  /// A future access list token that can't be a token at all.
+  const factory ThumbnailError.invalidToken(String field0) =
+      ThumbnailError_InvalidToken;
+  /// A future access list token that no longer refers to a file.
+  const factory ThumbnailError.staleToken(String field0) =
+      ThumbnailError_StaleToken;
+  /// The art is bigger than `SMTCConfig::max_thumbnail_bytes`.
+  const factory ThumbnailError.tooLarge(String field0) =
+      ThumbnailError_TooLarge;
+}
+
+/// How `update_metadata` loads art.
+enum ThumbnailMode {
+  /// Waits for the art (up to `thumbnail_budget_ms`, if set) and applies
+  /// it with the text.
+  sync,
+  /// Applies the text right away and the art on the thumbnail worker pool
+  /// once it has loaded, unless newer art or metadata came in first.
+  async,
+}
+
+/// Reported through `thumbnail_result_event` for every thumbnail load.
+class ThumbnailResult {
+  final String source;
+  /// `None` if the thumbnail loaded.
+  final ThumbnailError? error;
+  /// Whether the thumbnail came from the cache instead of being loaded.
+  final bool fromCache;
+
+  const ThumbnailResult({
+    required this.source,
+    this.error,
+    required this.fromCache,
+  });
+
+  @override
+  int get hashCode => source.hashCode ^ error.hashCode ^ fromCache.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThumbnailResult &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          error == other.error &&
+          fromCache == other.fromCache;
+}
+
+enum ThumbnailScheme { file, http, https }
+
+/// What the overlay shows while new art is still loading in the background,
+/// i.e. with `ThumbnailMode::Async` or past `thumbnail_budget_ms`.
+enum ThumbnailTransition {
+  /// No art until the new art has loaded.
+  blank,
+  /// The previous art next to the new text until the new art has loaded,
+  /// or is cleared if it fails to.
+  keepOld,
+}
@@ -0,0 +1,639 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'thumbnail.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ThumbnailError {
+
+ Object get field0;
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError&&const DeepCollectionEquality().equals(other.field0, field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+
+@override
+String toString() {
+  return 'ThumbnailError(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+class $ThumbnailErrorCopyWith<$Res>  {
+$ThumbnailErrorCopyWith(ThumbnailError _, $Res Function(ThumbnailError) __);
+}
+
+
+/// @nodoc
+
+
+class ThumbnailError_SchemeNotAllowed extends ThumbnailError {
+  const ThumbnailError_SchemeNotAllowed(this.field0): super._();
+
+
+@override final  ThumbnailScheme field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_SchemeNotAllowedCopyWith<ThumbnailError_SchemeNotAllowed> get copyWith => _$ThumbnailError_SchemeNotAllowedCopyWithImpl<ThumbnailError_SchemeNotAllowed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_SchemeNotAllowed&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.schemeNotAllowed(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_SchemeNotAllowedCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_SchemeNotAllowedCopyWith(ThumbnailError_SchemeNotAllowed value, $Res Function(ThumbnailError_SchemeNotAllowed) _then) = _$ThumbnailError_SchemeNotAllowedCopyWithImpl;
+@useResult
+$Res call({
+ ThumbnailScheme field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_SchemeNotAllowedCopyWithImpl<$Res>
+    implements $ThumbnailError_SchemeNotAllowedCopyWith<$Res> {
+  _$ThumbnailError_SchemeNotAllowedCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_SchemeNotAllowed _self;
+  final $Res Function(ThumbnailError_SchemeNotAllowed) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_SchemeNotAllowed(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as ThumbnailScheme,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_FileNotFound extends ThumbnailError {
+  const ThumbnailError_FileNotFound(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_FileNotFoundCopyWith<ThumbnailError_FileNotFound> get copyWith => _$ThumbnailError_FileNotFoundCopyWithImpl<ThumbnailError_FileNotFound>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_FileNotFound&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.fileNotFound(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_FileNotFoundCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_FileNotFoundCopyWith(ThumbnailError_FileNotFound value, $Res Function(ThumbnailError_FileNotFound) _then) = _$ThumbnailError_FileNotFoundCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_FileNotFoundCopyWithImpl<$Res>
+    implements $ThumbnailError_FileNotFoundCopyWith<$Res> {
+  _$ThumbnailError_FileNotFoundCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_FileNotFound _self;
+  final $Res Function(ThumbnailError_FileNotFound) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_FileNotFound(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_NetworkTimeout extends ThumbnailError {
+  const ThumbnailError_NetworkTimeout(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_NetworkTimeoutCopyWith<ThumbnailError_NetworkTimeout> get copyWith => _$ThumbnailError_NetworkTimeoutCopyWithImpl<ThumbnailError_NetworkTimeout>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_NetworkTimeout&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.networkTimeout(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_NetworkTimeoutCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_NetworkTimeoutCopyWith(ThumbnailError_NetworkTimeout value, $Res Function(ThumbnailError_NetworkTimeout) _then) = _$ThumbnailError_NetworkTimeoutCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_NetworkTimeoutCopyWithImpl<$Res>
+    implements $ThumbnailError_NetworkTimeoutCopyWith<$Res> {
+  _$ThumbnailError_NetworkTimeoutCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_NetworkTimeout _self;
+  final $Res Function(ThumbnailError_NetworkTimeout) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_NetworkTimeout(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_FetchFailed extends ThumbnailError {
+  const ThumbnailError_FetchFailed(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_FetchFailedCopyWith<ThumbnailError_FetchFailed> get copyWith => _$ThumbnailError_FetchFailedCopyWithImpl<ThumbnailError_FetchFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_FetchFailed&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.fetchFailed(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_FetchFailedCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_FetchFailedCopyWith(ThumbnailError_FetchFailed value, $Res Function(ThumbnailError_FetchFailed) _then) = _$ThumbnailError_FetchFailedCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_FetchFailedCopyWithImpl<$Res>
+    implements $ThumbnailError_FetchFailedCopyWith<$Res> {
+  _$ThumbnailError_FetchFailedCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_FetchFailed _self;
+  final $Res Function(ThumbnailError_FetchFailed) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_FetchFailed(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_DecodeFailed extends ThumbnailError {
+  const ThumbnailError_DecodeFailed(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_DecodeFailedCopyWith<ThumbnailError_DecodeFailed> get copyWith => _$ThumbnailError_DecodeFailedCopyWithImpl<ThumbnailError_DecodeFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_DecodeFailed&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.decodeFailed(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_DecodeFailedCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_DecodeFailedCopyWith(ThumbnailError_DecodeFailed value, $Res Function(ThumbnailError_DecodeFailed) _then) = _$ThumbnailError_DecodeFailedCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_DecodeFailedCopyWithImpl<$Res>
+    implements $ThumbnailError_DecodeFailedCopyWith<$Res> {
+  _$ThumbnailError_DecodeFailedCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_DecodeFailed _self;
+  final $Res Function(ThumbnailError_DecodeFailed) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_DecodeFailed(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_StreamCreateFailed extends ThumbnailError {
+  const ThumbnailError_StreamCreateFailed(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_StreamCreateFailedCopyWith<ThumbnailError_StreamCreateFailed> get copyWith => _$ThumbnailError_StreamCreateFailedCopyWithImpl<ThumbnailError_StreamCreateFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_StreamCreateFailed&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.streamCreateFailed(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_StreamCreateFailedCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_StreamCreateFailedCopyWith(ThumbnailError_StreamCreateFailed value, $Res Function(ThumbnailError_StreamCreateFailed) _then) = _$ThumbnailError_StreamCreateFailedCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_StreamCreateFailedCopyWithImpl<$Res>
+    implements $ThumbnailError_StreamCreateFailedCopyWith<$Res> {
+  _$ThumbnailError_StreamCreateFailedCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_StreamCreateFailed _self;
+  final $Res Function(ThumbnailError_StreamCreateFailed) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_StreamCreateFailed(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_InvalidToken extends ThumbnailError {
+  const ThumbnailError_InvalidToken(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_InvalidTokenCopyWith<ThumbnailError_InvalidToken> get copyWith => _$ThumbnailError_InvalidTokenCopyWithImpl<ThumbnailError_InvalidToken>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_InvalidToken&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.invalidToken(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_InvalidTokenCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_InvalidTokenCopyWith(ThumbnailError_InvalidToken value, $Res Function(ThumbnailError_InvalidToken) _then) = _$ThumbnailError_InvalidTokenCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_InvalidTokenCopyWithImpl<$Res>
+    implements $ThumbnailError_InvalidTokenCopyWith<$Res> {
+  _$ThumbnailError_InvalidTokenCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_InvalidToken _self;
+  final $Res Function(ThumbnailError_InvalidToken) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_InvalidToken(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_StaleToken extends ThumbnailError {
+  const ThumbnailError_StaleToken(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_StaleTokenCopyWith<ThumbnailError_StaleToken> get copyWith => _$ThumbnailError_StaleTokenCopyWithImpl<ThumbnailError_StaleToken>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_StaleToken&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.staleToken(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_StaleTokenCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_StaleTokenCopyWith(ThumbnailError_StaleToken value, $Res Function(ThumbnailError_StaleToken) _then) = _$ThumbnailError_StaleTokenCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_StaleTokenCopyWithImpl<$Res>
+    implements $ThumbnailError_StaleTokenCopyWith<$Res> {
+  _$ThumbnailError_StaleTokenCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_StaleToken _self;
+  final $Res Function(ThumbnailError_StaleToken) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_StaleToken(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThumbnailError_TooLarge extends ThumbnailError {
+  const ThumbnailError_TooLarge(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThumbnailError_TooLargeCopyWith<ThumbnailError_TooLarge> get copyWith => _$ThumbnailError_TooLargeCopyWithImpl<ThumbnailError_TooLarge>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThumbnailError_TooLarge&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThumbnailError.tooLarge(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThumbnailError_TooLargeCopyWith<$Res> implements $ThumbnailErrorCopyWith<$Res> {
+  factory $ThumbnailError_TooLargeCopyWith(ThumbnailError_TooLarge value, $Res Function(ThumbnailError_TooLarge) _then) = _$ThumbnailError_TooLargeCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThumbnailError_TooLargeCopyWithImpl<$Res>
+    implements $ThumbnailError_TooLargeCopyWith<$Res> {
+  _$ThumbnailError_TooLargeCopyWithImpl(this._self, this._then);
+
+  final ThumbnailError_TooLarge _self;
+  final $Res Function(ThumbnailError_TooLarge) _then;
+
+/// Create a copy of ThumbnailError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThumbnailError_TooLarge(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -12,6 +12,12 @@ import 'rust/internal/playback_status.dart';
 import 'rust/internal/timeline.dart';
 
 class SMTCWindows {
+  static const _emptyMetadata = MusicMetadata(
+    thumbnail: [],
+    genres: [],
+    mediaType: MediaType.music,
+  );
+
   //! Unsafe shared pointer to the underlying Rust struct.
   late final api.SmtcInternal _internal;
 
@@ -43,22 +49,14 @@ class SMTCWindows {
         _internal = api.smtcNew(enabled: enabled ?? true),
         _status = status,
         _config = config ??
-            const SMTCConfig(
-              playEnabled: true,
-              pauseEnabled: true,
-              nextEnabled: true,
-              prevEnabled: true,
-              stopEnabled: false,
-              fastForwardEnabled: false,
-              rewindEnabled: false,
-            ),
+            api.smtcDefaultConfig().copyWith(stopEnabled: false),
         _timeline = timeline ??
             const PlaybackTimeline(
               positionMs: 0,
               startTimeMs: 0,
               endTimeMs: 0,
             ),
-        _metadata = metadata ?? const MusicMetadata(),
+        _metadata = metadata ?? _emptyMetadata,
         _shuffleEnabled = shuffleEnabled ?? false,
         _repeatMode = repeatMode ?? RepeatMode.none {
     _buttonPressedStream = api.smtcButtonPressEvent(internal: _internal).map((event) => PressedButton.fromString(event)).asBroadcastStream();
@@ -144,7 +142,7 @@ class SMTCWindows {
   }
 
   Future<void> clearMetadata() {
-    _metadata = const MusicMetadata();
+    _metadata = _emptyMetadata;
     return api.smtcClearMetadata(internal: _internal);
   }
 
@@ -220,7 +218,7 @@ class SMTCWindows {
   }
 
   Future<void> setThumbnail(String thumbnail) {
-    return updateMetadata(metadata.copyWith(thumbnail: thumbnail));
+    return updateMetadata(metadata.copyWith(thumbnail: [thumbnail]));
   }
 
   Future<void> setPosition(Duration position) {
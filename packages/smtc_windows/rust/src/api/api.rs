@@ -1,21 +1,155 @@
+use std::collections::HashMap;
+
 use crate::frb_generated::{RustOpaque, StreamSink};
+use flutter_rust_bridge::DartFnFuture;
 
+use crate::internal::diagnostics::{self, Capabilities, Diagnostics};
+use crate::internal::error;
+use crate::internal::events::{
+    ButtonPress, EnabledChange, PositionChangeRequest, SmtcButton, SmtcEvent, StateChange,
+};
+use crate::internal::handlers::HandlerCounts;
+use crate::internal::init::SmtcInit;
 use crate::internal::playback_status::PlaybackStatus;
-use crate::internal::{config::SMTCConfig, metadata::MusicMetadata, timeline::PlaybackTimeline};
+use crate::internal::repeat_mode::RepeatMode;
+use crate::internal::session_info::SessionInfo;
+use crate::internal::smtc_internal;
+use crate::internal::task;
+use crate::internal::thumbnail::{self, ThumbnailResult};
+use crate::internal::{
+    config::SMTCConfig,
+    metadata::{validate_metadata, ClearFlags, MetadataField, MetadataReport, MusicMetadata},
+    timeline::PlaybackTimeline,
+};
 
 pub type SMTCInternal = crate::internal::smtc_internal::SMTCInternal;
+pub type EventSubscription = crate::internal::smtc_internal::EventSubscription;
+pub type CancellationToken = crate::internal::task::CancellationToken;
 
 #[flutter_rust_bridge::frb(sync)]
 pub fn smtc_new(enabled: Option<bool>) -> anyhow::Result<RustOpaque<SMTCInternal>> {
-    let internal = SMTCInternal::new(enabled)?;
+    let internal = SMTCInternal::new(enabled).map_err(error::classify)?;
+    Ok(RustOpaque::new(internal))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_new_with_config(
+    config: SMTCConfig,
+    enabled: Option<bool>,
+) -> anyhow::Result<RustOpaque<SMTCInternal>> {
+    let internal = SMTCInternal::with_config(config, enabled).map_err(error::classify)?;
     Ok(RustOpaque::new(internal))
 }
 
+/// Like `smtc_new_with_config`, but the player lives on, and is only called
+/// from, a dedicated STA thread.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_new_on_sta_thread(
+    config: SMTCConfig,
+    enabled: Option<bool>,
+) -> anyhow::Result<RustOpaque<SMTCInternal>> {
+    let internal = SMTCInternal::on_sta_thread(config, enabled).map_err(error::classify)?;
+    Ok(RustOpaque::new(internal))
+}
+
+/// The config a new control starts with, for building one from Dart.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_default_config() -> SMTCConfig {
+    SMTCConfig::default()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_new_with_init(init: SmtcInit) -> anyhow::Result<RustOpaque<SMTCInternal>> {
+    let internal = SMTCInternal::with_init(init).map_err(error::classify)?;
+    Ok(RustOpaque::new(internal))
+}
+
+/// Calls `callback` with each button press until the returned subscription
+/// is cancelled or disposed, for setups that can't listen to a stream. Each
+/// call waits for the previous one to complete, on a thread of its own, so
+/// the press is never held up waiting on Dart.
+pub fn smtc_on_button(
+    internal: RustOpaque<SMTCInternal>,
+    callback: impl Fn(SmtcButton) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> anyhow::Result<RustOpaque<EventSubscription>> {
+    let subscription = internal
+        .on_button(task::fire_in_order(callback))
+        .map_err(error::classify)?;
+    Ok(RustOpaque::new(subscription))
+}
+
+pub fn smtc_on_position_change(
+    internal: RustOpaque<SMTCInternal>,
+    callback: impl Fn(PositionChangeRequest) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> anyhow::Result<RustOpaque<EventSubscription>> {
+    let subscription = internal
+        .on_position_change(task::fire_in_order(callback))
+        .map_err(error::classify)?;
+    Ok(RustOpaque::new(subscription))
+}
+
+pub fn smtc_on_shuffle_change(
+    internal: RustOpaque<SMTCInternal>,
+    callback: impl Fn(bool) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> anyhow::Result<RustOpaque<EventSubscription>> {
+    let subscription = internal
+        .on_shuffle_change(task::fire_in_order(callback))
+        .map_err(error::classify)?;
+    Ok(RustOpaque::new(subscription))
+}
+
+pub fn smtc_on_repeat_mode_change(
+    internal: RustOpaque<SMTCInternal>,
+    callback: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> anyhow::Result<RustOpaque<EventSubscription>> {
+    let subscription = internal
+        .on_repeat_mode_change(task::fire_in_order(callback))
+        .map_err(error::classify)?;
+    Ok(RustOpaque::new(subscription))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_cancel_subscription(subscription: RustOpaque<EventSubscription>) {
+    subscription.cancel()
+}
+
+/// See `SMTCInternal::set_event_thread`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_set_event_thread(
+    internal: RustOpaque<SMTCInternal>,
+    dedicated: bool,
+) -> anyhow::Result<()> {
+    internal
+        .set_event_thread(dedicated)
+        .map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_current_config(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<SMTCConfig> {
+    internal.current_config().map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_freeze(internal: RustOpaque<SMTCInternal>) {
+    internal.freeze()
+}
+
+pub fn smtc_thaw(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.thaw().map_err(error::classify)
+}
+
+pub fn smtc_switch_session_full(
+    internal: RustOpaque<SMTCInternal>,
+    init: SmtcInit,
+) -> anyhow::Result<()> {
+    internal.switch_session_full(init).map_err(error::classify)
+}
+
 pub fn smtc_update_config(
     internal: RustOpaque<SMTCInternal>,
     config: SMTCConfig,
 ) -> anyhow::Result<()> {
-    internal.update_config(config)
+    internal.update_config(config).map_err(error::classify)
 }
 
 pub fn smtc_update_metadata(
@@ -23,75 +157,632 @@ pub fn smtc_update_metadata(
     metadata: MusicMetadata,
     app_id: Option<String>,
 ) -> anyhow::Result<()> {
-    internal.update_metadata(metadata, app_id)
+    internal
+        .update_metadata(metadata, app_id)
+        .map_err(error::classify)
+}
+
+/// See `SMTCInternal::update_metadata_silently`.
+pub fn smtc_update_metadata_silently(
+    internal: RustOpaque<SMTCInternal>,
+    metadata: MusicMetadata,
+    app_id: Option<String>,
+) -> anyhow::Result<()> {
+    internal
+        .update_metadata_silently(metadata, app_id)
+        .map_err(error::classify)
+}
+
+pub fn smtc_update_metadata_verified(
+    internal: RustOpaque<SMTCInternal>,
+    metadata: MusicMetadata,
+    app_id: Option<String>,
+) -> anyhow::Result<MetadataReport> {
+    internal
+        .update_metadata_verified(metadata, app_id)
+        .map_err(error::classify)
+}
+
+pub fn smtc_update_metadata_map(
+    internal: RustOpaque<SMTCInternal>,
+    fields: HashMap<String, String>,
+    app_id: Option<String>,
+) -> anyhow::Result<()> {
+    internal
+        .update_metadata_map(fields, app_id)
+        .map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_set_queue(internal: RustOpaque<SMTCInternal>, queue: Vec<MusicMetadata>) {
+    internal.set_queue(queue)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_queue(internal: RustOpaque<SMTCInternal>) -> Vec<MusicMetadata> {
+    internal.queue()
+}
+
+pub fn smtc_set_placeholder(
+    internal: RustOpaque<SMTCInternal>,
+    title: String,
+    subtitle: Option<String>,
+    thumbnail: Option<String>,
+) -> anyhow::Result<()> {
+    internal
+        .set_placeholder(title, subtitle, thumbnail)
+        .map_err(error::classify)
+}
+
+/// Creates a token for cancelling `smtc_update_metadata_async`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_cancellation_token() -> RustOpaque<CancellationToken> {
+    RustOpaque::new(CancellationToken::new())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_cancel(token: RustOpaque<CancellationToken>) {
+    token.cancel()
+}
+
+/// Resolves to `true` once the metadata is shown, or `false` if `token` or
+/// a later metadata update cancelled it first.
+pub async fn smtc_update_metadata_async(
+    internal: RustOpaque<SMTCInternal>,
+    metadata: MusicMetadata,
+    app_id: Option<String>,
+    token: RustOpaque<CancellationToken>,
+) -> anyhow::Result<bool> {
+    internal
+        .update_metadata_async(metadata, app_id, (*token).clone())
+        .await
+        .map_err(error::classify)
+}
+
+pub fn smtc_update_metadata_with(
+    internal: RustOpaque<SMTCInternal>,
+    metadata: MusicMetadata,
+    app_id: Option<String>,
+    clear: ClearFlags,
+) -> anyhow::Result<()> {
+    internal
+        .update_metadata_with(metadata, app_id, clear)
+        .map_err(error::classify)
+}
+
+pub fn smtc_switch_session(
+    internal: RustOpaque<SMTCInternal>,
+    app_id: String,
+    metadata: MusicMetadata,
+) -> anyhow::Result<()> {
+    internal
+        .switch_session(app_id, metadata)
+        .map_err(error::classify)
+}
+
+pub fn smtc_preview_metadata(
+    internal: RustOpaque<SMTCInternal>,
+    metadata: MusicMetadata,
+    revert_after_ms: u64,
+) -> anyhow::Result<()> {
+    internal
+        .preview_metadata(metadata, revert_after_ms)
+        .map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_confirm_preview(internal: RustOpaque<SMTCInternal>) -> bool {
+    internal.confirm_preview()
+}
+
+/// Returns the problems found in `metadata`, empty when it is valid.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_validate_metadata(metadata: MusicMetadata) -> Vec<String> {
+    validate_metadata(&metadata).err().unwrap_or_default()
+}
+
+/// Shuts down every SMTC instance, for calling on app exit. Safe to call
+/// more than once.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_shutdown() {
+    smtc_internal::shutdown()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_capabilities() -> Capabilities {
+    diagnostics::capabilities()
+}
+
+/// Versions and capabilities to include in bug reports.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_diagnostics() -> Diagnostics {
+    diagnostics::diagnostics()
+}
+
+/// MIME types of the image formats thumbnails can be decoded from on this
+/// machine.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_supported_thumbnail_formats() -> anyhow::Result<Vec<String>> {
+    thumbnail::supported_formats().map_err(error::classify)
+}
+
+/// Sets the thumbnail from a raw Win32 file `HANDLE` opened with read access.
+///
+/// The handle is only borrowed: it stays open and owned by the caller, but
+/// its file pointer is moved to the end of the data. Handles that are
+/// closed or not disk files are rejected. Windows only.
+#[cfg(windows)]
+pub fn smtc_set_thumbnail_from_handle(
+    internal: RustOpaque<SMTCInternal>,
+    handle: i64,
+) -> anyhow::Result<()> {
+    // SAFETY: Dart passes a handle it owns and keeps open for the call;
+    // `file_from_handle` rejects values that aren't open disk files.
+    let file = unsafe { thumbnail::file_from_handle(handle as std::os::windows::io::RawHandle) }
+        .map_err(error::classify)?;
+    internal
+        .set_thumbnail_from_file(file)
+        .map_err(error::classify)
+}
+
+/// Returns the average ARGB color of the art once shown, or `None` if it
+/// couldn't be loaded.
+pub fn smtc_set_thumbnail_with_color(
+    internal: RustOpaque<SMTCInternal>,
+    source: String,
+) -> anyhow::Result<Option<u32>> {
+    internal
+        .set_thumbnail_with_color(&source)
+        .map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_share_thumbnail_cache(
+    internal: RustOpaque<SMTCInternal>,
+    other: RustOpaque<SMTCInternal>,
+) {
+    internal.share_thumbnail_cache(&other)
+}
+
+/// Loads every thumbnail through `resolver`, which returns the image bytes
+/// for a source or `None` if it can't load it. Art it doesn't return within
+/// `thumbnail_budget_ms`, or 10 seconds without a budget, isn't shown.
+pub fn smtc_set_thumbnail_resolver(
+    internal: RustOpaque<SMTCInternal>,
+    resolver: impl Fn(String) -> DartFnFuture<Option<Vec<u8>>> + Send + Sync + 'static,
+) {
+    internal.set_thumbnail_resolver(move |source, timeout| {
+        task::block_on_timeout(resolver(source.to_string()), timeout)
+            .ok_or_else(|| anyhow::anyhow!("the resolver didn't answer within {timeout:?}"))?
+            .ok_or_else(|| anyhow::anyhow!("the resolver returned no data"))
+    })
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_clear_thumbnail_resolver(internal: RustOpaque<SMTCInternal>) {
+    internal.clear_thumbnail_resolver()
+}
+
+/// Resolves to `true` once the art is shown, or `false` if it couldn't be
+/// loaded.
+pub async fn smtc_set_thumbnail_async(
+    internal: RustOpaque<SMTCInternal>,
+    source: String,
+) -> anyhow::Result<bool> {
+    internal
+        .set_thumbnail_async(source)
+        .await
+        .map_err(error::classify)
+}
+
+pub fn smtc_prefetch_thumbnail(
+    internal: RustOpaque<SMTCInternal>,
+    source: String,
+) -> anyhow::Result<bool> {
+    internal
+        .prefetch_thumbnail(&source)
+        .map_err(error::classify)
+}
+
+/// Always fails, see `SMTCInternal::set_session_icon`.
+pub fn smtc_set_session_icon(
+    internal: RustOpaque<SMTCInternal>,
+    source: String,
+) -> anyhow::Result<()> {
+    internal.set_session_icon(&source).map_err(error::classify)
+}
+
+pub fn smtc_thumbnail_result_event(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<ThumbnailResult>,
+) {
+    internal.thumbnail_result_event(sink)
+}
+
+pub fn smtc_push_title_override(
+    internal: RustOpaque<SMTCInternal>,
+    title: String,
+) -> anyhow::Result<()> {
+    internal.push_title_override(title).map_err(error::classify)
+}
+
+pub fn smtc_clear_title_override(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.clear_title_override().map_err(error::classify)
+}
+
+pub fn smtc_set_thumbnail_from_token(
+    internal: RustOpaque<SMTCInternal>,
+    token: String,
+) -> anyhow::Result<()> {
+    internal
+        .set_thumbnail_from_token(&token)
+        .map_err(error::classify)
+}
+
+pub fn smtc_current_thumbnail_bytes(
+    internal: RustOpaque<SMTCInternal>,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    internal.current_thumbnail_bytes().map_err(error::classify)
 }
 
 pub fn smtc_clear_metadata(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
-    internal.clear_metadata()
+    internal.clear_metadata().map_err(error::classify)
+}
+
+pub fn smtc_clear_metadata_fields(
+    internal: RustOpaque<SMTCInternal>,
+    fields: Vec<MetadataField>,
+) -> anyhow::Result<()> {
+    internal
+        .clear_metadata_fields(fields)
+        .map_err(error::classify)
+}
+
+pub fn smtc_clear_app_media_id(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.clear_app_media_id().map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_session_info(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<SessionInfo> {
+    internal.session_info().map_err(error::classify)
+}
+
+pub fn smtc_is_active_session(internal: RustOpaque<SMTCInternal>) -> bool {
+    internal.is_active_session()
 }
 
 pub fn smtc_update_timeline(
     internal: RustOpaque<SMTCInternal>,
     timeline: PlaybackTimeline,
 ) -> anyhow::Result<()> {
-    internal.update_timeline(timeline)
+    internal.update_timeline(timeline).map_err(error::classify)
+}
+
+pub fn smtc_clear_timeline(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.clear_timeline().map_err(error::classify)
+}
+
+pub fn smtc_set_duration(
+    internal: RustOpaque<SMTCInternal>,
+    duration_ms: i64,
+) -> anyhow::Result<()> {
+    internal.set_duration(duration_ms).map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_current_timeline(internal: RustOpaque<SMTCInternal>) -> Option<PlaybackTimeline> {
+    internal.current_timeline()
+}
+
+pub fn smtc_set_playback_rate(internal: RustOpaque<SMTCInternal>, rate: f64) -> anyhow::Result<()> {
+    internal.set_playback_rate(rate).map_err(error::classify)
+}
+
+/// Makes the position timer ask `provider` for the position on each tick.
+/// A tick `provider` doesn't answer within the timer interval is skipped.
+pub fn smtc_set_position_provider(
+    internal: RustOpaque<SMTCInternal>,
+    provider: impl Fn() -> DartFnFuture<i64> + Send + Sync + 'static,
+) {
+    internal.set_position_provider(move |timeout| {
+        task::block_on_timeout(provider(), timeout).ok_or_else(|| {
+            anyhow::anyhow!("the position provider didn't answer within {timeout:?}")
+        })
+    })
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_clear_position_provider(internal: RustOpaque<SMTCInternal>) {
+    internal.clear_position_provider()
+}
+
+pub fn smtc_start_position_timer(
+    internal: RustOpaque<SMTCInternal>,
+    interval_ms: u64,
+) -> anyhow::Result<()> {
+    internal
+        .start_position_timer(interval_ms)
+        .map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_stop_position_timer(internal: RustOpaque<SMTCInternal>) {
+    internal.stop_position_timer()
+}
+
+pub fn smtc_set_seekable(internal: RustOpaque<SMTCInternal>, seekable: bool) -> anyhow::Result<()> {
+    internal.set_seekable(seekable).map_err(error::classify)
+}
+
+pub fn smtc_update_timeline_simple(
+    internal: RustOpaque<SMTCInternal>,
+    position_ms: i64,
+    duration_ms: i64,
+) -> anyhow::Result<()> {
+    internal
+        .update_timeline_simple(position_ms, duration_ms)
+        .map_err(error::classify)
+}
+
+/// Resolves to whether the position was far enough off to be updated.
+pub fn smtc_reconcile_position(
+    internal: RustOpaque<SMTCInternal>,
+    actual_ms: i64,
+) -> anyhow::Result<bool> {
+    internal
+        .reconcile_position(actual_ms)
+        .map_err(error::classify)
+}
+
+pub fn smtc_set_elapsed(
+    internal: RustOpaque<SMTCInternal>,
+    position_ms: i64,
+) -> anyhow::Result<()> {
+    internal.set_elapsed(position_ms).map_err(error::classify)
+}
+
+pub fn smtc_update_position(
+    internal: RustOpaque<SMTCInternal>,
+    position_ms: i64,
+) -> anyhow::Result<()> {
+    internal
+        .update_position(position_ms)
+        .map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_mark_playback_start(internal: RustOpaque<SMTCInternal>) {
+    internal.mark_playback_start()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_elapsed_since_start_ms(internal: RustOpaque<SMTCInternal>) -> Option<i64> {
+    internal.elapsed_since_start_ms()
 }
 
 pub fn smtc_update_playback_status(
     internal: RustOpaque<SMTCInternal>,
     status: PlaybackStatus,
 ) -> anyhow::Result<()> {
-    internal.update_playback_status(status)
+    internal
+        .update_playback_status(status)
+        .map_err(error::classify)
+}
+
+pub fn smtc_set_buffering(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.set_buffering().map_err(error::classify)
 }
 
 pub fn smtc_update_shuffle(
     internal: RustOpaque<SMTCInternal>,
     shuffle: bool,
 ) -> anyhow::Result<()> {
-    internal.update_shuffle(shuffle)
+    internal.update_shuffle(shuffle).map_err(error::classify)
 }
 
 pub fn smtc_update_repeat_mode(
     internal: RustOpaque<SMTCInternal>,
     repeat_mode: String,
 ) -> anyhow::Result<()> {
-    internal.update_repeat_mode(repeat_mode)
+    internal
+        .update_repeat_mode(repeat_mode)
+        .map_err(error::classify)
+}
+
+pub fn smtc_update_shuffle_repeat(
+    internal: RustOpaque<SMTCInternal>,
+    shuffle: bool,
+    repeat: RepeatMode,
+) -> anyhow::Result<()> {
+    internal
+        .update_shuffle_repeat(shuffle, repeat)
+        .map_err(error::classify)
 }
 
 pub fn smtc_enable_smtc(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
-    internal.enable_smtc()
+    internal.enable_smtc().map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_is_enabled(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<bool> {
+    internal.is_enabled().map_err(error::classify)
+}
+
+/// `System` changes are only noticed when `smtc_is_enabled` is called; see
+/// `SMTCInternal::enabled_change_event`.
+pub fn smtc_enabled_change_event(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<EnabledChange>,
+) {
+    internal.enabled_change_event(sink)
+}
+
+pub fn smtc_refresh_event(internal: RustOpaque<SMTCInternal>, sink: StreamSink<bool>) {
+    internal.refresh_event(sink)
+}
+
+pub fn smtc_reapply_state(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.reapply_state().map_err(error::classify)
+}
+
+/// See `SMTCInternal::refresh`.
+pub fn smtc_refresh(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.refresh().map_err(error::classify)
+}
+
+pub fn smtc_state_changed_event(internal: RustOpaque<SMTCInternal>, sink: StreamSink<StateChange>) {
+    internal.state_changed_event(sink)
 }
 
 pub fn smtc_disable_smtc(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
-    internal.disable_smtc()
+    internal.disable_smtc().map_err(error::classify)
+}
+
+pub fn smtc_idle(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.idle().map_err(error::classify)
 }
 
 pub fn smtc_button_press_event(
     internal: RustOpaque<SMTCInternal>,
     sink: StreamSink<String>,
 ) -> anyhow::Result<()> {
-    internal.button_press_event(sink)
+    internal.button_press_event(sink).map_err(error::classify)
+}
+
+pub fn smtc_record_toggle_event(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<bool>,
+) -> anyhow::Result<()> {
+    internal.record_toggle_event(sink).map_err(error::classify)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_set_recording(internal: RustOpaque<SMTCInternal>, recording: bool) {
+    internal.set_recording(recording)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_is_recording(internal: RustOpaque<SMTCInternal>) -> bool {
+    internal.is_recording()
+}
+
+pub fn smtc_channel_change_event(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<i32>,
+) -> anyhow::Result<()> {
+    internal.channel_change_event(sink).map_err(error::classify)
+}
+
+pub fn smtc_button_press_event_typed(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<ButtonPress>,
+) -> anyhow::Result<()> {
+    internal
+        .button_press_event_typed(sink)
+        .map_err(error::classify)
 }
 
 pub fn smtc_position_change_request_event(
     internal: RustOpaque<SMTCInternal>,
     sink: StreamSink<i64>,
 ) -> anyhow::Result<()> {
-    internal.position_change_request_event(sink)
+    internal
+        .position_change_request_event(sink)
+        .map_err(error::classify)
+}
+
+/// Each event is the requested position in milliseconds as an 8 byte
+/// little-endian integer.
+pub fn smtc_position_change_request_event_packed(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<Vec<u8>>,
+) -> anyhow::Result<()> {
+    internal
+        .position_change_request_event_packed(sink)
+        .map_err(error::classify)
+}
+
+pub fn smtc_position_change_request_event_typed(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<PositionChangeRequest>,
+) -> anyhow::Result<()> {
+    internal
+        .position_change_request_event_typed(sink)
+        .map_err(error::classify)
 }
 
 pub fn smtc_shuffle_request_event(
     internal: RustOpaque<SMTCInternal>,
     sink: StreamSink<bool>,
 ) -> anyhow::Result<()> {
-    internal.shuffle_request_event(sink)
+    internal
+        .shuffle_request_event(sink)
+        .map_err(error::classify)
 }
 
 pub fn smtc_repeat_mode_request_event(
     internal: RustOpaque<SMTCInternal>,
     sink: StreamSink<String>,
 ) -> anyhow::Result<()> {
-    internal.repeat_mode_request_event(sink)
+    internal
+        .repeat_mode_request_event(sink)
+        .map_err(error::classify)
+}
+
+pub fn smtc_unified_event_stream(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<SmtcEvent>,
+) -> anyhow::Result<()> {
+    internal.unified_event_stream(sink).map_err(error::classify)
+}
+
+pub fn smtc_media_error_event(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<String>,
+) -> anyhow::Result<()> {
+    internal.media_error_event(sink).map_err(error::classify)
+}
+
+pub fn smtc_register_all_events(
+    internal: RustOpaque<SMTCInternal>,
+    sink: StreamSink<SmtcEvent>,
+) -> anyhow::Result<()> {
+    internal.register_all_events(sink).map_err(error::classify)
+}
+
+pub fn smtc_replay_last_state(internal: RustOpaque<SMTCInternal>, sink: StreamSink<SmtcEvent>) {
+    internal.replay_last_state(sink)
+}
+
+pub fn smtc_raw_event_trace(internal: RustOpaque<SMTCInternal>, sink: StreamSink<String>) {
+    internal.raw_event_trace(sink)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_debug_dump(internal: RustOpaque<SMTCInternal>) -> String {
+    internal.debug_dump()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_last_button_press_ms(
+    internal: RustOpaque<SMTCInternal>,
+    button: SmtcButton,
+) -> Option<u64> {
+    internal.last_button_press_ms(button)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_handler_count(internal: RustOpaque<SMTCInternal>) -> usize {
+    internal.handler_count()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn smtc_handler_counts(internal: RustOpaque<SMTCInternal>) -> HandlerCounts {
+    internal.handler_counts()
+}
+
+pub fn smtc_remove_handlers(internal: RustOpaque<SMTCInternal>) -> anyhow::Result<()> {
+    internal.remove_handlers().map_err(error::classify)
 }
 
 #[flutter_rust_bridge::frb(init)]
@@ -0,0 +1,74 @@
+use crate::frb_generated::{RustOpaque, StreamSink};
+
+use crate::internal::error;
+use crate::internal::global_sessions::GlobalSessionSnapshot;
+
+pub type GlobalSessions = crate::internal::global_sessions::GlobalSessions;
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn global_sessions_new() -> anyhow::Result<RustOpaque<GlobalSessions>> {
+    let sessions = GlobalSessions::new().map_err(error::classify)?;
+    Ok(RustOpaque::new(sessions))
+}
+
+pub fn global_sessions_list(
+    sessions: RustOpaque<GlobalSessions>,
+) -> anyhow::Result<Vec<GlobalSessionSnapshot>> {
+    sessions.sessions().map_err(error::classify)
+}
+
+pub fn global_sessions_current(
+    sessions: RustOpaque<GlobalSessions>,
+) -> Option<GlobalSessionSnapshot> {
+    sessions.current_session()
+}
+
+pub fn global_sessions_changed_event(
+    sessions: RustOpaque<GlobalSessions>,
+    sink: StreamSink<Vec<GlobalSessionSnapshot>>,
+) -> anyhow::Result<()> {
+    sessions
+        .sessions_changed_event(sink)
+        .map_err(error::classify)
+}
+
+pub fn global_sessions_media_changed_event(
+    sessions: RustOpaque<GlobalSessions>,
+    sink: StreamSink<Option<GlobalSessionSnapshot>>,
+) -> anyhow::Result<()> {
+    sessions
+        .global_media_changed_event(sink)
+        .map_err(error::classify)
+}
+
+pub fn global_sessions_try_play(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_play()
+}
+
+pub fn global_sessions_try_pause(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_pause()
+}
+
+pub fn global_sessions_try_toggle_play_pause(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_toggle_play_pause()
+}
+
+pub fn global_sessions_try_stop(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_stop()
+}
+
+pub fn global_sessions_try_skip_next(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_skip_next()
+}
+
+pub fn global_sessions_try_skip_previous(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_skip_previous()
+}
+
+pub fn global_sessions_try_fast_forward(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_fast_forward()
+}
+
+pub fn global_sessions_try_rewind(sessions: RustOpaque<GlobalSessions>) -> bool {
+    sessions.try_rewind()
+}
@@ -1 +1,2 @@
-pub mod api;
\ No newline at end of file
+pub mod api;
+pub mod global_sessions;
\ No newline at end of file
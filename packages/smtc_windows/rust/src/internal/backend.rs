@@ -0,0 +1,884 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use windows::core::HSTRING;
+use windows::{
+    Foundation::TypedEventHandler,
+    Media::{
+        AutoRepeatModeChangeRequestedEventArgs, MediaPlaybackAutoRepeatMode, MediaPlaybackStatus,
+        Playback::{MediaPlayer, MediaPlayerError, MediaPlayerFailedEventArgs},
+        PlaybackPositionChangeRequestedEventArgs, ShuffleEnabledChangeRequestedEventArgs,
+        SystemMediaTransportControls, SystemMediaTransportControlsButton,
+        SystemMediaTransportControlsButtonPressedEventArgs,
+        SystemMediaTransportControlsDisplayUpdater, SystemMediaTransportControlsTimelineProperties,
+    },
+    Storage::Streams::RandomAccessStreamReference,
+};
+
+use super::{
+    dispatch::EventThread, events::SmtcButton, handlers::HandlerKind, metadata::MediaType,
+    playback_status::PlaybackStatus, repeat_mode::RepeatMode, timeline::PlaybackTimeline,
+};
+
+/// One change to the control. Everything `SMTCInternal` shows is sent to
+/// the backend as these, see `SmtcBackend::apply`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackendCall {
+    SetEnabled(bool),
+    /// `Record` and `Favorite` both set the Record button.
+    SetButtonEnabled(SmtcButton, bool),
+    SetPlaybackStatus(PlaybackStatus),
+    /// `None` removes the timeline, hiding the seek bar.
+    SetTimeline(Option<PlaybackTimeline>),
+    SetShuffle(bool),
+    SetRepeatMode(RepeatMode),
+    SetPlaybackRate(f64),
+    /// `MediaPlayer::Play` or `Pause`, for `mirror_playback_status`.
+    SetPlayerPlaying(bool),
+    /// `DisplayUpdater::ClearAll`.
+    ClearDisplay,
+    SetMediaType(MediaType),
+    /// An empty id clears it.
+    SetAppMediaId(String),
+    SetText(DisplayField, String),
+    /// Replaces the genres.
+    SetGenres(Vec<String>),
+    SetTrackNumber(u32),
+    SetThumbnail(Option<RandomAccessStreamReference>),
+    /// Shows the display changes made since the last one.
+    UpdateDisplay,
+}
+
+/// The text properties of the display updater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplayField {
+    MusicTitle,
+    MusicArtist,
+    MusicAlbum,
+    MusicAlbumArtist,
+    VideoTitle,
+    VideoSubtitle,
+    ImageTitle,
+    ImageSubtitle,
+}
+
+/// Receives the value of a control request, or `None` if WinRT raised it
+/// without args.
+pub type Handler<T> = Box<dyn Fn(Option<T>) + Send + Sync>;
+
+/// A `MediaPlayer::MediaFailed` event. Values that couldn't be read are
+/// `None`, `0` and empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaFailure {
+    pub error: Option<MediaPlayerError>,
+    pub code: i32,
+    pub message: String,
+}
+
+/// What `SMTCInternal` drives: the WinRT controls through `WinRtBackend`,
+/// or a `MockBackend` for running without a display, e.g. in CI. Handlers
+/// return a token for `remove_handler`.
+pub trait SmtcBackend: Send + Sync + std::fmt::Debug {
+    /// Applies `calls` in order, stopping at the first one that fails.
+    fn apply(&self, calls: &[BackendCall]) -> anyhow::Result<()>;
+
+    fn is_enabled(&self) -> anyhow::Result<bool>;
+    fn is_button_enabled(&self, button: SmtcButton) -> anyhow::Result<bool>;
+    fn playback_status(&self) -> anyhow::Result<PlaybackStatus>;
+    fn shuffle(&self) -> anyhow::Result<bool>;
+    fn repeat_mode(&self) -> anyhow::Result<RepeatMode>;
+    fn app_media_id(&self) -> anyhow::Result<String>;
+    fn text(&self, field: DisplayField) -> anyhow::Result<String>;
+    fn track_number(&self) -> anyhow::Result<u32>;
+    /// `None` without a thumbnail.
+    fn thumbnail(&self) -> anyhow::Result<Option<RandomAccessStreamReference>>;
+
+    fn on_button_pressed(
+        &self,
+        handler: Handler<SystemMediaTransportControlsButton>,
+    ) -> anyhow::Result<i64>;
+    /// The requested position is in 100ns ticks.
+    fn on_position_change_requested(&self, handler: Handler<i64>) -> anyhow::Result<i64>;
+    fn on_shuffle_requested(&self, handler: Handler<bool>) -> anyhow::Result<i64>;
+    fn on_repeat_mode_requested(
+        &self,
+        handler: Handler<MediaPlaybackAutoRepeatMode>,
+    ) -> anyhow::Result<i64>;
+    fn on_media_failed(&self, handler: Handler<MediaFailure>) -> anyhow::Result<i64>;
+    fn remove_handler(&self, kind: HandlerKind, token: i64) -> anyhow::Result<()>;
+
+    /// Releases the control; nothing works afterwards.
+    fn close(&self) -> anyhow::Result<()>;
+}
+
+/// Drives the `SystemMediaTransportControls` of a `MediaPlayer` owned by
+/// this process.
+#[derive(Debug)]
+pub struct WinRtBackend {
+    media_player: MediaPlayer,
+}
+
+impl WinRtBackend {
+    /// Creates the player with its command manager turned off, so the
+    /// control only shows what the app sets.
+    pub fn new() -> anyhow::Result<Self> {
+        let media_player = MediaPlayer::new()?;
+        media_player.CommandManager()?.SetIsEnabled(false)?;
+        Ok(Self { media_player })
+    }
+
+    pub fn media_player(&self) -> &MediaPlayer {
+        &self.media_player
+    }
+
+    fn smtc(&self) -> windows::core::Result<SystemMediaTransportControls> {
+        self.media_player.SystemMediaTransportControls()
+    }
+
+    fn updater(&self) -> windows::core::Result<SystemMediaTransportControlsDisplayUpdater> {
+        self.smtc()?.DisplayUpdater()
+    }
+}
+
+fn set_button_enabled(
+    smtc: &SystemMediaTransportControls,
+    button: SmtcButton,
+    enabled: bool,
+) -> windows::core::Result<()> {
+    match button {
+        SmtcButton::Play => smtc.SetIsPlayEnabled(enabled),
+        SmtcButton::Pause => smtc.SetIsPauseEnabled(enabled),
+        SmtcButton::Stop => smtc.SetIsStopEnabled(enabled),
+        SmtcButton::Record | SmtcButton::Favorite => smtc.SetIsRecordEnabled(enabled),
+        SmtcButton::FastForward => smtc.SetIsFastForwardEnabled(enabled),
+        SmtcButton::Rewind => smtc.SetIsRewindEnabled(enabled),
+        SmtcButton::Next => smtc.SetIsNextEnabled(enabled),
+        SmtcButton::Previous => smtc.SetIsPreviousEnabled(enabled),
+        SmtcButton::ChannelUp => smtc.SetIsChannelUpEnabled(enabled),
+        SmtcButton::ChannelDown => smtc.SetIsChannelDownEnabled(enabled),
+    }
+}
+
+fn set_text(
+    updater: &SystemMediaTransportControlsDisplayUpdater,
+    field: DisplayField,
+    text: &HSTRING,
+) -> windows::core::Result<()> {
+    match field {
+        DisplayField::MusicTitle => updater.MusicProperties()?.SetTitle(text),
+        DisplayField::MusicArtist => updater.MusicProperties()?.SetArtist(text),
+        DisplayField::MusicAlbum => updater.MusicProperties()?.SetAlbumTitle(text),
+        DisplayField::MusicAlbumArtist => updater.MusicProperties()?.SetAlbumArtist(text),
+        DisplayField::VideoTitle => updater.VideoProperties()?.SetTitle(text),
+        DisplayField::VideoSubtitle => updater.VideoProperties()?.SetSubtitle(text),
+        DisplayField::ImageTitle => updater.ImageProperties()?.SetTitle(text),
+        DisplayField::ImageSubtitle => updater.ImageProperties()?.SetSubtitle(text),
+    }
+}
+
+impl SmtcBackend for WinRtBackend {
+    fn apply(&self, calls: &[BackendCall]) -> anyhow::Result<()> {
+        let smtc = self.smtc()?;
+        let updater = smtc.DisplayUpdater()?;
+
+        for call in calls {
+            match call {
+                BackendCall::SetEnabled(enabled) => smtc.SetIsEnabled(*enabled)?,
+                BackendCall::SetButtonEnabled(button, enabled) => {
+                    set_button_enabled(&smtc, *button, *enabled)?
+                }
+                BackendCall::SetPlaybackStatus(status) => {
+                    smtc.SetPlaybackStatus(status.try_into_winrt()?)?
+                }
+                BackendCall::SetTimeline(timeline) => {
+                    let properties: anyhow::Result<_> = match *timeline {
+                        Some(timeline) => timeline.into(),
+                        None => Ok(SystemMediaTransportControlsTimelineProperties::new()?),
+                    };
+                    smtc.UpdateTimelineProperties(&properties?)?
+                }
+                BackendCall::SetShuffle(shuffle) => smtc.SetShuffleEnabled(*shuffle)?,
+                BackendCall::SetRepeatMode(repeat_mode) => {
+                    smtc.SetAutoRepeatMode((*repeat_mode).into())?
+                }
+                BackendCall::SetPlaybackRate(rate) => smtc.SetPlaybackRate(*rate)?,
+                BackendCall::SetPlayerPlaying(true) => self.media_player.Play()?,
+                BackendCall::SetPlayerPlaying(false) => self.media_player.Pause()?,
+                BackendCall::ClearDisplay => updater.ClearAll()?,
+                BackendCall::SetMediaType(media_type) => updater.SetType((*media_type).into())?,
+                BackendCall::SetAppMediaId(app_id) => {
+                    updater.SetAppMediaId(&HSTRING::from(app_id))?
+                }
+                BackendCall::SetText(field, text) => {
+                    set_text(&updater, *field, &HSTRING::from(text))?
+                }
+                BackendCall::SetGenres(genres) => {
+                    let list = updater.MusicProperties()?.Genres()?;
+                    list.Clear()?;
+                    for genre in genres {
+                        list.Append(&HSTRING::from(genre))?;
+                    }
+                }
+                BackendCall::SetTrackNumber(track_number) => {
+                    updater.MusicProperties()?.SetTrackNumber(*track_number)?
+                }
+                BackendCall::SetThumbnail(Some(thumbnail)) => updater.SetThumbnail(thumbnail)?,
+                BackendCall::SetThumbnail(None) => updater.SetThumbnail(None)?,
+                BackendCall::UpdateDisplay => updater.Update()?,
+            }
+        }
+        Ok(())
+    }
+
+    fn is_enabled(&self) -> anyhow::Result<bool> {
+        Ok(self.smtc()?.IsEnabled()?)
+    }
+
+    fn is_button_enabled(&self, button: SmtcButton) -> anyhow::Result<bool> {
+        let smtc = self.smtc()?;
+        Ok(match button {
+            SmtcButton::Play => smtc.IsPlayEnabled()?,
+            SmtcButton::Pause => smtc.IsPauseEnabled()?,
+            SmtcButton::Stop => smtc.IsStopEnabled()?,
+            SmtcButton::Record | SmtcButton::Favorite => smtc.IsRecordEnabled()?,
+            SmtcButton::FastForward => smtc.IsFastForwardEnabled()?,
+            SmtcButton::Rewind => smtc.IsRewindEnabled()?,
+            SmtcButton::Next => smtc.IsNextEnabled()?,
+            SmtcButton::Previous => smtc.IsPreviousEnabled()?,
+            SmtcButton::ChannelUp => smtc.IsChannelUpEnabled()?,
+            SmtcButton::ChannelDown => smtc.IsChannelDownEnabled()?,
+        })
+    }
+
+    fn playback_status(&self) -> anyhow::Result<PlaybackStatus> {
+        Ok(match self.smtc()?.PlaybackStatus()? {
+            MediaPlaybackStatus::Changing => PlaybackStatus::Changing,
+            MediaPlaybackStatus::Stopped => PlaybackStatus::Stopped,
+            MediaPlaybackStatus::Playing => PlaybackStatus::Playing,
+            MediaPlaybackStatus::Paused => PlaybackStatus::Paused,
+            _ => PlaybackStatus::Closed,
+        })
+    }
+
+    fn shuffle(&self) -> anyhow::Result<bool> {
+        Ok(self.smtc()?.ShuffleEnabled()?)
+    }
+
+    fn repeat_mode(&self) -> anyhow::Result<RepeatMode> {
+        Ok(self.smtc()?.AutoRepeatMode()?.into())
+    }
+
+    fn app_media_id(&self) -> anyhow::Result<String> {
+        Ok(self.updater()?.AppMediaId()?.to_string())
+    }
+
+    fn text(&self, field: DisplayField) -> anyhow::Result<String> {
+        let updater = self.updater()?;
+        let text = match field {
+            DisplayField::MusicTitle => updater.MusicProperties()?.Title()?,
+            DisplayField::MusicArtist => updater.MusicProperties()?.Artist()?,
+            DisplayField::MusicAlbum => updater.MusicProperties()?.AlbumTitle()?,
+            DisplayField::MusicAlbumArtist => updater.MusicProperties()?.AlbumArtist()?,
+            DisplayField::VideoTitle => updater.VideoProperties()?.Title()?,
+            DisplayField::VideoSubtitle => updater.VideoProperties()?.Subtitle()?,
+            DisplayField::ImageTitle => updater.ImageProperties()?.Title()?,
+            DisplayField::ImageSubtitle => updater.ImageProperties()?.Subtitle()?,
+        };
+        Ok(text.to_string())
+    }
+
+    fn track_number(&self) -> anyhow::Result<u32> {
+        Ok(self.updater()?.MusicProperties()?.TrackNumber()?)
+    }
+
+    fn thumbnail(&self) -> anyhow::Result<Option<RandomAccessStreamReference>> {
+        // Fails rather than returning null when there is none.
+        Ok(self.updater()?.Thumbnail().ok())
+    }
+
+    fn on_button_pressed(
+        &self,
+        handler: Handler<SystemMediaTransportControlsButton>,
+    ) -> anyhow::Result<i64> {
+        let handler = TypedEventHandler::<
+            SystemMediaTransportControls,
+            SystemMediaTransportControlsButtonPressedEventArgs,
+        >::new(move |_, args| {
+            handler(args.as_ref().map(|args| args.Button()).transpose()?);
+            Ok(())
+        });
+        Ok(self.smtc()?.ButtonPressed(&handler)?)
+    }
+
+    fn on_position_change_requested(&self, handler: Handler<i64>) -> anyhow::Result<i64> {
+        let handler = TypedEventHandler::<
+            SystemMediaTransportControls,
+            PlaybackPositionChangeRequestedEventArgs,
+        >::new(move |_, args| {
+            let ticks = args
+                .as_ref()
+                .map(|args| args.RequestedPlaybackPosition())
+                .transpose()?
+                .map(|position| position.Duration);
+            handler(ticks);
+            Ok(())
+        });
+        Ok(self.smtc()?.PlaybackPositionChangeRequested(&handler)?)
+    }
+
+    fn on_shuffle_requested(&self, handler: Handler<bool>) -> anyhow::Result<i64> {
+        let handler = TypedEventHandler::<
+            SystemMediaTransportControls,
+            ShuffleEnabledChangeRequestedEventArgs,
+        >::new(move |_, args| {
+            handler(
+                args.as_ref()
+                    .map(|args| args.RequestedShuffleEnabled())
+                    .transpose()?,
+            );
+            Ok(())
+        });
+        Ok(self.smtc()?.ShuffleEnabledChangeRequested(&handler)?)
+    }
+
+    fn on_repeat_mode_requested(
+        &self,
+        handler: Handler<MediaPlaybackAutoRepeatMode>,
+    ) -> anyhow::Result<i64> {
+        let handler = TypedEventHandler::<
+            SystemMediaTransportControls,
+            AutoRepeatModeChangeRequestedEventArgs,
+        >::new(move |_, args| {
+            handler(
+                args.as_ref()
+                    .map(|args| args.RequestedAutoRepeatMode())
+                    .transpose()?,
+            );
+            Ok(())
+        });
+        Ok(self.smtc()?.AutoRepeatModeChangeRequested(&handler)?)
+    }
+
+    fn on_media_failed(&self, handler: Handler<MediaFailure>) -> anyhow::Result<i64> {
+        let handler =
+            TypedEventHandler::<MediaPlayer, MediaPlayerFailedEventArgs>::new(move |_, args| {
+                handler(args.as_ref().map(|args| {
+                    MediaFailure {
+                        error: args.Error().ok(),
+                        code: args
+                            .ExtendedErrorCode()
+                            .map(|code| code.0)
+                            .unwrap_or_default(),
+                        message: args
+                            .ErrorMessage()
+                            .map(|message| message.to_string())
+                            .unwrap_or_default(),
+                    }
+                }));
+                Ok(())
+            });
+        Ok(self.media_player.MediaFailed(&handler)?)
+    }
+
+    fn remove_handler(&self, kind: HandlerKind, token: i64) -> anyhow::Result<()> {
+        let smtc = || self.smtc();
+        match kind {
+            HandlerKind::ButtonPressed => smtc()?.RemoveButtonPressed(token)?,
+            HandlerKind::PositionChangeRequest => {
+                smtc()?.RemovePlaybackPositionChangeRequested(token)?
+            }
+            HandlerKind::ShuffleRequest => smtc()?.RemoveShuffleEnabledChangeRequested(token)?,
+            HandlerKind::RepeatModeRequest => smtc()?.RemoveAutoRepeatModeChangeRequested(token)?,
+            HandlerKind::MediaFailed => self.media_player.RemoveMediaFailed(token)?,
+        }
+        Ok(())
+    }
+
+    fn close(&self) -> anyhow::Result<()> {
+        Ok(self.media_player.Close()?)
+    }
+}
+
+/// A `WinRtBackend` owned by an `EventThread`: the player is created there
+/// and every call to it is made there, whichever thread the app calls from.
+/// Handlers run there too, one at a time.
+#[derive(Debug)]
+pub struct StaBackend {
+    thread: EventThread,
+    backend: Arc<WinRtBackend>,
+}
+
+impl StaBackend {
+    pub fn new(thread: EventThread) -> anyhow::Result<Self> {
+        let backend = Arc::new(thread.call(WinRtBackend::new)??);
+        Ok(Self { thread, backend })
+    }
+
+    pub fn thread(&self) -> &EventThread {
+        &self.thread
+    }
+
+    fn on_thread<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&WinRtBackend) -> anyhow::Result<R> + Send + 'static,
+    ) -> anyhow::Result<R> {
+        let backend = self.backend.clone();
+        self.thread.call(move || f(&backend))?
+    }
+
+    /// Requeues `handler` on the thread, whichever thread WinRT raises the
+    /// event on.
+    fn marshal<T: Send + 'static>(&self, handler: Handler<T>) -> Handler<T> {
+        let thread = self.thread.clone();
+        let handler: Arc<dyn Fn(Option<T>) + Send + Sync> = Arc::from(handler);
+        Box::new(move |args| {
+            let handler = handler.clone();
+            thread.run(move || handler(args));
+        })
+    }
+}
+
+impl Drop for StaBackend {
+    /// Releases the player on its thread, unless a call there still holds it.
+    fn drop(&mut self) {
+        let backend = self.backend.clone();
+        self.thread.run(move || drop(backend));
+    }
+}
+
+impl SmtcBackend for StaBackend {
+    fn apply(&self, calls: &[BackendCall]) -> anyhow::Result<()> {
+        let calls = calls.to_vec();
+        self.on_thread(move |backend| backend.apply(&calls))
+    }
+
+    fn is_enabled(&self) -> anyhow::Result<bool> {
+        self.on_thread(|backend| backend.is_enabled())
+    }
+
+    fn is_button_enabled(&self, button: SmtcButton) -> anyhow::Result<bool> {
+        self.on_thread(move |backend| backend.is_button_enabled(button))
+    }
+
+    fn playback_status(&self) -> anyhow::Result<PlaybackStatus> {
+        self.on_thread(|backend| backend.playback_status())
+    }
+
+    fn shuffle(&self) -> anyhow::Result<bool> {
+        self.on_thread(|backend| backend.shuffle())
+    }
+
+    fn repeat_mode(&self) -> anyhow::Result<RepeatMode> {
+        self.on_thread(|backend| backend.repeat_mode())
+    }
+
+    fn app_media_id(&self) -> anyhow::Result<String> {
+        self.on_thread(|backend| backend.app_media_id())
+    }
+
+    fn text(&self, field: DisplayField) -> anyhow::Result<String> {
+        self.on_thread(move |backend| backend.text(field))
+    }
+
+    fn track_number(&self) -> anyhow::Result<u32> {
+        self.on_thread(|backend| backend.track_number())
+    }
+
+    fn thumbnail(&self) -> anyhow::Result<Option<RandomAccessStreamReference>> {
+        self.on_thread(|backend| backend.thumbnail())
+    }
+
+    fn on_button_pressed(
+        &self,
+        handler: Handler<SystemMediaTransportControlsButton>,
+    ) -> anyhow::Result<i64> {
+        let handler = self.marshal(handler);
+        self.on_thread(move |backend| backend.on_button_pressed(handler))
+    }
+
+    fn on_position_change_requested(&self, handler: Handler<i64>) -> anyhow::Result<i64> {
+        let handler = self.marshal(handler);
+        self.on_thread(move |backend| backend.on_position_change_requested(handler))
+    }
+
+    fn on_shuffle_requested(&self, handler: Handler<bool>) -> anyhow::Result<i64> {
+        let handler = self.marshal(handler);
+        self.on_thread(move |backend| backend.on_shuffle_requested(handler))
+    }
+
+    fn on_repeat_mode_requested(
+        &self,
+        handler: Handler<MediaPlaybackAutoRepeatMode>,
+    ) -> anyhow::Result<i64> {
+        let handler = self.marshal(handler);
+        self.on_thread(move |backend| backend.on_repeat_mode_requested(handler))
+    }
+
+    fn on_media_failed(&self, handler: Handler<MediaFailure>) -> anyhow::Result<i64> {
+        let handler = self.marshal(handler);
+        self.on_thread(move |backend| backend.on_media_failed(handler))
+    }
+
+    fn remove_handler(&self, kind: HandlerKind, token: i64) -> anyhow::Result<()> {
+        self.on_thread(move |backend| backend.remove_handler(kind, token))
+    }
+
+    fn close(&self) -> anyhow::Result<()> {
+        self.on_thread(|backend| backend.close())
+    }
+}
+
+/// What a `MockBackend` currently shows, as the WinRT control would
+/// report it.
+#[derive(Debug, Clone)]
+pub struct MockControl {
+    pub enabled: bool,
+    /// Buttons never set are disabled.
+    pub buttons: HashMap<SmtcButton, bool>,
+    pub status: PlaybackStatus,
+    pub timeline: Option<PlaybackTimeline>,
+    pub shuffle: bool,
+    pub repeat_mode: RepeatMode,
+    pub playback_rate: f64,
+    pub playing: bool,
+    pub media_type: MediaType,
+    pub app_media_id: String,
+    pub text: HashMap<DisplayField, String>,
+    pub genres: Vec<String>,
+    pub track_number: u32,
+    pub thumbnail: Option<RandomAccessStreamReference>,
+    /// Number of `UpdateDisplay` calls so far.
+    pub display_updates: usize,
+}
+
+impl Default for MockControl {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            buttons: HashMap::new(),
+            status: PlaybackStatus::Closed,
+            timeline: None,
+            shuffle: false,
+            repeat_mode: RepeatMode::None,
+            playback_rate: 1.0,
+            playing: false,
+            media_type: MediaType::Unknown,
+            app_media_id: String::new(),
+            text: HashMap::new(),
+            genres: Vec::new(),
+            track_number: 0,
+            thumbnail: None,
+            display_updates: 0,
+        }
+    }
+}
+
+impl MockControl {
+    fn apply(&mut self, call: &BackendCall) {
+        match call.clone() {
+            BackendCall::SetEnabled(enabled) => self.enabled = enabled,
+            BackendCall::SetButtonEnabled(button, enabled) => {
+                self.buttons.insert(record_alias(button), enabled);
+            }
+            BackendCall::SetPlaybackStatus(status) => self.status = status,
+            BackendCall::SetTimeline(timeline) => self.timeline = timeline,
+            BackendCall::SetShuffle(shuffle) => self.shuffle = shuffle,
+            BackendCall::SetRepeatMode(repeat_mode) => self.repeat_mode = repeat_mode,
+            BackendCall::SetPlaybackRate(rate) => self.playback_rate = rate,
+            BackendCall::SetPlayerPlaying(playing) => self.playing = playing,
+            BackendCall::ClearDisplay => {
+                self.media_type = MediaType::Unknown;
+                self.app_media_id.clear();
+                self.text.clear();
+                self.genres.clear();
+                self.track_number = 0;
+                self.thumbnail = None;
+            }
+            BackendCall::SetMediaType(media_type) => self.media_type = media_type,
+            BackendCall::SetAppMediaId(app_id) => self.app_media_id = app_id,
+            BackendCall::SetText(field, text) => {
+                self.text.insert(field, text);
+            }
+            BackendCall::SetGenres(genres) => self.genres = genres,
+            BackendCall::SetTrackNumber(track_number) => self.track_number = track_number,
+            BackendCall::SetThumbnail(thumbnail) => self.thumbnail = thumbnail,
+            BackendCall::UpdateDisplay => self.display_updates += 1,
+        }
+    }
+}
+
+/// Favorite shares the Record button.
+fn record_alias(button: SmtcButton) -> SmtcButton {
+    match button {
+        SmtcButton::Favorite => SmtcButton::Record,
+        button => button,
+    }
+}
+
+#[derive(Clone)]
+enum MockHandler {
+    Button(Arc<dyn Fn(Option<SystemMediaTransportControlsButton>) + Send + Sync>),
+    Position(Arc<dyn Fn(Option<i64>) + Send + Sync>),
+    Shuffle(Arc<dyn Fn(Option<bool>) + Send + Sync>),
+    RepeatMode(Arc<dyn Fn(Option<MediaPlaybackAutoRepeatMode>) + Send + Sync>),
+    MediaFailed(Arc<dyn Fn(Option<MediaFailure>) + Send + Sync>),
+}
+
+impl MockHandler {
+    fn kind(&self) -> HandlerKind {
+        match self {
+            MockHandler::Button(_) => HandlerKind::ButtonPressed,
+            MockHandler::Position(_) => HandlerKind::PositionChangeRequest,
+            MockHandler::Shuffle(_) => HandlerKind::ShuffleRequest,
+            MockHandler::RepeatMode(_) => HandlerKind::RepeatModeRequest,
+            MockHandler::MediaFailed(_) => HandlerKind::MediaFailed,
+        }
+    }
+}
+
+impl std::fmt::Debug for MockHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MockHandler({:?})", self.kind())
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    calls: Vec<BackendCall>,
+    batches: usize,
+    control: MockControl,
+    handlers: Vec<(i64, MockHandler)>,
+    next_token: i64,
+    closed: bool,
+}
+
+/// Records every call and keeps what the control would show instead of
+/// touching WinRT, for testing without a display. Control requests are
+/// raised with `press`, `request_position` and the like, which run the
+/// registered handlers on the calling thread.
+#[derive(Debug, Default)]
+pub struct MockBackend(Mutex<MockState>);
+
+impl MockBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Every call applied so far, in order.
+    pub fn calls(&self) -> Vec<BackendCall> {
+        self.state().calls.clone()
+    }
+
+    /// `calls`, forgetting them, to check only what the next step applies.
+    pub fn take_calls(&self) -> Vec<BackendCall> {
+        std::mem::take(&mut self.state().calls)
+    }
+
+    /// Number of `apply` calls so far, each applying one batch of calls.
+    pub fn batches(&self) -> usize {
+        self.state().batches
+    }
+
+    pub fn control(&self) -> MockControl {
+        self.state().control.clone()
+    }
+
+    /// Changes the enabled state without a call, the way the OS can.
+    pub fn set_enabled_by_system(&self, enabled: bool) {
+        self.state().control.enabled = enabled;
+    }
+
+    /// Drops everything shown but the enabled state and the buttons, without
+    /// a call, the way the overlay can.
+    pub fn clear_by_system(&self) {
+        let mut state = self.state();
+        let control = std::mem::take(&mut state.control);
+        state.control = MockControl {
+            enabled: control.enabled,
+            buttons: control.buttons,
+            ..MockControl::default()
+        };
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state().closed
+    }
+
+    pub fn handler_count(&self) -> usize {
+        self.state().handlers.len()
+    }
+
+    pub fn press(&self, button: SystemMediaTransportControlsButton) {
+        for handler in self.handlers() {
+            if let MockHandler::Button(handler) = handler {
+                handler(Some(button));
+            }
+        }
+    }
+
+    /// Requests a seek to `position_ms`, which handlers receive as ticks.
+    pub fn request_position(&self, position_ms: i64) {
+        for handler in self.handlers() {
+            if let MockHandler::Position(handler) = handler {
+                handler(Some(position_ms * 10_000));
+            }
+        }
+    }
+
+    pub fn request_shuffle(&self, shuffle: bool) {
+        for handler in self.handlers() {
+            if let MockHandler::Shuffle(handler) = handler {
+                handler(Some(shuffle));
+            }
+        }
+    }
+
+    pub fn request_repeat_mode(&self, repeat_mode: MediaPlaybackAutoRepeatMode) {
+        for handler in self.handlers() {
+            if let MockHandler::RepeatMode(handler) = handler {
+                handler(Some(repeat_mode));
+            }
+        }
+    }
+
+    pub fn fail_media(&self, failure: MediaFailure) {
+        for handler in self.handlers() {
+            if let MockHandler::MediaFailed(handler) = handler {
+                handler(Some(failure.clone()));
+            }
+        }
+    }
+
+    /// Raises `kind` without args, as WinRT may.
+    pub fn raise_without_args(&self, kind: HandlerKind) {
+        for handler in self.handlers() {
+            match handler {
+                MockHandler::Button(handler) if kind == HandlerKind::ButtonPressed => handler(None),
+                MockHandler::Position(handler) if kind == HandlerKind::PositionChangeRequest => {
+                    handler(None)
+                }
+                MockHandler::Shuffle(handler) if kind == HandlerKind::ShuffleRequest => {
+                    handler(None)
+                }
+                MockHandler::RepeatMode(handler) if kind == HandlerKind::RepeatModeRequest => {
+                    handler(None)
+                }
+                MockHandler::MediaFailed(handler) if kind == HandlerKind::MediaFailed => {
+                    handler(None)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Copied out, so handlers can call back into the backend.
+    fn handlers(&self) -> Vec<MockHandler> {
+        let state = self.state();
+        state.handlers.iter().map(|(_, h)| h.clone()).collect()
+    }
+
+    fn add_handler(&self, handler: MockHandler) -> anyhow::Result<i64> {
+        let mut state = self.state();
+        state.next_token += 1;
+        let token = state.next_token;
+        state.handlers.push((token, handler));
+        Ok(token)
+    }
+}
+
+impl SmtcBackend for MockBackend {
+    fn apply(&self, calls: &[BackendCall]) -> anyhow::Result<()> {
+        let mut state = self.state();
+        if state.closed {
+            anyhow::bail!("the mock backend is closed");
+        }
+        state.batches += 1;
+        for call in calls {
+            state.control.apply(call);
+            state.calls.push(call.clone());
+        }
+        Ok(())
+    }
+
+    fn is_enabled(&self) -> anyhow::Result<bool> {
+        Ok(self.state().control.enabled)
+    }
+
+    fn is_button_enabled(&self, button: SmtcButton) -> anyhow::Result<bool> {
+        let state = self.state();
+        Ok(state.control.buttons.get(&record_alias(button)) == Some(&true))
+    }
+
+    fn playback_status(&self) -> anyhow::Result<PlaybackStatus> {
+        Ok(self.state().control.status)
+    }
+
+    fn shuffle(&self) -> anyhow::Result<bool> {
+        Ok(self.state().control.shuffle)
+    }
+
+    fn repeat_mode(&self) -> anyhow::Result<RepeatMode> {
+        Ok(self.state().control.repeat_mode)
+    }
+
+    fn app_media_id(&self) -> anyhow::Result<String> {
+        Ok(self.state().control.app_media_id.clone())
+    }
+
+    fn text(&self, field: DisplayField) -> anyhow::Result<String> {
+        let state = self.state();
+        Ok(state.control.text.get(&field).cloned().unwrap_or_default())
+    }
+
+    fn track_number(&self) -> anyhow::Result<u32> {
+        Ok(self.state().control.track_number)
+    }
+
+    fn thumbnail(&self) -> anyhow::Result<Option<RandomAccessStreamReference>> {
+        Ok(self.state().control.thumbnail.clone())
+    }
+
+    fn on_button_pressed(
+        &self,
+        handler: Handler<SystemMediaTransportControlsButton>,
+    ) -> anyhow::Result<i64> {
+        self.add_handler(MockHandler::Button(Arc::from(handler)))
+    }
+
+    fn on_position_change_requested(&self, handler: Handler<i64>) -> anyhow::Result<i64> {
+        self.add_handler(MockHandler::Position(Arc::from(handler)))
+    }
+
+    fn on_shuffle_requested(&self, handler: Handler<bool>) -> anyhow::Result<i64> {
+        self.add_handler(MockHandler::Shuffle(Arc::from(handler)))
+    }
+
+    fn on_repeat_mode_requested(
+        &self,
+        handler: Handler<MediaPlaybackAutoRepeatMode>,
+    ) -> anyhow::Result<i64> {
+        self.add_handler(MockHandler::RepeatMode(Arc::from(handler)))
+    }
+
+    fn on_media_failed(&self, handler: Handler<MediaFailure>) -> anyhow::Result<i64> {
+        self.add_handler(MockHandler::MediaFailed(Arc::from(handler)))
+    }
+
+    fn remove_handler(&self, kind: HandlerKind, token: i64) -> anyhow::Result<()> {
+        self.state()
+            .handlers
+            .retain(|(t, handler)| (*t, handler.kind()) != (token, kind));
+        Ok(())
+    }
+
+    fn close(&self) -> anyhow::Result<()> {
+        let mut state = self.state();
+        state.closed = true;
+        state.handlers.clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// A job run by `Clock::run_every`, again for as long as it returns `true`.
+pub type TimerJob = Box<dyn FnMut() -> bool + Send>;
+
+/// Source of time for everything that measures or waits, so timers can be
+/// driven deterministically in tests.
+pub trait Clock: Debug + Send + Sync {
+    /// Monotonic time since an arbitrary, fixed origin.
+    fn now(&self) -> Duration;
+
+    /// Runs `job` each time `interval` has passed on this clock, until it
+    /// returns `false`. `interval` must not be zero.
+    fn run_every(&self, interval: Duration, job: TimerJob) -> std::io::Result<()>;
+
+    /// Runs `job` once `delay` has passed on this clock.
+    fn run_after(&self, delay: Duration, job: Box<dyn FnOnce() + Send>) -> std::io::Result<()> {
+        let mut job = Some(job);
+        self.run_every(
+            delay,
+            Box::new(move || {
+                if let Some(job) = job.take() {
+                    job();
+                }
+                false
+            }),
+        )
+    }
+}
+
+/// Wall-clock time, measured from when the clock was created. Each timer
+/// gets a thread of its own.
+#[derive(Debug)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    fn run_every(&self, interval: Duration, mut job: TimerJob) -> std::io::Result<()> {
+        std::thread::Builder::new()
+            .name("smtc-timer".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(interval);
+                if !job() {
+                    return;
+                }
+            })?;
+        Ok(())
+    }
+}
+
+struct Timer {
+    due: Duration,
+    interval: Duration,
+    /// Orders timers due at the same time by when they were scheduled.
+    order: u64,
+    job: TimerJob,
+}
+
+#[derive(Default)]
+struct ManualState {
+    now: Duration,
+    timers: Vec<Timer>,
+    scheduled: u64,
+}
+
+impl ManualState {
+    fn schedule(&mut self, due: Duration, interval: Duration, job: TimerJob) {
+        self.scheduled += 1;
+        self.timers.push(Timer {
+            due,
+            interval,
+            order: self.scheduled,
+            job,
+        });
+    }
+}
+
+/// A clock that only moves when `advance` is called. Timers run on the
+/// thread calling `advance`, in the order they fall due, so tests see their
+/// effects as soon as `advance` returns.
+#[derive(Default)]
+pub struct ManualClock {
+    state: Mutex<ManualState>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let until = self.now() + duration;
+        loop {
+            let mut timer = {
+                let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+                let next = state
+                    .timers
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, timer)| timer.due <= until)
+                    .min_by_key(|(_, timer)| (timer.due, timer.order))
+                    .map(|(index, _)| index);
+                let Some(next) = next else {
+                    state.now = until;
+                    return;
+                };
+                let timer = state.timers.remove(next);
+                state.now = timer.due;
+                timer
+            };
+
+            // Run unlocked, so the job can read the time and start timers.
+            if (timer.job)() {
+                let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+                state.schedule(timer.due + timer.interval, timer.interval, timer.job);
+            }
+        }
+    }
+}
+
+impl Debug for ManualClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        f.debug_struct("ManualClock")
+            .field("now", &state.now)
+            .field("timers", &state.timers.len())
+            .finish()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .now
+    }
+
+    fn run_every(&self, interval: Duration, job: TimerJob) -> std::io::Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let due = state.now + interval;
+        state.schedule(due, interval, job);
+        Ok(())
+    }
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_only_moves_when_advanced() {
+        let clock = ManualClock::new();
+        assert_eq!(clock.now(), Duration::ZERO);
+        clock.advance(Duration::from_millis(250));
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(clock.now(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn manual_clock_runs_a_timer_once_it_is_due() {
+        let clock = ManualClock::new();
+        let ran = Arc::new(Mutex::new(false));
+        {
+            let ran = ran.clone();
+            clock
+                .run_after(
+                    Duration::from_millis(100),
+                    Box::new(move || *ran.lock().unwrap() = true),
+                )
+                .unwrap();
+        }
+
+        clock.advance(Duration::from_millis(99));
+        assert!(!*ran.lock().unwrap());
+        clock.advance(Duration::from_millis(1));
+        assert!(*ran.lock().unwrap());
+    }
+
+    #[test]
+    fn manual_clock_repeats_a_timer_at_its_interval_until_it_stops() {
+        let clock = Arc::new(ManualClock::new());
+        let ticks = Arc::new(Mutex::new(Vec::new()));
+        {
+            let clock_in_job = clock.clone();
+            let ticks = ticks.clone();
+            clock
+                .run_every(
+                    Duration::from_millis(100),
+                    Box::new(move || {
+                        let mut ticks = ticks.lock().unwrap();
+                        ticks.push(clock_in_job.now());
+                        ticks.len() < 3
+                    }),
+                )
+                .unwrap();
+        }
+
+        clock.advance(Duration::from_millis(1_000));
+        let ms = |ms| Duration::from_millis(ms);
+        assert_eq!(*ticks.lock().unwrap(), [ms(100), ms(200), ms(300)]);
+        assert_eq!(clock.now(), ms(1_000));
+    }
+}
@@ -1,5 +1,14 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct SMTCConfig{
+use super::{
+  events::{EventSelection, SmtcButton},
+  metadata::MusicMetadata,
+  repeat_mode::RepeatMode,
+  thumbnail::{ThumbnailMode, ThumbnailScheme, ThumbnailTransition},
+};
+
+/// Not `Copy` since `allowed_thumbnail_schemes` became a list; clone it
+/// instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SMTCConfig {
   pub play_enabled: bool,
   pub pause_enabled: bool,
   pub stop_enabled: bool,
@@ -7,6 +16,130 @@ pub struct SMTCConfig{
   pub prev_enabled: bool,
   pub fast_forward_enabled: bool,
   pub rewind_enabled: bool,
+  /// Channel buttons, off by default. Radio-style apps often use them to
+  /// switch stations, see `channel_change_event`.
+  pub channel_up_enabled: bool,
+  pub channel_down_enabled: bool,
+  /// When `false`, `update_metadata` skips resolving thumbnails and clears
+  /// any art already shown. Useful for low-power modes.
+  pub thumbnails_enabled: bool,
+  /// Drives the underlying `MediaPlayer` (`Play`/`Pause`) from
+  /// `update_playback_status` so its `PlaybackSession` follows the status
+  /// shown in the overlay. Only observable once the player has a source.
+  pub mirror_playback_status: bool,
+  /// Makes `update_metadata` reject metadata that fails `validate_metadata`
+  /// instead of applying it.
+  pub strict_metadata: bool,
+  /// On a seek request from the OS, immediately shows the requested position
+  /// with a `Changing` status so the seek bar doesn't jump back while the app
+  /// seeks. The next `update_timeline`/`update_position` call replaces the
+  /// optimistic position and restores the last status. Off by default.
+  pub optimistic_seek: bool,
+  /// Strips control characters (newlines, tabs, ...) from the text fields
+  /// passed to `update_metadata`.
+  pub sanitize_metadata: bool,
+  /// Re-encodes every thumbnail as PNG before handing it to the overlay.
+  /// Costs an extra decode/encode per update, so it is off by default.
+  pub transcode_thumbnails: bool,
+  /// The content type every thumbnail stream reports, e.g. `image/png`, for
+  /// art the overlay doesn't show because its type is detected wrongly
+  /// (such as a CDN sending `application/octet-stream`). Applies to every
+  /// source, after any transcoding. `None` keeps the detected type.
+  pub thumbnail_content_type: Option<String>,
+  /// Thumbnail sources `update_metadata` may load. Sources using any other
+  /// scheme are skipped (and logged), leaving the track without art.
+  pub allowed_thumbnail_schemes: Vec<ThumbnailScheme>,
+  /// Headers sent when fetching http(s) thumbnails, e.g. for art behind
+  /// auth. When empty the overlay loads the url itself.
+  pub thumbnail_headers: Vec<(String, String)>,
+  /// Enables the Record button and reports its presses as
+  /// `SmtcButton::Favorite`. This is a convention some apps use for a
+  /// like action, not something the OS knows about.
+  pub record_as_favorite: bool,
+  /// Presses of the same button closer together than this are reported
+  /// once. `0` turns debouncing off.
+  pub button_debounce_ms: u64,
+  /// On `unified_event_stream` and `register_all_events`, drops an event
+  /// identical to the previous one if it arrives within this many
+  /// milliseconds. `0` turns coalescing off.
+  pub event_coalesce_ms: u64,
+  /// Disables the control this many milliseconds after the status becomes
+  /// `Stopped`, unless another status or new metadata arrives first. Keeps
+  /// the session around briefly for a quick resume. `0` turns it off.
+  pub auto_disable_after_stop_ms: u64,
+  /// How many thumbnails `set_thumbnail_async` loads at once; further loads
+  /// wait in a queue.
+  pub thumbnail_workers: usize,
+  /// Repeat modes the app supports. Requests from the overlay are clamped
+  /// to these (`List` becomes `Track`), and `update_repeat_mode` rejects the
+  /// rest. `None` is always allowed.
+  pub allowed_repeat_modes: Vec<RepeatMode>,
+  /// Drops presses of buttons this config disables, which the OS can still
+  /// send briefly after a button is turned off. Off by default.
+  pub ignore_disabled_buttons: bool,
+  /// Enables the Record button and treats its presses as a toggle, reported
+  /// through `record_toggle_event`. Like `record_as_favorite` this is only
+  /// an app convention; `record_as_favorite` wins if both are set.
+  pub record_as_toggle: bool,
+  /// Longest `update_metadata` waits for the art, in milliseconds. Text is
+  /// applied right away once this runs out, and the art follows when it
+  /// has loaded. `0` waits for the art.
+  pub thumbnail_budget_ms: u64,
+  /// Art over this many bytes is skipped, and reported as `TooLarge`,
+  /// before it is decoded or shown. `0` allows any size.
+  pub max_thumbnail_bytes: u64,
+  pub thumbnail_mode: ThumbnailMode,
+  pub thumbnail_transition: ThumbnailTransition,
+  /// Shown when the control is first enabled without any metadata set,
+  /// e.g. a "Loading..." title. It isn't treated as real metadata, and the
+  /// first `update_metadata` replaces it.
+  pub default_metadata: Option<MusicMetadata>,
+  /// Feeds `raw_event_trace`. For debugging only; off by default.
+  pub trace_raw_events: bool,
+  /// Re-applies the last state, see `reapply_state`, whenever
+  /// `refresh_event` fires, instead of leaving it to the app.
+  pub reapply_on_refresh: bool,
+  /// How far `reconcile_position` lets the reported position drift from
+  /// the expected one before correcting it.
+  pub reconcile_threshold_ms: u64,
+  /// Makes every call that changes the control log the backend calls it
+  /// would make instead of making them, for debugging integrations.
+  /// Nothing is shown, but the state is still recorded as if it were, so
+  /// snapshots and `debug_dump` show what the control would hold.
+  pub dry_run: bool,
+  /// The requests the unified event streams register for. All of them by
+  /// default.
+  pub events: EventSelection,
+}
+
+impl SMTCConfig {
+  /// Whether this config enables `button`. Record and Favorite follow
+  /// `record_as_favorite`.
+  pub fn is_button_enabled(&self, button: SmtcButton) -> bool {
+    match button {
+      SmtcButton::Play => self.play_enabled,
+      SmtcButton::Pause => self.pause_enabled,
+      SmtcButton::Stop => self.stop_enabled,
+      SmtcButton::Record => self.record_as_favorite || self.record_as_toggle,
+      SmtcButton::Favorite => self.record_as_favorite,
+      SmtcButton::FastForward => self.fast_forward_enabled,
+      SmtcButton::Rewind => self.rewind_enabled,
+      SmtcButton::Next => self.next_enabled,
+      SmtcButton::Previous => self.prev_enabled,
+      SmtcButton::ChannelUp => self.channel_up_enabled,
+      SmtcButton::ChannelDown => self.channel_down_enabled,
+    }
+  }
+
+  /// Whether art loaded under `self` could load differently under `other`,
+  /// i.e. whether a config change invalidates the thumbnail cache.
+  pub fn thumbnails_differ(&self, other: &Self) -> bool {
+    self.transcode_thumbnails != other.transcode_thumbnails
+      || self.thumbnail_content_type != other.thumbnail_content_type
+      || self.max_thumbnail_bytes != other.max_thumbnail_bytes
+      || self.thumbnail_headers != other.thumbnail_headers
+      || self.allowed_thumbnail_schemes != other.allowed_thumbnail_schemes
+  }
 }
 
 impl Default for SMTCConfig {
@@ -19,6 +152,81 @@ impl Default for SMTCConfig {
       prev_enabled: true,
       fast_forward_enabled: false,
       rewind_enabled: false,
+      channel_up_enabled: false,
+      channel_down_enabled: false,
+      thumbnails_enabled: true,
+      mirror_playback_status: false,
+      strict_metadata: false,
+      optimistic_seek: false,
+      sanitize_metadata: true,
+      transcode_thumbnails: false,
+      thumbnail_content_type: None,
+      allowed_thumbnail_schemes: ThumbnailScheme::all(),
+      thumbnail_headers: Vec::new(),
+      record_as_favorite: false,
+      button_debounce_ms: 0,
+      event_coalesce_ms: 0,
+      auto_disable_after_stop_ms: 0,
+      thumbnail_workers: 2,
+      allowed_repeat_modes: RepeatMode::all(),
+      ignore_disabled_buttons: false,
+      record_as_toggle: false,
+      thumbnail_budget_ms: 0,
+      max_thumbnail_bytes: 0,
+      thumbnail_mode: ThumbnailMode::Sync,
+      thumbnail_transition: ThumbnailTransition::Blank,
+      default_metadata: None,
+      trace_raw_events: false,
+      reapply_on_refresh: false,
+      reconcile_threshold_ms: 250,
+      dry_run: false,
+      events: EventSelection::all(),
     }
   }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn thumbnails_differ_ignores_unrelated_fields() {
+    let config = SMTCConfig::default();
+    let other = SMTCConfig {
+      play_enabled: false,
+      button_debounce_ms: 100,
+      ..config.clone()
+    };
+    assert!(!config.thumbnails_differ(&other));
+  }
+
+  #[test]
+  fn thumbnails_differ_detects_thumbnail_fields() {
+    let config = SMTCConfig::default();
+    let changes = [
+      SMTCConfig {
+        transcode_thumbnails: true,
+        ..config.clone()
+      },
+      SMTCConfig {
+        thumbnail_content_type: Some("image/png".into()),
+        ..config.clone()
+      },
+      SMTCConfig {
+        max_thumbnail_bytes: 1024,
+        ..config.clone()
+      },
+      SMTCConfig {
+        thumbnail_headers: vec![("Authorization".into(), "Bearer x".into())],
+        ..config.clone()
+      },
+      SMTCConfig {
+        allowed_thumbnail_schemes: vec![ThumbnailScheme::File],
+        ..config.clone()
+      },
+    ];
+    for other in changes {
+      assert!(config.thumbnails_differ(&other), "{other:?}");
+    }
+  }
+}
@@ -0,0 +1,118 @@
+use windows::core::HSTRING;
+use windows::{Foundation::Metadata::ApiInformation, System::Profile::AnalyticsInfo};
+
+const SMTC_TYPE: &str = "Windows.Media.SystemMediaTransportControls";
+
+/// Optional parts of the SMTC API the running Windows build provides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// `UpdateTimelineProperties`, for the seek bar.
+    pub timeline: bool,
+    pub shuffle: bool,
+    pub repeat_mode: bool,
+    pub playback_rate: bool,
+    /// `GlobalSystemMediaTransportControlsSessionManager`, for reading other
+    /// apps' sessions.
+    pub global_sessions: bool,
+}
+
+/// What a bug report needs to know about the environment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostics {
+    pub crate_version: String,
+    /// The Windows version as `major.minor.build.revision`, `None` if it
+    /// couldn't be read.
+    pub os_version: Option<String>,
+    /// The build part of `os_version`, e.g. `22631`. `0` if unknown.
+    pub os_build: u32,
+    pub capabilities: Capabilities,
+}
+
+pub fn capabilities() -> Capabilities {
+    // Assume presence if the OS can't be asked, like `try_into_winrt`.
+    let property = |name: &str| {
+        ApiInformation::IsPropertyPresent(&HSTRING::from(SMTC_TYPE), &HSTRING::from(name))
+            .unwrap_or(true)
+    };
+
+    Capabilities {
+        timeline: ApiInformation::IsMethodPresent(
+            &HSTRING::from(SMTC_TYPE),
+            &HSTRING::from("UpdateTimelineProperties"),
+        )
+        .unwrap_or(true),
+        shuffle: property("ShuffleEnabled"),
+        repeat_mode: property("AutoRepeatMode"),
+        playback_rate: property("PlaybackRate"),
+        global_sessions: ApiInformation::IsTypePresent(&HSTRING::from(
+            "Windows.Media.Control.GlobalSystemMediaTransportControlsSessionManager",
+        ))
+        .unwrap_or(true),
+    }
+}
+
+pub fn diagnostics() -> Diagnostics {
+    let os_version = os_version();
+    Diagnostics {
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        os_build: os_version.as_deref().map_or(0, build_of),
+        os_version,
+        capabilities: capabilities(),
+    }
+}
+
+fn os_version() -> Option<String> {
+    let packed: u64 = AnalyticsInfo::VersionInfo()
+        .and_then(|info| info.DeviceFamilyVersion())
+        .ok()?
+        .to_string()
+        .parse()
+        .ok()?;
+    Some(unpack_version(packed))
+}
+
+/// `DeviceFamilyVersion` packs the four version parts into a `u64`, 16 bits
+/// each.
+fn unpack_version(packed: u64) -> String {
+    let part = |shift: u32| (packed >> shift) & 0xFFFF;
+    format!("{}.{}.{}.{}", part(48), part(32), part(16), part(0))
+}
+
+/// The build part of a `major.minor.build.revision` version, `0` if missing.
+fn build_of(version: &str) -> u32 {
+    version
+        .split('.')
+        .nth(2)
+        .and_then(|build| build.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpack_version_splits_16_bit_parts() {
+        let packed = (10 << 48) | (22631 << 16) | 4317;
+        assert_eq!(unpack_version(packed), "10.0.22631.4317");
+    }
+
+    #[test]
+    fn build_of_reads_the_third_part() {
+        assert_eq!(build_of("10.0.22631.4317"), 22631);
+        assert_eq!(build_of("10.0"), 0);
+        assert_eq!(build_of("10.0.beta.1"), 0);
+    }
+
+    #[test]
+    fn diagnostics_reports_the_crate_and_os() {
+        let diagnostics = diagnostics();
+
+        assert_eq!(diagnostics.crate_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(
+            diagnostics.os_build,
+            diagnostics.os_version.as_deref().map_or(0, build_of)
+        );
+        assert_eq!(diagnostics.capabilities, capabilities());
+    }
+}
@@ -0,0 +1,289 @@
+use std::sync::{mpsc, Arc};
+use std::thread::ThreadId;
+
+use windows::Win32::{
+    Foundation::{HWND, LPARAM, WPARAM},
+    System::{
+        Com::{CoInitializeEx, CoUninitialize, COINIT_APARTMENTTHREADED},
+        Threading::GetCurrentThreadId,
+    },
+    UI::WindowsAndMessaging::{
+        DispatchMessageW, GetMessageW, PeekMessageW, PostThreadMessageW, TranslateMessage, MSG,
+        PM_NOREMOVE, WM_APP, WM_QUIT, WM_USER,
+    },
+};
+
+/// Decides where event callbacks run once a WinRT handler fires.
+#[derive(Debug, Clone, Default)]
+pub enum EventDispatcher {
+    /// Run the callback inline on the WinRT callback thread.
+    #[default]
+    Direct,
+    /// Spawn the callback onto a Tokio runtime, keeping the WinRT callback
+    /// thread free.
+    #[cfg(feature = "tokio")]
+    Runtime(tokio::runtime::Handle),
+    /// Queue the callback onto the crate's own STA thread, see `EventThread`.
+    Thread(EventThread),
+}
+
+impl EventDispatcher {
+    pub fn dispatch(&self, f: impl FnOnce() + Send + 'static) {
+        match self {
+            EventDispatcher::Direct => f(),
+            #[cfg(feature = "tokio")]
+            EventDispatcher::Runtime(handle) => {
+                handle.spawn(async move { f() });
+            }
+            EventDispatcher::Thread(thread) => thread.run(f),
+        }
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Posted to the thread when jobs are queued.
+const WAKE: u32 = WM_APP;
+
+/// A thread initialized as a single-threaded COM apartment that pumps its
+/// messages and runs queued jobs one at a time, in order: event callbacks,
+/// and with `StaBackend` every call to the control. It exits once every
+/// clone is dropped and the queue is drained.
+#[derive(Debug, Clone)]
+pub struct EventThread(Arc<ThreadHandle>);
+
+#[derive(Debug)]
+struct ThreadHandle {
+    sender: mpsc::Sender<Job>,
+    os_thread_id: u32,
+    thread_id: ThreadId,
+}
+
+impl Drop for ThreadHandle {
+    fn drop(&mut self) {
+        let _ = unsafe { PostThreadMessageW(self.os_thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) };
+    }
+}
+
+impl EventThread {
+    pub fn spawn(name: &str) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let (ready, started) = mpsc::channel();
+        std::thread::Builder::new()
+            .name(name.to_string())
+            .spawn(move || {
+                let initialized = unsafe { CoInitializeEx(None, COINIT_APARTMENTTHREADED) }.is_ok();
+                if !initialized {
+                    log::warn!("event thread runs without COM initialized");
+                }
+                // Creates the message queue before anyone can post to it.
+                let mut msg = MSG::default();
+                let _ = unsafe {
+                    PeekMessageW(&mut msg, HWND::default(), WM_USER, WM_USER, PM_NOREMOVE)
+                };
+                let _ = ready.send((unsafe { GetCurrentThreadId() }, std::thread::current().id()));
+
+                pump(&receiver);
+                receiver.try_iter().for_each(run_job);
+
+                if initialized {
+                    unsafe { CoUninitialize() };
+                }
+            })?;
+        let (os_thread_id, thread_id) = started.recv()?;
+        Ok(Self(Arc::new(ThreadHandle {
+            sender,
+            os_thread_id,
+            thread_id,
+        })))
+    }
+
+    /// Queues `f` without waiting for it.
+    pub fn run(&self, f: impl FnOnce() + Send + 'static) {
+        if self.0.sender.send(Box::new(f)).is_err() {
+            log::warn!("event thread is gone, dropping an event");
+            return;
+        }
+        // A failed post means the queue is full of wake-ups already.
+        let _ = unsafe { PostThreadMessageW(self.0.os_thread_id, WAKE, WPARAM(0), LPARAM(0)) };
+    }
+
+    /// Runs `f` on the thread and waits for its result. Calls made from the
+    /// thread itself, e.g. by a handler, run right away instead of waiting
+    /// on the queue they are part of.
+    pub fn call<R: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> R + Send + 'static,
+    ) -> anyhow::Result<R> {
+        if self.is_current() {
+            return Ok(f());
+        }
+        let (sender, receiver) = mpsc::channel();
+        self.run(move || {
+            let _ = sender.send(f());
+        });
+        receiver
+            .recv()
+            .map_err(|_| anyhow::anyhow!("the event thread dropped the call"))
+    }
+
+    pub fn is_current(&self) -> bool {
+        std::thread::current().id() == self.0.thread_id
+    }
+}
+
+/// Dispatches the thread's messages, running the queued jobs whenever it is
+/// woken, until `WM_QUIT`.
+fn pump(receiver: &mpsc::Receiver<Job>) {
+    let mut msg = MSG::default();
+    // 0 for WM_QUIT, -1 on failure.
+    while unsafe { GetMessageW(&mut msg, HWND::default(), 0, 0) }.0 > 0 {
+        if msg.message != WAKE {
+            unsafe {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+        // Also after other messages, in case a nested message loop swallowed
+        // a wake-up.
+        receiver.try_iter().for_each(run_job);
+    }
+}
+
+/// A panicking job is logged and doesn't take the thread down with it.
+fn run_job(job: Job) {
+    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(job)).is_err() {
+        log::error!("a job on the event thread panicked");
+    }
+}
+
+/// Callbacks for events raised by this crate rather than by WinRT.
+pub struct Listeners<T>(Vec<Arc<dyn Fn(T) + Send + Sync>>);
+
+impl<T> Listeners<T> {
+    pub fn add(&mut self, listener: impl Fn(T) + Send + Sync + 'static) {
+        self.0.push(Arc::new(listener));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: Clone> Listeners<T> {
+    pub fn notify(&self, value: T) {
+        for listener in &self.0 {
+            listener(value.clone());
+        }
+    }
+}
+
+impl<T> Clone for Listeners<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for Listeners<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> std::fmt::Debug for Listeners<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Listeners({})", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn jobs_from_many_callers_run_on_the_thread_in_order() {
+        const CALLERS: usize = 8;
+        const JOBS: usize = 250;
+        let thread = EventThread::spawn("smtc-test").unwrap();
+        let runs = Arc::new(Mutex::new(Vec::new()));
+
+        let callers: Vec<_> = (0..CALLERS)
+            .map(|caller| {
+                let thread = thread.clone();
+                let runs = runs.clone();
+                std::thread::spawn(move || {
+                    for job in 0..JOBS {
+                        let runs = runs.clone();
+                        let record = move || {
+                            let on = std::thread::current().id();
+                            runs.lock().unwrap().push((caller, job, on));
+                        };
+                        // Mixes queued jobs with ones the caller waits on.
+                        if job % 3 == 0 {
+                            thread.call(record).unwrap();
+                        } else {
+                            thread.run(record);
+                        }
+                    }
+                })
+            })
+            .collect();
+        for caller in callers {
+            caller.join().unwrap();
+        }
+        // Queued after everything else, so it also waits for the rest.
+        let event_thread = thread.call(|| std::thread::current().id()).unwrap();
+
+        let runs = runs.lock().unwrap();
+        assert_eq!(runs.len(), CALLERS * JOBS);
+        assert!(runs.iter().all(|&(_, _, on)| on == event_thread));
+        for caller in 0..CALLERS {
+            let jobs: Vec<_> = runs
+                .iter()
+                .filter(|&&(of, _, _)| of == caller)
+                .map(|&(_, job, _)| job)
+                .collect();
+            assert_eq!(jobs, (0..JOBS).collect::<Vec<_>>(), "caller {caller}");
+        }
+    }
+
+    #[test]
+    fn calls_from_the_thread_itself_run_right_away() {
+        let thread = EventThread::spawn("smtc-test").unwrap();
+        let inner = thread.clone();
+        let result = thread
+            .call(move || {
+                assert!(inner.is_current());
+                inner.call(|| 42).unwrap()
+            })
+            .unwrap();
+        assert_eq!(result, 42);
+        assert!(!thread.is_current());
+    }
+
+    #[test]
+    fn a_panicking_job_leaves_the_thread_running() {
+        let thread = EventThread::spawn("smtc-test").unwrap();
+        thread.run(|| panic!("job failed"));
+        assert_eq!(thread.call(|| 1).unwrap(), 1);
+    }
+
+    #[test]
+    fn queued_jobs_run_before_the_thread_exits() {
+        let thread = EventThread::spawn("smtc-test").unwrap();
+        let (sender, receiver) = mpsc::channel();
+        for job in 0..10 {
+            let sender = sender.clone();
+            thread.run(move || sender.send(job).unwrap());
+        }
+        drop(thread);
+
+        let jobs: Vec<_> = (0..10)
+            .map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        assert_eq!(jobs, (0..10).collect::<Vec<_>>());
+    }
+}
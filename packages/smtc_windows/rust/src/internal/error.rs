@@ -0,0 +1,111 @@
+use std::fmt;
+
+use windows::core::HRESULT;
+
+use super::{playback_status::PlaybackStatus, repeat_mode::RepeatMode};
+
+const RPC_E_WRONG_THREAD: HRESULT = HRESULT(0x8001010E_u32 as i32);
+const RPC_E_CHANGED_MODE: HRESULT = HRESULT(0x80010106_u32 as i32);
+const CO_E_NOTINITIALIZED: HRESULT = HRESULT(0x800401F0_u32 as i32);
+
+#[derive(Debug)]
+pub enum SMTCError {
+    /// WinRT rejected the call because of the calling thread's COM apartment.
+    WrongThread(windows::core::Error),
+    /// The platform has no API for the requested feature.
+    Unsupported(&'static str),
+    /// The OS build has no `MediaPlaybackStatus` for the status.
+    UnsupportedStatus(PlaybackStatus),
+    /// `SMTCConfig::allowed_repeat_modes` doesn't include the mode.
+    RepeatModeNotAllowed(RepeatMode),
+    /// `shutdown` already closed the instance.
+    ShutDown,
+}
+
+impl fmt::Display for SMTCError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SMTCError::WrongThread(err) => write!(
+                f,
+                "SMTC was called from a thread with the wrong COM apartment ({:#010x}: {}). \
+                 Make the call from the thread that created the SMTC instance.",
+                err.code().0,
+                err.message()
+            ),
+            SMTCError::Unsupported(reason) => write!(f, "not supported by Windows: {reason}"),
+            SMTCError::UnsupportedStatus(status) => write!(
+                f,
+                "the playback status {status:?} is not supported by this Windows build"
+            ),
+            SMTCError::RepeatModeNotAllowed(mode) => write!(
+                f,
+                "the repeat mode {mode:?} is not in SMTCConfig::allowed_repeat_modes"
+            ),
+            SMTCError::ShutDown => write!(f, "the SMTC instance was shut down"),
+        }
+    }
+}
+
+impl std::error::Error for SMTCError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SMTCError::WrongThread(err) => Some(err),
+            SMTCError::Unsupported(_)
+            | SMTCError::UnsupportedStatus(_)
+            | SMTCError::RepeatModeNotAllowed(_)
+            | SMTCError::ShutDown => None,
+        }
+    }
+}
+
+/// Maps WinRT failures with a known cause to an `SMTCError`, leaving any other
+/// error untouched.
+pub fn classify(err: anyhow::Error) -> anyhow::Error {
+    match err.downcast::<windows::core::Error>() {
+        Ok(err) => match err.code() {
+            RPC_E_WRONG_THREAD | RPC_E_CHANGED_MODE | CO_E_NOTINITIALIZED => {
+                SMTCError::WrongThread(err).into()
+            }
+            _ => err.into(),
+        },
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const E_FAIL: HRESULT = HRESULT(0x80004005_u32 as i32);
+
+    fn classified(code: HRESULT) -> anyhow::Error {
+        classify(windows::core::Error::from(code).into())
+    }
+
+    #[test]
+    fn apartment_errors_become_wrong_thread() {
+        for code in [RPC_E_WRONG_THREAD, RPC_E_CHANGED_MODE, CO_E_NOTINITIALIZED] {
+            match classified(code).downcast::<SMTCError>() {
+                Ok(SMTCError::WrongThread(err)) => assert_eq!(err.code(), code),
+                other => panic!("{code:?} was classified as {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn other_winrt_errors_are_left_alone() {
+        let err = classified(E_FAIL);
+        assert!(err.downcast_ref::<SMTCError>().is_none());
+        assert_eq!(
+            err.downcast_ref::<windows::core::Error>().unwrap().code(),
+            E_FAIL
+        );
+    }
+
+    #[test]
+    fn non_winrt_errors_are_left_alone() {
+        let err = classify(anyhow::anyhow!("no metadata"));
+        assert!(err.downcast_ref::<SMTCError>().is_none());
+        assert_eq!(err.to_string(), "no metadata");
+    }
+}
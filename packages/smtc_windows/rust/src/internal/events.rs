@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use windows::Media::SystemMediaTransportControlsButton;
+
+use super::{
+    metadata::MusicMetadata, playback_status::PlaybackStatus, repeat_mode::RepeatMode,
+    timeline::PlaybackTimeline,
+};
+
+/// `TimeSpan` ticks are 100ns units.
+const TICKS_PER_MS: i64 = 10_000;
+
+/// A seek request coming from the OS overlay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionChangeRequest {
+    pub position_ms: i64,
+    /// The raw requested position in 100ns ticks, for callers that can't
+    /// afford the rounding to milliseconds.
+    pub ticks: i64,
+    /// The rate last passed to `set_playback_rate` when the request came in,
+    /// for extrapolating the position after the seek. `None` if never set.
+    pub playback_rate: Option<f64>,
+    /// The position is outside the seekable range of the last timeline
+    /// (or seeking is turned off with `set_seekable`). Such requests are
+    /// forwarded as is, not clamped, and skip `optimistic_seek`.
+    pub out_of_range: bool,
+}
+
+impl PositionChangeRequest {
+    pub fn from_ticks(ticks: i64, playback_rate: Option<f64>) -> Self {
+        Self {
+            position_ms: ticks / TICKS_PER_MS,
+            ticks,
+            playback_rate,
+            out_of_range: false,
+        }
+    }
+
+    /// `position_ms` as 8 little-endian bytes, the encoding used by
+    /// `position_change_request_event_packed`.
+    pub fn packed(&self) -> [u8; 8] {
+        self.position_ms.to_le_bytes()
+    }
+
+    /// Reads a position written by `packed`, `None` unless `bytes` is
+    /// exactly 8 bytes long.
+    pub fn unpack_position_ms(bytes: &[u8]) -> Option<i64> {
+        Some(i64::from_le_bytes(bytes.try_into().ok()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmtcButton {
+    Play,
+    Pause,
+    Stop,
+    Record,
+    FastForward,
+    Rewind,
+    Next,
+    Previous,
+    ChannelUp,
+    ChannelDown,
+    /// Record, reported as a like/favorite action when
+    /// `SMTCConfig::record_as_favorite` is set. Windows has no favorite
+    /// button; repurposing Record is only an app convention.
+    Favorite,
+}
+
+impl SmtcButton {
+    pub fn from_winrt(button: SystemMediaTransportControlsButton) -> Option<Self> {
+        match button {
+            SystemMediaTransportControlsButton::Play => Some(SmtcButton::Play),
+            SystemMediaTransportControlsButton::Pause => Some(SmtcButton::Pause),
+            SystemMediaTransportControlsButton::Stop => Some(SmtcButton::Stop),
+            SystemMediaTransportControlsButton::Record => Some(SmtcButton::Record),
+            SystemMediaTransportControlsButton::FastForward => Some(SmtcButton::FastForward),
+            SystemMediaTransportControlsButton::Rewind => Some(SmtcButton::Rewind),
+            SystemMediaTransportControlsButton::Next => Some(SmtcButton::Next),
+            SystemMediaTransportControlsButton::Previous => Some(SmtcButton::Previous),
+            SystemMediaTransportControlsButton::ChannelUp => Some(SmtcButton::ChannelUp),
+            SystemMediaTransportControlsButton::ChannelDown => Some(SmtcButton::ChannelDown),
+            _ => None,
+        }
+    }
+
+    /// Maps Record to `Favorite` when `record_as_favorite` is set.
+    pub fn aliased(self, record_as_favorite: bool) -> Self {
+        match self {
+            SmtcButton::Record if record_as_favorite => SmtcButton::Favorite,
+            button => button,
+        }
+    }
+
+    /// The name used by the string based `button_press_event`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SmtcButton::Play => "play",
+            SmtcButton::Pause => "pause",
+            SmtcButton::Stop => "stop",
+            SmtcButton::Record => "record",
+            SmtcButton::FastForward => "fast_forward",
+            SmtcButton::Rewind => "rewind",
+            SmtcButton::Next => "next",
+            SmtcButton::Previous => "previous",
+            SmtcButton::ChannelUp => "channel_up",
+            SmtcButton::ChannelDown => "channel_down",
+            SmtcButton::Favorite => "favorite",
+        }
+    }
+}
+
+/// Where a button press came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonSource {
+    /// A media key or remote.
+    Hardware,
+    /// The OS overlay or another software client.
+    Software,
+    Unknown,
+}
+
+/// A button press with what is known about its origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ButtonPress {
+    pub button: SmtcButton,
+    /// WinRT's `ButtonPressed` args only carry the button, so this is
+    /// currently always `Unknown`. Kept so callers don't break once the
+    /// platform exposes more.
+    pub source: ButtonSource,
+}
+
+/// Who changed whether the control is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnabledChangeSource {
+    /// `enable_smtc`, `disable_smtc` or `auto_disable_after_stop_ms`.
+    App,
+    /// Something outside the crate. WinRT raises no event for this, so it's
+    /// only noticed when `is_enabled` reads a different value than the one
+    /// last set.
+    System,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnabledChange {
+    pub enabled: bool,
+    pub source: EnabledChangeSource,
+}
+
+/// Drops repeats of the same button within a time window, for remotes that
+/// report a single press twice.
+#[derive(Debug, Default)]
+pub struct ButtonDebouncer {
+    last_accepted: HashMap<SmtcButton, Duration>,
+}
+
+impl ButtonDebouncer {
+    /// Whether a press of `button` at `now` should be emitted. A zero
+    /// `window` accepts every press.
+    pub fn accept(&mut self, button: SmtcButton, now: Duration, window: Duration) -> bool {
+        if let Some(last) = self.last_accepted.get(&button) {
+            if now.saturating_sub(*last) < window {
+                return false;
+            }
+        }
+        self.last_accepted.insert(button, now);
+        true
+    }
+}
+
+/// Collapses bursts of identical consecutive events on the combined streams.
+#[derive(Debug, Default)]
+pub struct EventCoalescer {
+    last_forwarded: Option<(SmtcEventKind, u64)>,
+}
+
+impl EventCoalescer {
+    /// Whether `kind` at `timestamp_ms` should be forwarded: it is dropped if
+    /// it equals the last forwarded event and came within `window_ms` of it.
+    /// A zero `window_ms` forwards every event.
+    pub fn accept(&mut self, kind: &SmtcEventKind, timestamp_ms: u64, window_ms: u64) -> bool {
+        if let Some((last, at)) = &self.last_forwarded {
+            if last == kind && timestamp_ms.saturating_sub(*at) < window_ms {
+                return false;
+            }
+        }
+        self.last_forwarded = Some((kind.clone(), timestamp_ms));
+        true
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SmtcEventKind {
+    Button(SmtcButton),
+    PositionChangeRequest(PositionChangeRequest),
+    ShuffleRequest(bool),
+    RepeatModeRequest(String),
+    /// What the control currently shows. Not a request: sent first on every
+    /// new unified stream, and by `replay_last_state`.
+    Snapshot(StateSnapshot),
+}
+
+/// Which requests `unified_event_stream` and `register_all_events` register
+/// handlers for, as a set of bits combined with `|`. Leaving one out saves
+/// its handler when the app ignores it. Unknown bits are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventSelection(pub u32);
+
+impl EventSelection {
+    pub const BUTTON: Self = Self(1 << 0);
+    pub const POSITION: Self = Self(1 << 1);
+    pub const SHUFFLE: Self = Self(1 << 2);
+    pub const REPEAT: Self = Self(1 << 3);
+
+    pub fn all() -> Self {
+        Self::BUTTON | Self::POSITION | Self::SHUFFLE | Self::REPEAT
+    }
+
+    pub fn none() -> Self {
+        Self(0)
+    }
+
+    /// Whether every bit of `other` is set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for EventSelection {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for EventSelection {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl Default for EventSelection {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// The last metadata, status and timeline passed to the control, `None`
+/// for whatever hasn't been set (or was cleared) yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateSnapshot {
+    pub metadata: Option<MusicMetadata>,
+    pub status: Option<PlaybackStatus>,
+    pub timeline: Option<PlaybackTimeline>,
+}
+
+/// Sent by `state_changed_event` after each successful update: the values
+/// that update set, `None` for everything it left alone.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateChange {
+    pub metadata: Option<MusicMetadata>,
+    pub status: Option<PlaybackStatus>,
+    pub timeline: Option<PlaybackTimeline>,
+    pub shuffle: Option<bool>,
+    pub repeat_mode: Option<RepeatMode>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmtcEvent {
+    pub kind: SmtcEventKind,
+    /// Milliseconds since the owning `SMTCInternal` was created, taken from a
+    /// monotonic clock when the OS request was received.
+    pub timestamp_ms: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_ticks_keeps_the_raw_ticks_and_truncates_to_ms() {
+        let request = PositionChangeRequest::from_ticks(12_345_678, Some(1.5));
+        assert_eq!(request.position_ms, 1_234);
+        assert_eq!(request.ticks, 12_345_678);
+        assert_eq!(request.playback_rate, Some(1.5));
+        assert!(!request.out_of_range);
+    }
+
+    #[test]
+    fn from_ticks_below_a_ms_is_zero() {
+        assert_eq!(
+            PositionChangeRequest::from_ticks(9_999, None).position_ms,
+            0
+        );
+        assert_eq!(
+            PositionChangeRequest::from_ticks(10_000, None).position_ms,
+            1
+        );
+    }
+
+    #[test]
+    fn aliased_only_maps_record() {
+        assert_eq!(SmtcButton::Record.aliased(true), SmtcButton::Favorite);
+        assert_eq!(SmtcButton::Record.aliased(false), SmtcButton::Record);
+        assert_eq!(SmtcButton::Play.aliased(true), SmtcButton::Play);
+        assert_eq!(SmtcButton::Favorite.as_str(), "favorite");
+    }
+
+    #[test]
+    fn debouncer_drops_repeats_within_the_window() {
+        let mut debouncer = ButtonDebouncer::default();
+        let window = Duration::from_millis(100);
+        assert!(debouncer.accept(SmtcButton::Next, Duration::from_millis(0), window));
+        assert!(!debouncer.accept(SmtcButton::Next, Duration::from_millis(99), window));
+        assert!(debouncer.accept(SmtcButton::Next, Duration::from_millis(100), window));
+    }
+
+    #[test]
+    fn debouncer_measures_from_the_last_accepted_press() {
+        let mut debouncer = ButtonDebouncer::default();
+        let window = Duration::from_millis(100);
+        assert!(debouncer.accept(SmtcButton::Next, Duration::from_millis(0), window));
+        assert!(!debouncer.accept(SmtcButton::Next, Duration::from_millis(60), window));
+        // 120ms after the last accepted press, not 60ms after the dropped one.
+        assert!(debouncer.accept(SmtcButton::Next, Duration::from_millis(120), window));
+    }
+
+    #[test]
+    fn debouncer_keeps_buttons_apart_and_accepts_all_without_a_window() {
+        let mut debouncer = ButtonDebouncer::default();
+        let window = Duration::from_millis(100);
+        assert!(debouncer.accept(SmtcButton::Play, Duration::ZERO, window));
+        assert!(debouncer.accept(SmtcButton::Pause, Duration::ZERO, window));
+        assert!(debouncer.accept(SmtcButton::Play, Duration::ZERO, Duration::ZERO));
+    }
+
+    #[test]
+    fn coalescer_drops_identical_events_within_the_window() {
+        let mut coalescer = EventCoalescer::default();
+        let next = SmtcEventKind::Button(SmtcButton::Next);
+        assert!(coalescer.accept(&next, 1_000, 50));
+        assert!(!coalescer.accept(&next, 1_049, 50));
+        assert!(coalescer.accept(&next, 1_050, 50));
+    }
+
+    #[test]
+    fn coalescer_forwards_anything_different() {
+        let mut coalescer = EventCoalescer::default();
+        let next = SmtcEventKind::Button(SmtcButton::Next);
+        let previous = SmtcEventKind::Button(SmtcButton::Previous);
+        assert!(coalescer.accept(&next, 0, 50));
+        assert!(coalescer.accept(&previous, 10, 50));
+        // Only the last forwarded event counts, so this isn't a repeat.
+        assert!(coalescer.accept(&next, 20, 50));
+        assert!(coalescer.accept(&SmtcEventKind::ShuffleRequest(true), 30, 50));
+        assert!(coalescer.accept(&SmtcEventKind::ShuffleRequest(false), 40, 50));
+    }
+
+    #[test]
+    fn coalescer_forwards_everything_without_a_window() {
+        let mut coalescer = EventCoalescer::default();
+        let next = SmtcEventKind::Button(SmtcButton::Next);
+        assert!(coalescer.accept(&next, 0, 0));
+        assert!(coalescer.accept(&next, 0, 0));
+    }
+
+    #[test]
+    fn packed_positions_unpack_to_the_same_ms() {
+        for position_ms in [0, 1, 12_345, -5_000, i64::MAX] {
+            let request = PositionChangeRequest {
+                position_ms,
+                ..PositionChangeRequest::from_ticks(0, None)
+            };
+            assert_eq!(
+                PositionChangeRequest::unpack_position_ms(&request.packed()),
+                Some(position_ms)
+            );
+        }
+    }
+
+    #[test]
+    fn packed_is_little_endian() {
+        let request = PositionChangeRequest::from_ticks(2_560_000, None);
+        assert_eq!(request.packed(), [0, 1, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn unpack_needs_exactly_8_bytes() {
+        assert_eq!(PositionChangeRequest::unpack_position_ms(&[0; 7]), None);
+        assert_eq!(PositionChangeRequest::unpack_position_ms(&[0; 9]), None);
+        assert_eq!(PositionChangeRequest::unpack_position_ms(&[]), None);
+    }
+
+    #[test]
+    fn event_selection_combines_as_bits() {
+        let selection = EventSelection::BUTTON | EventSelection::SHUFFLE;
+        assert!(selection.contains(EventSelection::BUTTON));
+        assert!(selection.contains(EventSelection::SHUFFLE));
+        assert!(!selection.contains(EventSelection::POSITION));
+        assert!(!selection.contains(EventSelection::all()));
+
+        let mut selection = selection;
+        selection |= EventSelection::POSITION | EventSelection::REPEAT;
+        assert_eq!(selection, EventSelection::all());
+        assert!(!EventSelection::none().contains(EventSelection::REPEAT));
+    }
+}
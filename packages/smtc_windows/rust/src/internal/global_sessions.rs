@@ -0,0 +1,383 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use windows::core::HSTRING;
+use windows::{
+    Foundation::{IAsyncOperation, TypedEventHandler},
+    Media::Control::{
+        CurrentSessionChangedEventArgs, GlobalSystemMediaTransportControlsSession,
+        GlobalSystemMediaTransportControlsSessionManager, MediaPropertiesChangedEventArgs,
+        PlaybackInfoChangedEventArgs, SessionsChangedEventArgs,
+    },
+};
+
+use crate::frb_generated::StreamSink;
+
+use super::playback_status::PlaybackStatus;
+
+/// What another app's media session is currently showing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GlobalSessionSnapshot {
+    pub source_app_id: String,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub playback_status: Option<PlaybackStatus>,
+}
+
+impl GlobalSessionSnapshot {
+    fn read(session: &GlobalSystemMediaTransportControlsSession) -> anyhow::Result<Self> {
+        fn text(value: windows::core::Result<HSTRING>) -> Option<String> {
+            value.ok().map(|s| s.to_string()).filter(|s| !s.is_empty())
+        }
+
+        let properties = session
+            .TryGetMediaPropertiesAsync()
+            .and_then(|op| op.get())
+            .ok();
+        let playback_status = session
+            .GetPlaybackInfo()
+            .and_then(|info| info.PlaybackStatus())
+            .ok()
+            .map(PlaybackStatus::from);
+
+        Ok(Self {
+            source_app_id: session.SourceAppUserModelId()?.to_string(),
+            title: properties.as_ref().and_then(|p| text(p.Title())),
+            artist: properties.as_ref().and_then(|p| text(p.Artist())),
+            album: properties.as_ref().and_then(|p| text(p.AlbumTitle())),
+            album_artist: properties.as_ref().and_then(|p| text(p.AlbumArtist())),
+            playback_status,
+        })
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The handlers registered on whichever session is current right now.
+#[derive(Debug, Default)]
+struct SessionWatch {
+    session: Option<GlobalSystemMediaTransportControlsSession>,
+    media_properties_token: i64,
+    playback_info_token: i64,
+}
+
+impl SessionWatch {
+    fn unwire(&mut self) {
+        if let Some(session) = self.session.take() {
+            let _ = session.RemoveMediaPropertiesChanged(self.media_properties_token);
+            let _ = session.RemovePlaybackInfoChanged(self.playback_info_token);
+        }
+    }
+}
+
+/// Read-only view over the media sessions of every app on the system.
+#[derive(Debug)]
+pub struct GlobalSessions {
+    manager: GlobalSystemMediaTransportControlsSessionManager,
+    sessions_changed_tokens: Mutex<Vec<i64>>,
+    current_session_changed_tokens: Mutex<Vec<i64>>,
+    session_watches: Mutex<Vec<Arc<Mutex<SessionWatch>>>>,
+}
+
+impl GlobalSessions {
+    pub fn new() -> anyhow::Result<Self> {
+        let manager = GlobalSystemMediaTransportControlsSessionManager::RequestAsync()?.get()?;
+        Ok(Self {
+            manager,
+            sessions_changed_tokens: Mutex::default(),
+            current_session_changed_tokens: Mutex::default(),
+            session_watches: Mutex::default(),
+        })
+    }
+
+    /// Every session currently known to the OS. Sessions whose info can't be
+    /// read are skipped.
+    pub fn sessions(&self) -> anyhow::Result<Vec<GlobalSessionSnapshot>> {
+        Ok(collect_sessions(self.manager.GetSessions()?))
+    }
+
+    /// The session the OS considers active, if any.
+    pub fn current_session(&self) -> Option<GlobalSessionSnapshot> {
+        let session = self.manager.GetCurrentSession().ok()?;
+        GlobalSessionSnapshot::read(&session).ok()
+    }
+
+    /// Runs a control command against the current session. Returns `false`
+    /// when there is no current session or its app refused the command.
+    fn send_command(
+        &self,
+        command: impl FnOnce(
+            &GlobalSystemMediaTransportControlsSession,
+        ) -> windows::core::Result<IAsyncOperation<bool>>,
+    ) -> bool {
+        let Ok(session) = self.manager.GetCurrentSession() else {
+            return false;
+        };
+        command(&session).and_then(|op| op.get()).unwrap_or(false)
+    }
+
+    pub fn try_play(&self) -> bool {
+        self.send_command(|session| session.TryPlayAsync())
+    }
+
+    pub fn try_pause(&self) -> bool {
+        self.send_command(|session| session.TryPauseAsync())
+    }
+
+    pub fn try_toggle_play_pause(&self) -> bool {
+        self.send_command(|session| session.TryTogglePlayPauseAsync())
+    }
+
+    pub fn try_stop(&self) -> bool {
+        self.send_command(|session| session.TryStopAsync())
+    }
+
+    pub fn try_skip_next(&self) -> bool {
+        self.send_command(|session| session.TrySkipNextAsync())
+    }
+
+    pub fn try_skip_previous(&self) -> bool {
+        self.send_command(|session| session.TrySkipPreviousAsync())
+    }
+
+    pub fn try_fast_forward(&self) -> bool {
+        self.send_command(|session| session.TryFastForwardAsync())
+    }
+
+    pub fn try_rewind(&self) -> bool {
+        self.send_command(|session| session.TryRewindAsync())
+    }
+
+    /// Emits the current session list right away, then again every time a
+    /// session is added or removed.
+    pub fn sessions_changed_event(
+        &self,
+        sink: StreamSink<Vec<GlobalSessionSnapshot>>,
+    ) -> anyhow::Result<()> {
+        let _ = sink.add(self.sessions()?);
+
+        let handler = TypedEventHandler::<
+            GlobalSystemMediaTransportControlsSessionManager,
+            SessionsChangedEventArgs,
+        >::new(move |manager, _| {
+            if let Some(manager) = manager.as_ref() {
+                if let Ok(sessions) = manager.GetSessions() {
+                    let _ = sink.add(collect_sessions(sessions));
+                }
+            }
+            Ok(())
+        });
+
+        let token = self.manager.SessionsChanged(&handler)?;
+        lock(&self.sessions_changed_tokens).push(token);
+
+        Ok(())
+    }
+
+    /// Follows whatever session is current system wide, emitting a snapshot
+    /// whenever its media properties or playback info change.
+    ///
+    /// When the current session switches to another app the stream moves
+    /// along with it; `None` is emitted while no session is active.
+    pub fn global_media_changed_event(
+        &self,
+        sink: StreamSink<Option<GlobalSessionSnapshot>>,
+    ) -> anyhow::Result<()> {
+        let watch = Arc::new(Mutex::new(SessionWatch::default()));
+        watch_current_session(&self.manager, &watch, &sink)?;
+
+        let handler_watch = watch.clone();
+        let handler = TypedEventHandler::<
+            GlobalSystemMediaTransportControlsSessionManager,
+            CurrentSessionChangedEventArgs,
+        >::new(move |manager, _| {
+            if let Some(manager) = manager.as_ref() {
+                let _ = watch_current_session(manager, &handler_watch, &sink);
+            }
+            Ok(())
+        });
+
+        let token = self.manager.CurrentSessionChanged(&handler)?;
+        lock(&self.current_session_changed_tokens).push(token);
+        lock(&self.session_watches).push(watch);
+
+        Ok(())
+    }
+}
+
+impl Drop for GlobalSessions {
+    fn drop(&mut self) {
+        let tokens = self
+            .sessions_changed_tokens
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        for token in tokens.drain(..) {
+            let _ = self.manager.RemoveSessionsChanged(token);
+        }
+
+        let tokens = self
+            .current_session_changed_tokens
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        for token in tokens.drain(..) {
+            let _ = self.manager.RemoveCurrentSessionChanged(token);
+        }
+
+        for watch in lock(&self.session_watches).drain(..) {
+            lock(&watch).unwire();
+        }
+    }
+}
+
+/// Moves `watch` over to the manager's current session, if there is one.
+fn watch_current_session(
+    manager: &GlobalSystemMediaTransportControlsSessionManager,
+    watch: &Mutex<SessionWatch>,
+    sink: &StreamSink<Option<GlobalSessionSnapshot>>,
+) -> anyhow::Result<()> {
+    let mut watch = lock(watch);
+    watch.unwire();
+
+    let Ok(session) = manager.GetCurrentSession() else {
+        let _ = sink.add(None);
+        return Ok(());
+    };
+
+    fn forward(
+        sink: &StreamSink<Option<GlobalSessionSnapshot>>,
+        session: &Option<GlobalSystemMediaTransportControlsSession>,
+    ) {
+        if let Some(snapshot) = session
+            .as_ref()
+            .and_then(|session| GlobalSessionSnapshot::read(session).ok())
+        {
+            let _ = sink.add(Some(snapshot));
+        }
+    }
+
+    let media_sink = sink.clone();
+    let media_handler = TypedEventHandler::<
+        GlobalSystemMediaTransportControlsSession,
+        MediaPropertiesChangedEventArgs,
+    >::new(move |session, _| {
+        forward(&media_sink, session);
+        Ok(())
+    });
+
+    let playback_sink = sink.clone();
+    let playback_handler = TypedEventHandler::<
+        GlobalSystemMediaTransportControlsSession,
+        PlaybackInfoChangedEventArgs,
+    >::new(move |session, _| {
+        forward(&playback_sink, session);
+        Ok(())
+    });
+
+    watch.media_properties_token = session.MediaPropertiesChanged(&media_handler)?;
+    watch.playback_info_token = session.PlaybackInfoChanged(&playback_handler)?;
+
+    forward(sink, &Some(session.clone()));
+    watch.session = Some(session);
+
+    Ok(())
+}
+
+/// A session `collect_sessions` can read, so enumeration can run against
+/// fakes.
+trait ReadSession {
+    fn read(&self) -> anyhow::Result<GlobalSessionSnapshot>;
+}
+
+impl ReadSession for GlobalSystemMediaTransportControlsSession {
+    fn read(&self) -> anyhow::Result<GlobalSessionSnapshot> {
+        GlobalSessionSnapshot::read(self)
+    }
+}
+
+/// Snapshots of `sessions` in order, skipping the ones that can't be read.
+fn collect_sessions<S: ReadSession>(
+    sessions: impl IntoIterator<Item = S>,
+) -> Vec<GlobalSessionSnapshot> {
+    sessions
+        .into_iter()
+        .filter_map(|session| session.read().ok())
+        .collect()
+}
+
+/// Best-effort check whether the session the OS currently considers active
+/// belongs to this process.
+///
+/// WinRT only exposes the source app id of the current session, so for
+/// unpackaged apps this compares it with the executable file name. Another
+/// process running the same executable, or an app that sets an explicit
+/// AppUserModelID, can make the result inaccurate.
+pub fn is_current_process_active() -> bool {
+    let Some(source_app_id) = GlobalSessions::new()
+        .ok()
+        .and_then(|sessions| sessions.current_session())
+        .map(|session| session.source_app_id)
+    else {
+        return false;
+    };
+
+    let Some(exe_name) = std::env::current_exe().ok().and_then(|path| {
+        path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    }) else {
+        return false;
+    };
+
+    source_app_id.eq_ignore_ascii_case(&exe_name)
+        || source_app_id
+            .to_ascii_lowercase()
+            .ends_with(&format!("!{}", exe_name.to_ascii_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeSession(Option<&'static str>);
+
+    impl ReadSession for FakeSession {
+        fn read(&self) -> anyhow::Result<GlobalSessionSnapshot> {
+            let Some(app_id) = self.0 else {
+                anyhow::bail!("the session went away");
+            };
+            Ok(GlobalSessionSnapshot {
+                source_app_id: app_id.to_string(),
+                title: None,
+                artist: None,
+                album: None,
+                album_artist: None,
+                playback_status: Some(PlaybackStatus::Playing),
+            })
+        }
+    }
+
+    #[test]
+    fn collect_sessions_keeps_the_order_and_skips_unreadable_ones() {
+        let sessions = collect_sessions([
+            FakeSession(Some("Spotify.exe")),
+            FakeSession(None),
+            FakeSession(Some(
+                "Microsoft.ZuneMusic_8wekyb3d8bbwe!Microsoft.ZuneMusic",
+            )),
+        ]);
+
+        let app_ids: Vec<_> = sessions
+            .iter()
+            .map(|session| session.source_app_id.as_str())
+            .collect();
+        assert_eq!(
+            app_ids,
+            [
+                "Spotify.exe",
+                "Microsoft.ZuneMusic_8wekyb3d8bbwe!Microsoft.ZuneMusic"
+            ]
+        );
+    }
+}
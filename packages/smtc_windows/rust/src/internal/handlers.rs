@@ -0,0 +1,109 @@
+/// The WinRT events a handler can be registered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandlerKind {
+    ButtonPressed,
+    PositionChangeRequest,
+    ShuffleRequest,
+    RepeatModeRequest,
+    MediaFailed,
+}
+
+/// Number of live handlers per event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HandlerCounts {
+    pub button_pressed: usize,
+    pub position_change_request: usize,
+    pub shuffle_request: usize,
+    pub repeat_mode_request: usize,
+    pub media_failed: usize,
+}
+
+/// Registration tokens of the handlers currently attached to the control.
+#[derive(Debug, Default)]
+pub struct HandlerRegistry {
+    tokens: Vec<(HandlerKind, i64)>,
+}
+
+impl HandlerRegistry {
+    pub fn register(&mut self, kind: HandlerKind, token: i64) {
+        self.tokens.push((kind, token));
+    }
+
+    /// Returns whether the token was still registered.
+    pub fn remove(&mut self, kind: HandlerKind, token: i64) -> bool {
+        let before = self.tokens.len();
+        self.tokens.retain(|entry| *entry != (kind, token));
+        self.tokens.len() != before
+    }
+
+    /// Removes every token and returns them, so the caller can detach the
+    /// handlers from the control.
+    pub fn take_all(&mut self) -> Vec<(HandlerKind, i64)> {
+        std::mem::take(&mut self.tokens)
+    }
+
+    pub fn count(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn counts(&self) -> HandlerCounts {
+        let mut counts = HandlerCounts::default();
+        for (kind, _) in &self.tokens {
+            match kind {
+                HandlerKind::ButtonPressed => counts.button_pressed += 1,
+                HandlerKind::PositionChangeRequest => counts.position_change_request += 1,
+                HandlerKind::ShuffleRequest => counts.shuffle_request += 1,
+                HandlerKind::RepeatModeRequest => counts.repeat_mode_request += 1,
+                HandlerKind::MediaFailed => counts.media_failed += 1,
+            }
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_counts_per_kind() {
+        let mut registry = HandlerRegistry::default();
+        registry.register(HandlerKind::ButtonPressed, 1);
+        registry.register(HandlerKind::ButtonPressed, 2);
+        registry.register(HandlerKind::MediaFailed, 3);
+        assert_eq!(registry.count(), 3);
+        assert_eq!(
+            registry.counts(),
+            HandlerCounts {
+                button_pressed: 2,
+                media_failed: 1,
+                ..HandlerCounts::default()
+            }
+        );
+    }
+
+    #[test]
+    fn remove_needs_the_kind_and_token() {
+        let mut registry = HandlerRegistry::default();
+        registry.register(HandlerKind::ShuffleRequest, 1);
+        assert!(!registry.remove(HandlerKind::RepeatModeRequest, 1));
+        assert!(registry.remove(HandlerKind::ShuffleRequest, 1));
+        assert!(!registry.remove(HandlerKind::ShuffleRequest, 1));
+        assert_eq!(registry.count(), 0);
+    }
+
+    #[test]
+    fn take_all_empties_the_registry() {
+        let mut registry = HandlerRegistry::default();
+        registry.register(HandlerKind::ButtonPressed, 1);
+        registry.register(HandlerKind::PositionChangeRequest, 2);
+        assert_eq!(
+            registry.take_all(),
+            [
+                (HandlerKind::ButtonPressed, 1),
+                (HandlerKind::PositionChangeRequest, 2)
+            ]
+        );
+        assert_eq!(registry.count(), 0);
+    }
+}
@@ -0,0 +1,20 @@
+use super::{
+    config::SMTCConfig, metadata::MusicMetadata, playback_status::PlaybackStatus,
+    timeline::PlaybackTimeline,
+};
+
+/// Everything `SMTCInternal::with_init` applies before enabling the control,
+/// so simple apps can set up the overlay in one call.
+#[derive(Debug, Clone, Default)]
+pub struct SmtcInit {
+    /// `None` applies no config: the defaults for a new control, the current
+    /// config for `switch_session_full`.
+    pub config: Option<SMTCConfig>,
+    pub metadata: Option<MusicMetadata>,
+    /// Passed to `update_metadata` along with `metadata`.
+    pub app_id: Option<String>,
+    pub timeline: Option<PlaybackTimeline>,
+    pub status: Option<PlaybackStatus>,
+    /// Defaults to `true`, like `SMTCInternal::new`.
+    pub enabled: Option<bool>,
+}
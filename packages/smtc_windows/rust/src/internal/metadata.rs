@@ -1,4 +1,10 @@
+use std::collections::HashMap;
+use std::path::Path;
+
 use windows::core::HSTRING;
+use windows::Media::MediaPlaybackType;
+
+use super::thumbnail;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 
@@ -7,10 +13,108 @@ pub struct MusicMetadata {
     pub artist: Option<String>,
     pub album: Option<String>,
     pub album_artist: Option<String>,
-    pub thumbnail: Option<String>,
+    /// Art sources, tried in order; the first one that loads is shown.
+    /// `validate_metadata` only checks the first, since the ones after it
+    /// are fallbacks expected to fail at times.
+    pub thumbnail: Vec<String>,
+    pub genres: Vec<String>,
+    pub track_number: Option<u32>,
+    pub media_type: MediaType,
+    /// BCP-47 language tags for `title` and `artist`, e.g. `ja-JP`. The
+    /// display properties WinRT exposes have no language qualifier, so these
+    /// are only kept with the metadata (and show up wherever it is read
+    /// back, like snapshots) for the app's own use; the overlay ignores them.
+    pub title_locale: Option<String>,
+    pub artist_locale: Option<String>,
+}
+
+/// Which kind of content the overlay is told it shows. For `Video` and
+/// `Image` the title and artist are shown as title and subtitle, and the
+/// album fields, genres and track number are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MediaType {
+    #[default]
+    Music,
+    Video,
+    Image,
+    /// Content that fits none of the others. The overlay shows no text for
+    /// it, only the app name (or `app_id`) and the thumbnail.
+    Unknown,
+}
+
+impl Into<MediaPlaybackType> for MediaType {
+    fn into(self) -> MediaPlaybackType {
+        match self {
+            MediaType::Music => MediaPlaybackType::Music,
+            MediaType::Video => MediaPlaybackType::Video,
+            MediaType::Image => MediaPlaybackType::Image,
+            MediaType::Unknown => MediaPlaybackType::Unknown,
+        }
+    }
 }
 
 impl MusicMetadata {
+    /// Builds metadata from field names to values, for callers that don't
+    /// have the typed struct at hand. `genres` is a comma separated list.
+    /// Unknown keys are logged and skipped; a `track_number` that isn't a
+    /// number is an error.
+    pub fn from_map(fields: HashMap<String, String>) -> anyhow::Result<Self> {
+        let mut metadata = Self {
+            title: None,
+            artist: None,
+            album: None,
+            album_artist: None,
+            thumbnail: Vec::new(),
+            genres: Vec::new(),
+            track_number: None,
+            media_type: MediaType::Music,
+            title_locale: None,
+            artist_locale: None,
+        };
+
+        for (key, value) in fields {
+            match key.as_str() {
+                "title" => metadata.title = Some(value),
+                "artist" => metadata.artist = Some(value),
+                "album" => metadata.album = Some(value),
+                "album_artist" => metadata.album_artist = Some(value),
+                "thumbnail" => metadata.thumbnail = vec![value],
+                "title_locale" => metadata.title_locale = Some(value),
+                "artist_locale" => metadata.artist_locale = Some(value),
+                "genres" => {
+                    metadata.genres = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|genre| !genre.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                }
+                "track_number" => {
+                    let number = value.trim().parse().map_err(|e| {
+                        anyhow::anyhow!("track_number must be a number, got {value:?}: {e}")
+                    })?;
+                    metadata.track_number = Some(number);
+                }
+                _ => log::warn!("ignoring unknown metadata key {key:?}"),
+            }
+        }
+
+        Ok(metadata)
+    }
+
+    /// Returns a copy with control characters in the text fields replaced:
+    /// line breaks and tabs become a single space, any other control
+    /// character is dropped. Printable Unicode is left untouched.
+    pub fn sanitized(&self) -> Self {
+        Self {
+            title: self.title.as_deref().map(sanitize_text),
+            artist: self.artist.as_deref().map(sanitize_text),
+            album: self.album.as_deref().map(sanitize_text),
+            album_artist: self.album_artist.as_deref().map(sanitize_text),
+            ..self.clone()
+        }
+    }
+
     pub fn h_title(&self) -> Option<windows::core::HSTRING> {
         self.title.clone().map(|s| HSTRING::from(s))
     }
@@ -28,10 +132,365 @@ impl MusicMetadata {
     }
 
     pub fn h_thumbnail(&self) -> Option<windows::core::HSTRING> {
-        self.thumbnail.clone().map(|s| HSTRING::from(s))
+        self.thumbnail.first().map(HSTRING::from)
     }
 
     pub fn h_thumbnail_raw(&self) -> String {
-        self.thumbnail.clone().unwrap()
+        self.thumbnail.first().cloned().unwrap()
+    }
+
+    /// `thumbnail`, in the order the sources are tried.
+    pub fn thumbnail_sources(&self) -> impl Iterator<Item = &str> {
+        self.thumbnail.iter().map(String::as_str)
+    }
+}
+
+fn sanitize_text(text: &str) -> String {
+    let mut sanitized = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\r' | '\n' | '\t' => {
+                if !sanitized.ends_with(' ') {
+                    sanitized.push(' ');
+                }
+            }
+            c if c.is_control() => {}
+            c => sanitized.push(c),
+        }
+    }
+    sanitized.trim().to_string()
+}
+
+/// A value the control holds differently from what was set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldMismatch {
+    pub field: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// What `update_metadata_verified` read back. Empty when everything stuck.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MetadataReport {
+    pub mismatches: Vec<FieldMismatch>,
+}
+
+impl MetadataReport {
+    pub fn is_empty(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    pub(crate) fn check(&mut self, field: &str, expected: String, actual: String) {
+        if expected != actual {
+            self.mismatches.push(FieldMismatch {
+                field: field.to_string(),
+                expected,
+                actual,
+            });
+        }
+    }
+}
+
+/// Which groups of display properties `update_metadata_with` clears before
+/// applying new values, as a set of bits combined with `|`. Groups that
+/// aren't cleared keep their current values for any field the new metadata
+/// leaves as `None`. Unknown bits are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClearFlags(pub u32);
+
+impl ClearFlags {
+    pub const MUSIC_PROPERTIES: Self = Self(1 << 0);
+    pub const VIDEO_PROPERTIES: Self = Self(1 << 1);
+    pub const THUMBNAIL: Self = Self(1 << 2);
+    pub const APP_MEDIA_ID: Self = Self(1 << 3);
+
+    pub fn all() -> Self {
+        Self::MUSIC_PROPERTIES | Self::VIDEO_PROPERTIES | Self::THUMBNAIL | Self::APP_MEDIA_ID
+    }
+
+    pub fn none() -> Self {
+        Self(0)
+    }
+
+    /// Whether every bit of `other` is set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_all(&self) -> bool {
+        self.contains(Self::all())
+    }
+}
+
+impl std::ops::BitOr for ClearFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ClearFlags {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl Default for ClearFlags {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// A single field `clear_metadata_fields` can blank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetadataField {
+    Title,
+    Artist,
+    Album,
+    AlbumArtist,
+    Genres,
+    TrackNumber,
+    Thumbnail,
+}
+
+impl MusicMetadata {
+    pub fn clear_fields(&mut self, fields: &[MetadataField]) {
+        for field in fields {
+            match field {
+                MetadataField::Title => self.title = None,
+                MetadataField::Artist => self.artist = None,
+                MetadataField::Album => self.album = None,
+                MetadataField::AlbumArtist => self.album_artist = None,
+                MetadataField::Genres => self.genres.clear(),
+                MetadataField::TrackNumber => self.track_number = None,
+                MetadataField::Thumbnail => self.thumbnail.clear(),
+            }
+        }
+    }
+}
+
+/// Checks `metadata` for values the overlay can't show sensibly, without
+/// touching the control. Returns every problem found.
+pub fn validate_metadata(metadata: &MusicMetadata) -> Result<(), Vec<String>> {
+    let mut problems = Vec::new();
+
+    match metadata.title.as_deref() {
+        None => problems.push("title is missing".to_string()),
+        Some(title) if title.trim().is_empty() => problems.push("title is empty".to_string()),
+        Some(_) => {}
+    }
+
+    for (name, value) in [
+        ("artist", &metadata.artist),
+        ("album", &metadata.album),
+        ("album_artist", &metadata.album_artist),
+    ] {
+        if value.as_deref().is_some_and(|v| v.trim().is_empty()) {
+            problems.push(format!("{name} is empty"));
+        }
+    }
+
+    if let Some(source) = metadata.thumbnail.first() {
+        if source.trim().is_empty() {
+            problems.push("thumbnail is empty".to_string());
+        } else if thumbnail::is_url(source) {
+            let valid = ["http://", "https://"].iter().any(|scheme| {
+                source
+                    .strip_prefix(scheme)
+                    .is_some_and(|rest| !rest.is_empty())
+            });
+            if !valid {
+                problems.push(format!("thumbnail url is invalid: {source}"));
+            }
+        } else if !Path::new(&thumbnail::normalize_path(source)).is_file() {
+            problems.push(format!("thumbnail path does not exist: {source}"));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(title: &str) -> MusicMetadata {
+        MusicMetadata {
+            title: Some(title.to_string()),
+            artist: None,
+            album: None,
+            album_artist: None,
+            thumbnail: Vec::new(),
+            genres: Vec::new(),
+            track_number: None,
+            media_type: MediaType::Music,
+            title_locale: None,
+            artist_locale: None,
+        }
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn from_map_reads_every_key() {
+        let parsed = MusicMetadata::from_map(fields(&[
+            ("title", "Song"),
+            ("artist", "Artist"),
+            ("album", "Album"),
+            ("album_artist", "Album Artist"),
+            ("thumbnail", "cover.png"),
+            ("title_locale", "ja-JP"),
+            ("artist_locale", "en-US"),
+            ("genres", " Rock, ,Pop "),
+            ("track_number", " 7 "),
+        ]))
+        .unwrap();
+        assert_eq!(
+            parsed,
+            MusicMetadata {
+                artist: Some("Artist".to_string()),
+                album: Some("Album".to_string()),
+                album_artist: Some("Album Artist".to_string()),
+                thumbnail: vec!["cover.png".to_string()],
+                genres: vec!["Rock".to_string(), "Pop".to_string()],
+                track_number: Some(7),
+                title_locale: Some("ja-JP".to_string()),
+                artist_locale: Some("en-US".to_string()),
+                ..metadata("Song")
+            }
+        );
+    }
+
+    #[test]
+    fn from_map_skips_unknown_keys() {
+        let parsed =
+            MusicMetadata::from_map(fields(&[("title", "Song"), ("mood", "happy")])).unwrap();
+        assert_eq!(parsed, metadata("Song"));
+    }
+
+    #[test]
+    fn from_map_rejects_a_bad_track_number() {
+        let err = MusicMetadata::from_map(fields(&[("track_number", "seven")])).unwrap_err();
+        assert!(err.to_string().contains("\"seven\""), "{err}");
+    }
+
+    #[test]
+    fn sanitized_turns_line_breaks_into_single_spaces() {
+        let metadata = MusicMetadata {
+            artist: Some("Artist\r\n\tand Band".to_string()),
+            ..metadata(" Song\nTitle\n")
+        }
+        .sanitized();
+        assert_eq!(metadata.title.as_deref(), Some("Song Title"));
+        assert_eq!(metadata.artist.as_deref(), Some("Artist and Band"));
+    }
+
+    #[test]
+    fn sanitized_drops_other_control_characters_only() {
+        let metadata = MusicMetadata {
+            album: Some("Al\u{0}bum\u{1b}".to_string()),
+            album_artist: Some("Ärtist – 歌手 🎵".to_string()),
+            thumbnail: vec!["cover\n.png".to_string()],
+            ..metadata("Song")
+        }
+        .sanitized();
+        assert_eq!(metadata.album.as_deref(), Some("Album"));
+        assert_eq!(metadata.album_artist.as_deref(), Some("Ärtist – 歌手 🎵"));
+        // Only the text fields are touched.
+        assert_eq!(metadata.thumbnail, ["cover\n.png"]);
+    }
+
+    #[test]
+    fn validate_accepts_a_title_alone() {
+        assert_eq!(validate_metadata(&metadata("Song")), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_every_problem() {
+        let metadata = MusicMetadata {
+            artist: Some(" ".to_string()),
+            album_artist: Some(String::new()),
+            thumbnail: vec!["https://".to_string()],
+            ..metadata("")
+        };
+        assert_eq!(
+            validate_metadata(&metadata),
+            Err(vec![
+                "title is empty".to_string(),
+                "artist is empty".to_string(),
+                "album_artist is empty".to_string(),
+                "thumbnail url is invalid: https://".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_requires_a_title() {
+        let metadata = MusicMetadata {
+            title: None,
+            ..metadata("")
+        };
+        assert_eq!(
+            validate_metadata(&metadata),
+            Err(vec!["title is missing".to_string()])
+        );
+    }
+
+    #[test]
+    fn validate_checks_that_thumbnail_paths_exist() {
+        let existing = std::env::current_exe().unwrap();
+        let existing = MusicMetadata {
+            thumbnail: vec![existing.to_string_lossy().into_owned()],
+            ..metadata("Song")
+        };
+        assert_eq!(validate_metadata(&existing), Ok(()));
+
+        let missing = MusicMetadata {
+            thumbnail: vec!["does/not/exist.png".to_string()],
+            ..metadata("Song")
+        };
+        assert_eq!(
+            validate_metadata(&missing),
+            Err(vec![
+                "thumbnail path does not exist: does/not/exist.png".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_ignores_fallbacks() {
+        let metadata = MusicMetadata {
+            thumbnail: vec![
+                "https://example.com/cover.png".to_string(),
+                "does/not/exist.png".to_string(),
+            ],
+            ..metadata("Song")
+        };
+        assert_eq!(validate_metadata(&metadata), Ok(()));
+    }
+
+    #[test]
+    fn clear_flags_combine_as_bits() {
+        let flags = ClearFlags::MUSIC_PROPERTIES | ClearFlags::THUMBNAIL;
+        assert!(flags.contains(ClearFlags::MUSIC_PROPERTIES));
+        assert!(flags.contains(ClearFlags::THUMBNAIL));
+        assert!(!flags.contains(ClearFlags::VIDEO_PROPERTIES));
+        assert!(!flags.is_all());
+
+        let mut flags = flags;
+        flags |= ClearFlags::VIDEO_PROPERTIES | ClearFlags::APP_MEDIA_ID;
+        assert!(flags.is_all());
+        assert!(ClearFlags(u32::MAX).is_all());
+        assert!(!ClearFlags::none().contains(ClearFlags::THUMBNAIL));
     }
 }
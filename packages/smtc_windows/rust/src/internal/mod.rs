@@ -2,4 +2,17 @@ pub mod smtc_internal;
 pub mod config;
 pub mod metadata;
 pub mod timeline;
-pub mod playback_status;
\ No newline at end of file
+pub mod playback_status;
+pub mod thumbnail;
+pub mod events;
+pub mod error;
+pub mod session_info;
+pub mod dispatch;
+pub mod global_sessions;
+pub mod handlers;
+pub mod task;
+pub mod clock;
+pub mod init;
+pub mod repeat_mode;
+pub mod diagnostics;
+pub mod backend;
@@ -1,6 +1,14 @@
-use windows::Media::MediaPlaybackStatus;
+use windows::core::HSTRING;
+use windows::{
+    Foundation::Metadata::ApiInformation,
+    Media::{
+        Control::GlobalSystemMediaTransportControlsSessionPlaybackStatus, MediaPlaybackStatus,
+    },
+};
 
-#[derive(Clone, Copy, Debug)]
+use super::error::SMTCError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PlaybackStatus {
     Closed,
     Changing,
@@ -9,14 +17,108 @@ pub enum PlaybackStatus {
     Paused,
 }
 
-impl Into<MediaPlaybackStatus> for PlaybackStatus {
-    fn into(self) -> MediaPlaybackStatus {
+impl PlaybackStatus {
+    /// The name of the matching `MediaPlaybackStatus` value.
+    pub fn as_str(&self) -> &'static str {
         match self {
+            PlaybackStatus::Closed => "Closed",
+            PlaybackStatus::Changing => "Changing",
+            PlaybackStatus::Stopped => "Stopped",
+            PlaybackStatus::Playing => "Playing",
+            PlaybackStatus::Paused => "Paused",
+        }
+    }
+
+    /// The WinRT value for this status, the only conversion there is so the
+    /// check can't be skipped. Fails with `SMTCError::UnsupportedStatus` if
+    /// the OS doesn't know the value. If the OS can't be asked, the value is assumed
+    /// to be there.
+    pub fn try_into_winrt(self) -> Result<MediaPlaybackStatus, SMTCError> {
+        let present = ApiInformation::IsEnumNamedValuePresent(
+            &HSTRING::from("Windows.Media.MediaPlaybackStatus"),
+            &HSTRING::from(self.as_str()),
+        )
+        .unwrap_or(true);
+
+        if !present {
+            return Err(SMTCError::UnsupportedStatus(self));
+        }
+        Ok(match self {
             PlaybackStatus::Closed => MediaPlaybackStatus::Closed,
             PlaybackStatus::Changing => MediaPlaybackStatus::Changing,
             PlaybackStatus::Stopped => MediaPlaybackStatus::Stopped,
             PlaybackStatus::Playing => MediaPlaybackStatus::Playing,
             PlaybackStatus::Paused => MediaPlaybackStatus::Paused,
+        })
+    }
+}
+
+impl From<GlobalSystemMediaTransportControlsSessionPlaybackStatus> for PlaybackStatus {
+    /// `Opened` (media loaded but never started) is reported as `Stopped`.
+    fn from(status: GlobalSystemMediaTransportControlsSessionPlaybackStatus) -> Self {
+        match status {
+            GlobalSystemMediaTransportControlsSessionPlaybackStatus::Changing => {
+                PlaybackStatus::Changing
+            }
+            GlobalSystemMediaTransportControlsSessionPlaybackStatus::Opened
+            | GlobalSystemMediaTransportControlsSessionPlaybackStatus::Stopped => {
+                PlaybackStatus::Stopped
+            }
+            GlobalSystemMediaTransportControlsSessionPlaybackStatus::Playing => {
+                PlaybackStatus::Playing
+            }
+            GlobalSystemMediaTransportControlsSessionPlaybackStatus::Paused => {
+                PlaybackStatus::Paused
+            }
+            _ => PlaybackStatus::Closed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_statuses_map_opened_to_stopped() {
+        use GlobalSystemMediaTransportControlsSessionPlaybackStatus as Global;
+
+        assert_eq!(
+            PlaybackStatus::from(Global::Opened),
+            PlaybackStatus::Stopped
+        );
+        assert_eq!(
+            PlaybackStatus::from(Global::Stopped),
+            PlaybackStatus::Stopped
+        );
+        assert_eq!(
+            PlaybackStatus::from(Global::Playing),
+            PlaybackStatus::Playing
+        );
+        assert_eq!(PlaybackStatus::from(Global::Paused), PlaybackStatus::Paused);
+        assert_eq!(
+            PlaybackStatus::from(Global::Changing),
+            PlaybackStatus::Changing
+        );
+        assert_eq!(PlaybackStatus::from(Global::Closed), PlaybackStatus::Closed);
+    }
+
+    #[test]
+    fn try_into_winrt_maps_to_the_named_value() {
+        // Every status exists on the builds this runs on.
+        for (status, winrt) in [
+            (PlaybackStatus::Closed, MediaPlaybackStatus::Closed),
+            (PlaybackStatus::Changing, MediaPlaybackStatus::Changing),
+            (PlaybackStatus::Stopped, MediaPlaybackStatus::Stopped),
+            (PlaybackStatus::Playing, MediaPlaybackStatus::Playing),
+            (PlaybackStatus::Paused, MediaPlaybackStatus::Paused),
+        ] {
+            assert_eq!(
+                status.try_into_winrt().unwrap(),
+                winrt,
+                "{}",
+                status.as_str()
+            );
         }
     }
 }
@@ -0,0 +1,101 @@
+use windows::Media::MediaPlaybackAutoRepeatMode;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RepeatMode {
+    None,
+    Track,
+    List,
+}
+
+impl RepeatMode {
+    /// Parses the names used by the string based repeat mode methods,
+    /// falling back to `None` for anything unknown.
+    pub fn from_str_lossy(repeat_mode: &str) -> Self {
+        match repeat_mode {
+            "track" => RepeatMode::Track,
+            "list" => RepeatMode::List,
+            _ => RepeatMode::None,
+        }
+    }
+
+    pub fn all() -> Vec<Self> {
+        vec![RepeatMode::None, RepeatMode::Track, RepeatMode::List]
+    }
+
+    /// The closest mode in `allowed`: `List` falls back to `Track`, and
+    /// anything else to `None`, which is always allowed.
+    pub fn clamp_to(self, allowed: &[RepeatMode]) -> Self {
+        let fallbacks: &[RepeatMode] = match self {
+            RepeatMode::None => &[],
+            RepeatMode::Track => &[RepeatMode::Track],
+            RepeatMode::List => &[RepeatMode::List, RepeatMode::Track],
+        };
+        fallbacks
+            .iter()
+            .copied()
+            .find(|mode| allowed.contains(mode))
+            .unwrap_or(RepeatMode::None)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepeatMode::None => "none",
+            RepeatMode::Track => "track",
+            RepeatMode::List => "list",
+        }
+    }
+}
+
+impl Into<MediaPlaybackAutoRepeatMode> for RepeatMode {
+    fn into(self) -> MediaPlaybackAutoRepeatMode {
+        match self {
+            RepeatMode::None => MediaPlaybackAutoRepeatMode::None,
+            RepeatMode::Track => MediaPlaybackAutoRepeatMode::Track,
+            RepeatMode::List => MediaPlaybackAutoRepeatMode::List,
+        }
+    }
+}
+
+impl From<MediaPlaybackAutoRepeatMode> for RepeatMode {
+    fn from(repeat_mode: MediaPlaybackAutoRepeatMode) -> Self {
+        match repeat_mode {
+            MediaPlaybackAutoRepeatMode::Track => RepeatMode::Track,
+            MediaPlaybackAutoRepeatMode::List => RepeatMode::List,
+            _ => RepeatMode::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_lossy_round_trips_and_falls_back_to_none() {
+        for mode in RepeatMode::all() {
+            assert_eq!(RepeatMode::from_str_lossy(mode.as_str()), mode);
+        }
+        assert_eq!(RepeatMode::from_str_lossy("Track"), RepeatMode::None);
+        assert_eq!(RepeatMode::from_str_lossy(""), RepeatMode::None);
+    }
+
+    #[test]
+    fn clamp_to_keeps_allowed_modes() {
+        for mode in RepeatMode::all() {
+            assert_eq!(mode.clamp_to(&RepeatMode::all()), mode);
+        }
+    }
+
+    #[test]
+    fn clamp_to_falls_back_from_list_to_track_to_none() {
+        let repeat_one = [RepeatMode::None, RepeatMode::Track];
+        assert_eq!(RepeatMode::List.clamp_to(&repeat_one), RepeatMode::Track);
+        assert_eq!(RepeatMode::Track.clamp_to(&repeat_one), RepeatMode::Track);
+
+        let list_only = [RepeatMode::List];
+        assert_eq!(RepeatMode::Track.clamp_to(&list_only), RepeatMode::None);
+        assert_eq!(RepeatMode::List.clamp_to(&[]), RepeatMode::None);
+        // `None` needn't be listed to be allowed.
+        assert_eq!(RepeatMode::None.clamp_to(&list_only), RepeatMode::None);
+    }
+}
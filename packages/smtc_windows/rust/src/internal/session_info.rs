@@ -0,0 +1,7 @@
+/// Live values read back from the control.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SessionInfo {
+    /// `None` when no app media id is set.
+    pub app_media_id: Option<String>,
+    pub enabled: bool,
+}
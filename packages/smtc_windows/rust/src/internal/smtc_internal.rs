@@ -1,301 +1,4373 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs::File;
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::time::Duration;
+
 use crate::frb_generated::StreamSink;
-use windows::core::{Result, HSTRING};
-use windows::{
-    Foundation::{self, TypedEventHandler},
-    Media::{
-        AutoRepeatModeChangeRequestedEventArgs, MediaPlaybackAutoRepeatMode, MediaPlaybackType,
-        PlaybackPositionChangeRequestedEventArgs, ShuffleEnabledChangeRequestedEventArgs,
-        SystemMediaTransportControls, SystemMediaTransportControlsButton,
-        SystemMediaTransportControlsButtonPressedEventArgs,
-        SystemMediaTransportControlsTimelineProperties,
+use windows::Media::Playback::MediaPlayerError;
+use windows::Storage::Streams::RandomAccessStreamReference;
+
+use super::{
+    backend::{BackendCall, DisplayField, SmtcBackend, StaBackend, WinRtBackend},
+    clock::{SharedClock, SystemClock},
+    config::SMTCConfig,
+    dispatch::{EventDispatcher, EventThread, Listeners},
+    error::SMTCError,
+    events::{
+        ButtonDebouncer, ButtonPress, ButtonSource, EnabledChange, EnabledChangeSource,
+        EventCoalescer, EventSelection, PositionChangeRequest, SmtcButton, SmtcEvent,
+        SmtcEventKind, StateChange, StateSnapshot,
+    },
+    global_sessions,
+    handlers::{HandlerCounts, HandlerKind, HandlerRegistry},
+    init::SmtcInit,
+    metadata::{
+        validate_metadata, ClearFlags, MediaType, MetadataField, MetadataReport, MusicMetadata,
     },
-    Storage::{StorageFile, Streams::RandomAccessStreamReference},
+    playback_status::PlaybackStatus,
+    repeat_mode::RepeatMode,
+    session_info::SessionInfo,
+    task::{self, CancellationToken, Completion, WorkerPool},
+    thumbnail::{
+        self, SharedFetcher, SharedThumbnailCache, ThumbnailError, ThumbnailFetcher, ThumbnailMode,
+        ThumbnailResolver, ThumbnailResult, ThumbnailScheme, ThumbnailTransition,
+    },
+    timeline::{PlaybackTimeline, PositionProvider},
 };
 
-use super::{
-    config::SMTCConfig, metadata::MusicMetadata, playback_status::PlaybackStatus,
-    timeline::PlaybackTimeline,
+/// The end `set_elapsed` reports, long enough to not run out in practice.
+const ELAPSED_ONLY_END_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// What `update_position` and `set_duration` start from before any timeline
+/// was set.
+const EMPTY_TIMELINE: PlaybackTimeline = PlaybackTimeline {
+    start_time_ms: 0,
+    end_time_ms: 0,
+    position_ms: 0,
+    min_seek_time_ms: None,
+    max_seek_time_ms: None,
 };
 
-#[derive(Debug, Clone)]
-pub struct SMTCInternal {
-    pub media_player: Box<windows::Media::Playback::MediaPlayer>,
+/// How long a thumbnail resolver may take without a `thumbnail_budget_ms`.
+const RESOLVER_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default)]
+struct SMTCState {
+    config: SMTCConfig,
+    status: Option<PlaybackStatus>,
+    /// Set while the status shown is a temporary `Changing` (buffering or an
+    /// optimistic seek); the next timeline update restores `status`.
+    pending_status_restore: bool,
+    dispatcher: EventDispatcher,
+    timeline: Option<PlaybackTimeline>,
+    /// Clock time at which `timeline` was sent to the control.
+    timeline_updated_at: Option<Duration>,
+    /// Clock time at which playback started.
+    playback_started_at: Option<Duration>,
+    /// Set through `set_seekable(false)`, for ads or live streams.
+    seeking_disabled: bool,
+    metadata: Option<MusicMetadata>,
+    app_id: Option<String>,
+    /// The last shuffle and repeat mode sent to the control, for
+    /// `reapply_state`.
+    shuffle: Option<bool>,
+    repeat_mode: Option<RepeatMode>,
+    preview: Option<MetadataPreview>,
+    preview_generation: u64,
+    /// Bumped to cancel a pending `auto_disable_after_stop_ms` timer.
+    auto_disable_generation: u64,
+    /// Clock time of the last press of each button, debounced or not.
+    last_button_presses: HashMap<SmtcButton, Duration>,
+    /// Set between `freeze` and `thaw`.
+    frozen: Option<PendingUpdates>,
+    /// Set while `thaw` gathers what it replays into one batch.
+    collected: Option<Vec<BackendCall>>,
+    /// Shown instead of the metadata title, see `push_title_override`.
+    title_override: Option<String>,
+    handlers: HandlerRegistry,
+    fetcher: SharedFetcher,
+    thumbnail_resolver: Option<ThumbnailResolver>,
+    /// The thumbnail last read by `current_thumbnail_bytes`, with its bytes.
+    shown_thumbnail_bytes: Option<(RandomAccessStreamReference, Vec<u8>)>,
+    thumbnail_cache: SharedThumbnailCache,
+    thumbnail_listeners: Listeners<ThumbnailResult>,
+    /// Bumped by every thumbnail change, so slower async loads don't
+    /// overwrite newer art.
+    thumbnail_generation: u64,
+    thumbnail_pool: Option<Arc<WorkerPool>>,
+    /// `set_placeholder` is shown, with the transport buttons disabled.
+    placeholder: bool,
+    playback_rate: Option<f64>,
+    /// Token of the last metadata update, cancelled by the next one.
+    metadata_token: Option<CancellationToken>,
+    position_provider: Option<PositionProvider>,
+    /// Bumped to stop the running position timer.
+    position_timer_generation: u64,
+    /// The enabled state last set or read, to tell changes apart.
+    last_enabled: Option<bool>,
+    enabled_listeners: Listeners<EnabledChange>,
+    /// The on/off state driven by Record presses under `record_as_toggle`.
+    recording: bool,
+    record_listeners: Listeners<bool>,
+    /// The single handler flipping `recording` is registered.
+    record_toggle_registered: bool,
+    queue: Vec<MusicMetadata>,
+    /// Set once the last `SMTCInternal` clone is dropped; events still in
+    /// flight are discarded from then on.
+    detached: bool,
+    /// `shutdown` closed the player.
+    shut_down: bool,
+    /// `default_metadata` was considered for display; it only ever is once.
+    default_metadata_shown: bool,
+    raw_trace_listeners: Listeners<String>,
+    refresh_listeners: Listeners<bool>,
+    state_listeners: Listeners<StateChange>,
 }
 
-impl SMTCInternal {
-    pub fn new(enabled: Option<bool>) -> anyhow::Result<Self> {
-        let media_player = Box::new(windows::Media::Playback::MediaPlayer::new()?);
+/// What was held back while frozen, in the order it was made.
+#[derive(Debug, Default)]
+struct PendingUpdates(Vec<PendingUpdate>);
 
-        let smtc = media_player.SystemMediaTransportControls()?;
+/// An `update_*` call made while frozen, or the backend calls of any other
+/// change made meanwhile.
+#[derive(Debug)]
+enum PendingUpdate {
+    Config(SMTCConfig),
+    /// The flag is `silent`, see `update_metadata_silently`.
+    Metadata(MusicMetadata, Option<String>, ClearFlags, bool),
+    Timeline(PlaybackTimeline),
+    Status(PlaybackStatus),
+    Shuffle(bool),
+    RepeatMode(RepeatMode),
+    Calls(Vec<BackendCall>),
+}
 
-        media_player.CommandManager()?.SetIsEnabled(false)?;
+impl PendingUpdates {
+    /// Queues `update`, dropping an earlier `update_*` call of the same
+    /// kind so only the latest is applied. Calls are all kept.
+    fn record(&mut self, update: PendingUpdate) {
+        if !matches!(update, PendingUpdate::Calls(_)) {
+            let kind = std::mem::discriminant(&update);
+            self.0
+                .retain(|pending| std::mem::discriminant(pending) != kind);
+        }
+        self.0.push(update);
+    }
 
-        smtc.SetIsEnabled(enabled.unwrap_or(true))?;
-        Ok(Self { media_player })
+    fn timeline(&self) -> Option<PlaybackTimeline> {
+        self.0.iter().find_map(|update| match update {
+            PendingUpdate::Timeline(timeline) => Some(*timeline),
+            _ => None,
+        })
     }
 
-    pub fn update_config(&self, config: SMTCConfig) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+    fn clear_timeline(&mut self) {
+        self.0
+            .retain(|update| !matches!(update, PendingUpdate::Timeline(_)));
+    }
+}
 
-        smtc.SetIsPlayEnabled(config.play_enabled)?;
-        smtc.SetIsPauseEnabled(config.pause_enabled)?;
-        smtc.SetIsNextEnabled(config.next_enabled)?;
-        smtc.SetIsPreviousEnabled(config.prev_enabled)?;
-        smtc.SetIsFastForwardEnabled(config.fast_forward_enabled)?;
-        smtc.SetIsRewindEnabled(config.rewind_enabled)?;
-        smtc.SetIsStopEnabled(config.stop_enabled)?;
+/// Metadata shown by `preview_metadata` that hasn't been confirmed yet.
+#[derive(Debug)]
+struct MetadataPreview {
+    generation: u64,
+    metadata: MusicMetadata,
+}
 
-        Ok(())
+fn button_calls(config: &SMTCConfig) -> Vec<BackendCall> {
+    [
+        SmtcButton::Play,
+        SmtcButton::Pause,
+        SmtcButton::Next,
+        SmtcButton::Previous,
+        SmtcButton::FastForward,
+        SmtcButton::Rewind,
+        SmtcButton::Stop,
+        SmtcButton::ChannelUp,
+        SmtcButton::ChannelDown,
+        SmtcButton::Record,
+    ]
+    .into_iter()
+    .map(|button| BackendCall::SetButtonEnabled(button, config.is_button_enabled(button)))
+    .collect()
+}
+
+/// Calls blanking `fields`.
+fn blank(fields: impl IntoIterator<Item = DisplayField>) -> impl Iterator<Item = BackendCall> {
+    fields
+        .into_iter()
+        .map(|field| BackendCall::SetText(field, String::new()))
+}
+
+fn clear_calls(clear: ClearFlags) -> Vec<BackendCall> {
+    if clear.is_all() {
+        return vec![BackendCall::ClearDisplay];
     }
 
-    pub fn update_metadata(
-        &self,
-        metadata: MusicMetadata,
-        app_id: Option<String>,
-    ) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+    let mut calls = Vec::new();
+
+    if clear.contains(ClearFlags::MUSIC_PROPERTIES) {
+        calls.extend(blank([
+            DisplayField::MusicTitle,
+            DisplayField::MusicArtist,
+            DisplayField::MusicAlbum,
+            DisplayField::MusicAlbumArtist,
+        ]));
+        calls.push(BackendCall::SetGenres(Vec::new()));
+        calls.push(BackendCall::SetTrackNumber(0));
+    }
+
+    if clear.contains(ClearFlags::VIDEO_PROPERTIES) {
+        calls.extend(blank([
+            DisplayField::VideoTitle,
+            DisplayField::VideoSubtitle,
+        ]));
+    }
 
-        let updater = smtc.DisplayUpdater()?;
+    if clear.contains(ClearFlags::THUMBNAIL) {
+        calls.push(BackendCall::SetThumbnail(None));
+    }
 
-        updater.ClearAll()?;
+    if clear.contains(ClearFlags::APP_MEDIA_ID) {
+        calls.push(BackendCall::SetAppMediaId(String::new()));
+    }
 
-        app_id.map(|s| updater.SetAppMediaId(&HSTRING::from(s)));
+    calls
+}
 
-        updater.SetType(MediaPlaybackType::Music)?;
+/// Moves the shown position to `request` right away when `optimistic_seek`
+/// is on, marking the status `Changing` until the app reports the real
+/// position.
+fn apply_optimistic_seek(
+    backend: &dyn SmtcBackend,
+    state: &Mutex<SMTCState>,
+    request: PositionChangeRequest,
+) -> anyhow::Result<()> {
+    let (timeline, seeking_disabled) = {
+        let state = lock_state(state);
+        if !state.config.optimistic_seek {
+            return Ok(());
+        }
+        (state.timeline, state.seeking_disabled)
+    };
 
-        let music_properties = updater.MusicProperties()?;
+    let timeline = timeline.map(|timeline| PlaybackTimeline {
+        position_ms: request.position_ms,
+        ..timeline
+    });
+    let mut calls = Vec::with_capacity(2);
+    if let Some(timeline) = timeline {
+        calls.push(BackendCall::SetTimeline(Some(shown_timeline(
+            timeline,
+            seeking_disabled,
+        ))));
+    }
+    calls.push(BackendCall::SetPlaybackStatus(PlaybackStatus::Changing));
+    apply_calls(backend, state, &calls)?;
 
-        metadata.h_artist().map(|s| music_properties.SetArtist(&s));
+    let mut state = lock_state(state);
+    if timeline.is_some() {
+        state.timeline = timeline;
+    }
+    state.pending_status_restore = true;
 
-        metadata
-            .h_album()
-            .map(|s| music_properties.SetAlbumTitle(&s));
-        metadata.h_title().map(|s| music_properties.SetTitle(&s));
-        metadata
-            .h_album_artist()
-            .map(|s| music_properties.SetAlbumArtist(&s));
+    Ok(())
+}
 
-        let thumbnail = if let Some(s) = metadata.h_thumbnail() {
-            let is_url = metadata.h_thumbnail_raw().starts_with("http");
-            if is_url {
-                let uri = Foundation::Uri::CreateUri(&s).unwrap();
-                Some(RandomAccessStreamReference::CreateFromUri(&uri).unwrap())
-            } else {
-                fn get_storage_file_sync(path: &HSTRING) -> Result<StorageFile> {
-                    let async_op = StorageFile::GetFileFromPathAsync(path)?;
-                    async_op.get()
-                }
-                match get_storage_file_sync(&s) {
-                    Ok(file) => Some(RandomAccessStreamReference::CreateFromFile(&file).unwrap()),
-                    Err(_e) => None,
-                }
-            }
-        } else {
-            None
+/// `timeline` as the overlay is given it, see `set_seekable`.
+fn shown_timeline(timeline: PlaybackTimeline, seeking_disabled: bool) -> PlaybackTimeline {
+    if seeking_disabled {
+        timeline.without_seeking()
+    } else {
+        timeline
+    }
+}
+
+fn lock_state(state: &Mutex<SMTCState>) -> MutexGuard<'_, SMTCState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sends the line built by `line` to `raw_event_trace` listeners if
+/// `trace_raw_events` is on, synchronously and before the event is handled.
+fn trace(state: &Mutex<SMTCState>, line: impl FnOnce() -> String) {
+    let listeners = {
+        let state = lock_state(state);
+        if !state.config.trace_raw_events || state.raw_trace_listeners.is_empty() || state.detached
+        {
+            return;
+        }
+        state.raw_trace_listeners.clone()
+    };
+    listeners.notify(line());
+}
+
+/// Hands `value` to `callback` through the currently configured dispatcher.
+fn emit<T: Send + 'static>(
+    state: &Arc<Mutex<SMTCState>>,
+    callback: &Arc<impl Fn(T) + Send + Sync + 'static>,
+    value: T,
+) {
+    let dispatcher = {
+        let state = lock_state(state);
+        if state.detached {
+            return;
+        }
+        state.dispatcher.clone()
+    };
+
+    let state = state.clone();
+    let callback = callback.clone();
+    dispatcher.dispatch(move || {
+        // The instance may have been dropped while this was queued.
+        if !lock_state(&state).detached {
+            callback(value);
+        }
+    });
+}
+
+/// Sends `calls` to `backend`, queues them while frozen, or only logs them
+/// with `dry_run`. Every change to the control goes through here.
+fn apply_calls(
+    backend: &dyn SmtcBackend,
+    state: &Mutex<SMTCState>,
+    calls: &[BackendCall],
+) -> anyhow::Result<()> {
+    {
+        let mut state = lock_state(state);
+        if state.shut_down {
+            return Err(SMTCError::ShutDown.into());
+        }
+        if let Some(pending) = &mut state.frozen {
+            pending.record(PendingUpdate::Calls(calls.to_vec()));
+            return Ok(());
+        }
+        if let Some(collected) = &mut state.collected {
+            collected.extend_from_slice(calls);
+            return Ok(());
+        }
+        if state.config.dry_run {
+            log::info!("dry run: would apply {calls:?}");
+            return Ok(());
+        }
+    }
+    backend.apply(calls)
+}
+
+/// Detaches every handler in `state` from `backend`.
+fn remove_handlers(backend: &dyn SmtcBackend, state: &Mutex<SMTCState>) -> anyhow::Result<()> {
+    let tokens = lock_state(state).handlers.take_all();
+    for (kind, token) in tokens {
+        backend.remove_handler(kind, token)?;
+    }
+    Ok(())
+}
+
+/// Wraps an `FnMut` callback so the handlers can share it, running one call
+/// at a time.
+fn serialized<T>(callback: impl FnMut(T) + Send + 'static) -> impl Fn(T) + Send + Sync + 'static {
+    let callback = Mutex::new(callback);
+    move |value| (callback.lock().unwrap_or_else(PoisonError::into_inner))(value)
+}
+
+/// A handler registered with one of the `on_*` callbacks. Dropping it (or
+/// calling `cancel`) detaches the handler; `remove_handlers` and dropping
+/// the instance detach it as well.
+#[derive(Debug)]
+pub struct EventSubscription {
+    backend: Weak<dyn SmtcBackend>,
+    state: Weak<Mutex<SMTCState>>,
+    handler: Mutex<Option<(HandlerKind, i64)>>,
+}
+
+impl EventSubscription {
+    /// Detaches the handler. Calling it again has no effect.
+    pub fn cancel(&self) {
+        let taken = self
+            .handler
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        let (Some((kind, token)), Some(state)) = (taken, self.state.upgrade()) else {
+            return;
         };
+        if !lock_state(&state).handlers.remove(kind, token) {
+            return;
+        }
+        if let Some(backend) = self.backend.upgrade() {
+            let _ = backend.remove_handler(kind, token);
+        }
+    }
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
 
-        match thumbnail {
-            Some(x) => updater.SetThumbnail(&x)?,
-            None => updater.SetThumbnail(None)?,
+/// Shared by all clones of an `SMTCInternal`, but not by the WinRT handlers
+/// (which keep `state` alive), so it is dropped with the last clone.
+#[derive(Debug)]
+struct Lifetime {
+    backend: Arc<dyn SmtcBackend>,
+    state: Arc<Mutex<SMTCState>>,
+}
+
+impl Lifetime {
+    /// Detaches the handlers, disables the control and closes the player.
+    /// Only the first call does anything.
+    fn shut_down(&self) {
+        let dry_run = {
+            let mut state = lock_state(&self.state);
+            if std::mem::replace(&mut state.shut_down, true) {
+                return;
+            }
+            state.detached = true;
+            state.position_timer_generation += 1;
+            // Nothing held back is applied after this.
+            state.frozen = None;
+            state.collected = None;
+            state.config.dry_run
+        };
+        let _ = remove_handlers(&*self.backend, &self.state);
+        // Past `apply_calls`, which refuses everything from now on.
+        if !dry_run {
+            let _ = self.backend.apply(&[BackendCall::SetEnabled(false)]);
         }
+        let _ = self.backend.close();
+    }
+}
+
+impl Drop for Lifetime {
+    fn drop(&mut self) {
+        // Detach before the sinks captured by the handlers can outlive their
+        // Dart streams.
+        lock_state(&self.state).detached = true;
+        let _ = remove_handlers(&*self.backend, &self.state);
+    }
+}
 
-        updater.Update()?;
+/// Every live instance, for `shutdown`.
+static INSTANCES: Mutex<Vec<Weak<Lifetime>>> = Mutex::new(Vec::new());
 
-        Ok(())
+/// Shuts down every `SMTCInternal` still alive: detaches their handlers,
+/// disables the controls and closes the players, so no session outlives the
+/// app. The instances are unusable afterwards, their updates fail with
+/// `SMTCError::ShutDown`. Calling it again, or with no instances left, does
+/// nothing.
+pub fn shutdown() {
+    shut_down_all(std::mem::take(
+        &mut *INSTANCES.lock().unwrap_or_else(PoisonError::into_inner),
+    ));
+}
+
+fn shut_down_all(instances: Vec<Weak<Lifetime>>) {
+    for lifetime in instances.iter().filter_map(Weak::upgrade) {
+        lifetime.shut_down();
     }
+}
 
-    pub fn clear_metadata(&self) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+/// Drives the control through a `SmtcBackend`: the WinRT controls by
+/// default, or the backend passed to `with_backend`, e.g. a `MockBackend`
+/// to run without a display.
+#[derive(Debug, Clone)]
+pub struct SMTCInternal {
+    backend: Arc<dyn SmtcBackend>,
+    state: Arc<Mutex<SMTCState>>,
+    clock: SharedClock,
+    created_at: Duration,
+    _lifetime: Arc<Lifetime>,
+}
 
-        let updater = smtc.DisplayUpdater()?;
+/// An `SMTCInternal` that doesn't keep the control alive, for background
+/// loops that should end once every clone is dropped.
+struct WeakInternal {
+    backend: Arc<dyn SmtcBackend>,
+    state: Arc<Mutex<SMTCState>>,
+    clock: SharedClock,
+    created_at: Duration,
+    lifetime: Weak<Lifetime>,
+}
 
-        updater.ClearAll()?;
-        updater.Update()?;
+impl WeakInternal {
+    fn upgrade(&self) -> Option<SMTCInternal> {
+        Some(SMTCInternal {
+            backend: self.backend.clone(),
+            state: self.state.clone(),
+            clock: self.clock.clone(),
+            created_at: self.created_at,
+            _lifetime: self.lifetime.upgrade()?,
+        })
+    }
+}
 
-        Ok(())
+impl SMTCInternal {
+    pub fn new(enabled: Option<bool>) -> anyhow::Result<Self> {
+        Self::create(
+            None,
+            enabled,
+            Arc::new(SystemClock::new()),
+            Arc::new(WinRtBackend::new()?),
+            |_| Ok(()),
+        )
     }
 
-    pub fn update_timeline(&self, timeline: PlaybackTimeline) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+    /// Like `new`, but applies `config` before the control is enabled so the
+    /// first time the overlay shows up it already has the right buttons.
+    pub fn with_config(config: SMTCConfig, enabled: Option<bool>) -> anyhow::Result<Self> {
+        Self::create(
+            Some(config),
+            enabled,
+            Arc::new(SystemClock::new()),
+            Arc::new(WinRtBackend::new()?),
+            |_| Ok(()),
+        )
+    }
 
-        let timeline_properties: anyhow::Result<SystemMediaTransportControlsTimelineProperties> =
-            timeline.into();
-        smtc.UpdateTimelineProperties(&timeline_properties?)?;
+    /// Creates the control and applies the initial metadata, timeline and
+    /// status from `init` before enabling it.
+    pub fn with_init(init: SmtcInit) -> anyhow::Result<Self> {
+        let SmtcInit {
+            config,
+            metadata,
+            app_id,
+            timeline,
+            status,
+            enabled,
+        } = init;
 
-        Ok(())
+        Self::create(
+            config,
+            enabled,
+            Arc::new(SystemClock::new()),
+            Arc::new(WinRtBackend::new()?),
+            move |internal| {
+                if let Some(metadata) = metadata {
+                    internal.update_metadata(metadata, app_id)?;
+                }
+                if let Some(timeline) = timeline {
+                    internal.update_timeline(timeline)?;
+                }
+                if let Some(status) = status {
+                    internal.update_playback_status(status)?;
+                }
+                Ok(())
+            },
+        )
     }
 
-    pub fn update_playback_status(&self, status: PlaybackStatus) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+    /// Like `with_config`, but measures and waits on `clock`, e.g. a
+    /// `ManualClock` to step through preview reverts deterministically.
+    pub fn with_clock(
+        config: SMTCConfig,
+        enabled: Option<bool>,
+        clock: SharedClock,
+    ) -> anyhow::Result<Self> {
+        Self::create(
+            Some(config),
+            enabled,
+            clock,
+            Arc::new(WinRtBackend::new()?),
+            |_| Ok(()),
+        )
+    }
 
-        smtc.SetPlaybackStatus(status.into())?;
-        Ok(())
+    /// Like `with_clock`, but drives `backend` instead of the WinRT
+    /// controls, e.g. a `MockBackend` to test an app's integration without
+    /// a display.
+    pub fn with_backend(
+        config: SMTCConfig,
+        enabled: Option<bool>,
+        clock: SharedClock,
+        backend: Arc<dyn SmtcBackend>,
+    ) -> anyhow::Result<Self> {
+        Self::create(Some(config), enabled, clock, backend, |_| Ok(()))
     }
 
-    pub fn update_shuffle(&self, shuffle: bool) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
-        smtc.SetShuffleEnabled(shuffle)?;
-        Ok(())
+    /// Like `with_config`, but creates the player on a thread this crate
+    /// owns, initialized as an STA and pumping its messages, and makes every
+    /// call to it from there, whichever thread the app calls from. Events
+    /// are delivered from that thread too, as with `set_event_thread`.
+    pub fn on_sta_thread(config: SMTCConfig, enabled: Option<bool>) -> anyhow::Result<Self> {
+        let backend = StaBackend::new(EventThread::spawn("smtc-sta")?)?;
+        let dispatcher = EventDispatcher::Thread(backend.thread().clone());
+        Self::create(
+            Some(config),
+            enabled,
+            Arc::new(SystemClock::new()),
+            Arc::new(backend),
+            move |internal| {
+                internal.state().dispatcher = dispatcher;
+                Ok(())
+            },
+        )
     }
 
-    pub fn update_repeat_mode(&self, repeat_mode: String) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+    /// Runs `setup` after the config is applied but before the control is
+    /// enabled.
+    fn create(
+        config: Option<SMTCConfig>,
+        enabled: Option<bool>,
+        clock: SharedClock,
+        backend: Arc<dyn SmtcBackend>,
+        setup: impl FnOnce(&Self) -> anyhow::Result<()>,
+    ) -> anyhow::Result<Self> {
+        let state = Arc::default();
+        let lifetime = Arc::new(Lifetime {
+            backend: backend.clone(),
+            state: Arc::clone(&state),
+        });
+        {
+            let mut instances = INSTANCES.lock().unwrap_or_else(PoisonError::into_inner);
+            instances.retain(|instance| instance.strong_count() > 0);
+            instances.push(Arc::downgrade(&lifetime));
+        }
+        let internal = Self {
+            backend,
+            state,
+            created_at: clock.now(),
+            clock,
+            _lifetime: lifetime,
+        };
 
-        match repeat_mode.as_str() {
-            "none" => smtc.SetAutoRepeatMode(MediaPlaybackAutoRepeatMode::None)?,
-            "track" => smtc.SetAutoRepeatMode(MediaPlaybackAutoRepeatMode::Track)?,
-            "list" => smtc.SetAutoRepeatMode(MediaPlaybackAutoRepeatMode::List)?,
-            _ => smtc.SetAutoRepeatMode(MediaPlaybackAutoRepeatMode::None)?,
+        if let Some(config) = config {
+            internal.update_config(config)?;
         }
 
-        Ok(())
+        setup(&internal)?;
+
+        let enabled = enabled.unwrap_or(true);
+        internal.apply(&[BackendCall::SetEnabled(enabled)])?;
+        internal.state().last_enabled = Some(enabled);
+        if enabled {
+            internal.show_default_metadata()?;
+        }
+        Ok(internal)
     }
 
-    pub fn enable_smtc(&self) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls();
-        smtc?.SetIsEnabled(true)?;
-        Ok(())
+    fn state(&self) -> MutexGuard<'_, SMTCState> {
+        lock_state(&self.state)
     }
 
-    pub fn disable_smtc(&self) -> anyhow::Result<()> {
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls();
-        smtc?.SetIsEnabled(false)?;
+    /// Sends `calls` to the backend, in order.
+    fn apply(&self, calls: &[BackendCall]) -> anyhow::Result<()> {
+        apply_calls(&*self.backend, &self.state, calls)
+    }
+
+    /// Forwards events through `handle` instead of calling the sinks from the
+    /// WinRT callback thread. Pass `None` to go back to direct delivery.
+    ///
+    /// Spawned forwarding gives up ordering guarantees between events, and a
+    /// callback may run after the WinRT handler has already returned, so it
+    /// must not rely on the event args still being valid. A runtime that is
+    /// blocked on a call into this instance will also hold back its events.
+    #[cfg(feature = "tokio")]
+    pub fn set_event_runtime(&self, handle: Option<tokio::runtime::Handle>) {
+        self.state().dispatcher = match handle {
+            Some(handle) => EventDispatcher::Runtime(handle),
+            None => EventDispatcher::Direct,
+        };
+    }
+
+    /// Delivers events from a thread this crate owns, initialized as an STA,
+    /// instead of the WinRT callback thread, in the order they arrived.
+    /// Pass `false` to go back to direct delivery.
+    ///
+    /// Only event delivery moves: handlers are still registered, and calls
+    /// still run, on the calling thread; create the instance with
+    /// `on_sta_thread` to move those too. Like `set_event_runtime`, a
+    /// callback may run after the WinRT handler has returned.
+    pub fn set_event_thread(&self, dedicated: bool) -> anyhow::Result<()> {
+        let dispatcher = if dedicated {
+            EventDispatcher::Thread(EventThread::spawn("smtc-events")?)
+        } else {
+            EventDispatcher::Direct
+        };
+        self.state().dispatcher = dispatcher;
         Ok(())
     }
 
-    pub fn button_press_event(&self, sink: StreamSink<String>) -> anyhow::Result<()> {
-        let handler = TypedEventHandler::<
-            SystemMediaTransportControls,
-            SystemMediaTransportControlsButtonPressedEventArgs,
-        >::new(move |_, args| {
-            let button = args.as_ref().unwrap().Button().unwrap();
-
-            match button {
-                SystemMediaTransportControlsButton::Play => {
-                    sink.add("play".to_string());
-                }
-                SystemMediaTransportControlsButton::Pause => {
-                    sink.add("pause".to_string());
-                }
-                SystemMediaTransportControlsButton::Next => {
-                    sink.add("next".to_string());
-                }
-                SystemMediaTransportControlsButton::Previous => {
-                    sink.add("previous".to_string());
-                }
-                SystemMediaTransportControlsButton::FastForward => {
-                    sink.add("fast_forward".to_string());
-                }
-                SystemMediaTransportControlsButton::Rewind => {
-                    sink.add("rewind".to_string());
-                }
-                SystemMediaTransportControlsButton::Stop => {
-                    sink.add("stop".to_string());
-                }
-                SystemMediaTransportControlsButton::Record => {
-                    sink.add("record".to_string());
-                }
-                SystemMediaTransportControlsButton::ChannelUp => {
-                    sink.add("channel_up".to_string());
+    /// Holds back every change to the control until `thaw`, e.g. during a
+    /// bulk import. Of the `update_*` calls only the latest of each kind is
+    /// kept; everything else (placeholders, title overrides, clearing
+    /// fields and so on) is kept as made. Calling it again while frozen has
+    /// no effect.
+    pub fn freeze(&self) {
+        let mut state = self.state();
+        if state.frozen.is_none() {
+            state.frozen = Some(PendingUpdates::default());
+        }
+    }
+
+    /// Applies what was held back since `freeze`, in the order it was made
+    /// and in one batch, and goes back to applying changes immediately.
+    pub fn thaw(&self) -> anyhow::Result<()> {
+        let Some(pending) = self.state().frozen.take() else {
+            return Ok(());
+        };
+
+        self.state().collected = Some(Vec::new());
+        let replayed = self.replay(pending);
+        let calls = self.state().collected.take().unwrap_or_default();
+        replayed?;
+        if calls.is_empty() {
+            return Ok(());
+        }
+        self.apply(&calls)
+    }
+
+    fn replay(&self, pending: PendingUpdates) -> anyhow::Result<()> {
+        for update in pending.0 {
+            match update {
+                PendingUpdate::Config(config) => self.update_config(config)?,
+                PendingUpdate::Metadata(metadata, app_id, clear, silent) => {
+                    let token = CancellationToken::new();
+                    self.update_metadata_unless_cancelled(metadata, app_id, clear, silent, token)?;
                 }
-                SystemMediaTransportControlsButton::ChannelDown => {
-                    sink.add("channel_down".to_string());
+                PendingUpdate::Timeline(timeline) => self.update_timeline(timeline)?,
+                PendingUpdate::Status(status) => self.update_playback_status(status)?,
+                PendingUpdate::Shuffle(shuffle) => self.update_shuffle(shuffle)?,
+                PendingUpdate::RepeatMode(repeat_mode) => {
+                    self.update_repeat_mode(repeat_mode.as_str().to_string())?
                 }
-                _ => {}
+                PendingUpdate::Calls(calls) => self.apply(&calls)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the control over to another session in one coalesced update,
+    /// the way `with_init` sets up a new one, so the overlay never shows the
+    /// new metadata with the old timeline or status. Pieces left `None` keep
+    /// their current value; `enabled` is applied once the rest is in place.
+    pub fn switch_session_full(&self, init: SmtcInit) -> anyhow::Result<()> {
+        let SmtcInit {
+            config,
+            metadata,
+            app_id,
+            timeline,
+            status,
+            enabled,
+        } = init;
+
+        self.freeze();
+        let recorded = (|| -> anyhow::Result<()> {
+            if let Some(config) = config {
+                self.update_config(config)?;
+            }
+            if let Some(metadata) = metadata {
+                self.update_metadata(metadata, app_id)?;
+            }
+            if let Some(timeline) = timeline {
+                self.update_timeline(timeline)?;
+            }
+            if let Some(status) = status {
+                self.update_playback_status(status)?;
             }
             Ok(())
-        });
+        })();
+        let thawed = self.thaw();
+        recorded.and(thawed)?;
+
+        match enabled {
+            Some(true) => self.enable_smtc(),
+            Some(false) => self.disable_smtc(),
+            None => Ok(()),
+        }
+    }
 
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+    /// The config in effect, with the button flags read back from the
+    /// control so OS overrides show up. Fields the OS doesn't know about are
+    /// the values last passed to `update_config`.
+    pub fn current_config(&self) -> anyhow::Result<SMTCConfig> {
+        let backend = &self.backend;
 
-        smtc.ButtonPressed(&handler)?;
+        let mut config = self.state().config.clone();
+        config.play_enabled = backend.is_button_enabled(SmtcButton::Play)?;
+        config.pause_enabled = backend.is_button_enabled(SmtcButton::Pause)?;
+        config.stop_enabled = backend.is_button_enabled(SmtcButton::Stop)?;
+        config.next_enabled = backend.is_button_enabled(SmtcButton::Next)?;
+        config.prev_enabled = backend.is_button_enabled(SmtcButton::Previous)?;
+        config.fast_forward_enabled = backend.is_button_enabled(SmtcButton::FastForward)?;
+        config.rewind_enabled = backend.is_button_enabled(SmtcButton::Rewind)?;
+        config.channel_up_enabled = backend.is_button_enabled(SmtcButton::ChannelUp)?;
+        config.channel_down_enabled = backend.is_button_enabled(SmtcButton::ChannelDown)?;
 
-        anyhow::Result::Ok(())
+        Ok(config)
     }
 
-    pub fn position_change_request_event(&self, sink: StreamSink<i64>) -> anyhow::Result<()> {
-        let handler = TypedEventHandler::<
-            SystemMediaTransportControls,
-            PlaybackPositionChangeRequestedEventArgs,
-        >::new(move |_, args| {
-            let position_ms = args
-                .as_ref()
-                .unwrap()
-                .RequestedPlaybackPosition()
-                .unwrap()
-                .Duration;
+    /// Records `update` with `record` and returns `None` while frozen,
+    /// otherwise hands it back to be applied.
+    fn defer<T>(&self, update: T, record: impl FnOnce(&mut PendingUpdates, T)) -> Option<T> {
+        match &mut self.state().frozen {
+            Some(pending) => {
+                record(pending, update);
+                None
+            }
+            None => Some(update),
+        }
+    }
 
-            sink.add(position_ms);
-            Ok(())
-        });
+    pub fn update_config(&self, config: SMTCConfig) -> anyhow::Result<()> {
+        let Some(config) = self.defer(config, |pending, config| {
+            pending.record(PendingUpdate::Config(config))
+        }) else {
+            return Ok(());
+        };
 
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+        // Stored first, so `apply` sees this config's `dry_run`.
+        let placeholder = {
+            let mut state = self.state();
+            // Only these fields change what a cached source resolves to.
+            if state.config.thumbnails_differ(&config) {
+                state.thumbnail_cache.clear();
+            }
+            state.config = config.clone();
+            state.placeholder
+        };
+
+        // The placeholder keeps its buttons disabled until real metadata.
+        if !placeholder {
+            self.apply(&button_calls(&config))?;
+        }
 
-        smtc.PlaybackPositionChangeRequested(&handler)?;
+        Ok(())
+    }
 
-        anyhow::Result::Ok(())
+    /// Replaces the displayed metadata.
+    ///
+    /// `Update()` itself never shows the media flyout: Windows only pops it
+    /// for media key and volume presses, and no property or call order on the
+    /// display updater changes that. For background refreshes that the app
+    /// shouldn't hear about either, see `update_metadata_silently`.
+    pub fn update_metadata(
+        &self,
+        metadata: MusicMetadata,
+        app_id: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.update_metadata_with(metadata, app_id, ClearFlags::all())
     }
 
-    pub fn shuffle_request_event(&self, sink: StreamSink<bool>) -> anyhow::Result<()> {
-        let handler = TypedEventHandler::<
-            SystemMediaTransportControls,
-            ShuffleEnabledChangeRequestedEventArgs,
-        >::new(move |_, args| {
-            let shuffle = args.as_ref().unwrap().RequestedShuffleEnabled().unwrap();
+    /// Like `update_metadata`, but without the `StateChange` that
+    /// `state_changed_event` would send, e.g. for a periodic refresh of
+    /// metadata that hasn't changed. The overlay is as silent as ever.
+    pub fn update_metadata_silently(
+        &self,
+        metadata: MusicMetadata,
+        app_id: Option<String>,
+    ) -> anyhow::Result<()> {
+        let token = CancellationToken::new();
+        self.update_metadata_unless_cancelled(metadata, app_id, ClearFlags::all(), true, token)
+            .map(drop)
+    }
 
-            sink.add(shuffle);
-            Ok(())
-        });
+    /// `update_metadata`, then reads the text fields, track number and app
+    /// media id back from the display updater and reports any the control
+    /// doesn't hold as set (after sanitizing and `title_override`). Only
+    /// `Music` metadata is read back; while frozen nothing is applied yet,
+    /// so the report is empty.
+    pub fn update_metadata_verified(
+        &self,
+        metadata: MusicMetadata,
+        app_id: Option<String>,
+    ) -> anyhow::Result<MetadataReport> {
+        self.update_metadata(metadata, app_id.clone())?;
+
+        let (expected, title_override) = {
+            let state = self.state();
+            if state.frozen.is_some() {
+                return Ok(MetadataReport::default());
+            }
+            (state.metadata.clone(), state.title_override.clone())
+        };
+        let mut report = MetadataReport::default();
+        let Some(expected) = expected else {
+            return Ok(report);
+        };
+
+        let backend = &self.backend;
+        if let Some(app_id) = app_id {
+            report.check("app_media_id", app_id, backend.app_media_id()?);
+        }
+        if expected.media_type != MediaType::Music {
+            return Ok(report);
+        }
 
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+        let text = |value: Option<String>| value.unwrap_or_default();
+        report.check(
+            "title",
+            text(title_override.or(expected.title)),
+            backend.text(DisplayField::MusicTitle)?,
+        );
+        report.check(
+            "artist",
+            text(expected.artist),
+            backend.text(DisplayField::MusicArtist)?,
+        );
+        report.check(
+            "album",
+            text(expected.album),
+            backend.text(DisplayField::MusicAlbum)?,
+        );
+        report.check(
+            "album_artist",
+            text(expected.album_artist),
+            backend.text(DisplayField::MusicAlbumArtist)?,
+        );
+        if let Some(track_number) = expected.track_number {
+            report.check(
+                "track_number",
+                track_number.to_string(),
+                backend.track_number()?.to_string(),
+            );
+        }
 
-        smtc.ShuffleEnabledChangeRequested(&handler)?;
+        Ok(report)
+    }
 
-        anyhow::Result::Ok(())
+    /// Switches to another service's session: replaces the app media id and
+    /// all metadata in a single `Update()`, so the overlay never shows one
+    /// service's branding with the other's track.
+    pub fn switch_session(&self, app_id: String, metadata: MusicMetadata) -> anyhow::Result<()> {
+        self.update_metadata_with(metadata, Some(app_id), ClearFlags::all())
     }
 
-    pub fn repeat_mode_request_event(&self, sink: StreamSink<String>) -> anyhow::Result<()> {
-        let handler = TypedEventHandler::<
-            SystemMediaTransportControls,
-            AutoRepeatModeChangeRequestedEventArgs,
-        >::new(move |_, args| {
-            let repeat_mode = args.as_ref().unwrap().RequestedAutoRepeatMode().unwrap();
-
-            match repeat_mode {
-                MediaPlaybackAutoRepeatMode::None => {
-                    sink.add("none".to_string());
-                }
-                MediaPlaybackAutoRepeatMode::Track => {
-                    sink.add("track".to_string());
-                }
-                MediaPlaybackAutoRepeatMode::List => {
-                    sink.add("list".to_string());
-                }
-                _ => {
-                    sink.add("none".to_string());
+    /// `update_metadata` with the metadata given as field names to values,
+    /// see `MusicMetadata::from_map`.
+    pub fn update_metadata_map(
+        &self,
+        fields: HashMap<String, String>,
+        app_id: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.update_metadata(MusicMetadata::from_map(fields)?, app_id)
+    }
+
+    /// Like `update_metadata`, but only clears the display property groups
+    /// selected in `clear` before applying `metadata`.
+    pub fn update_metadata_with(
+        &self,
+        metadata: MusicMetadata,
+        app_id: Option<String>,
+        clear: ClearFlags,
+    ) -> anyhow::Result<()> {
+        let token = CancellationToken::new();
+        self.update_metadata_unless_cancelled(metadata, app_id, clear, false, token)
+            .map(drop)
+    }
+
+    /// `update_metadata` on a background thread, for metadata whose art is
+    /// slow to load. The update is abandoned once `token` is cancelled, and
+    /// any later metadata update cancels it too; an abandoned update never
+    /// touches the control. Resolves to whether the metadata was applied.
+    pub fn update_metadata_async(
+        &self,
+        metadata: MusicMetadata,
+        app_id: Option<String>,
+        token: CancellationToken,
+    ) -> Completion<anyhow::Result<bool>> {
+        let internal = self.clone();
+        task::spawn_blocking(move || {
+            let clear = ClearFlags::all();
+            internal.update_metadata_unless_cancelled(metadata, app_id, clear, false, token)
+        })
+    }
+
+    fn update_metadata_unless_cancelled(
+        &self,
+        metadata: MusicMetadata,
+        app_id: Option<String>,
+        clear: ClearFlags,
+        silent: bool,
+        token: CancellationToken,
+    ) -> anyhow::Result<bool> {
+        if let Some(previous) = self.state().metadata_token.replace(token.clone()) {
+            previous.cancel();
+        }
+
+        let metadata = self.prepare_metadata(metadata)?;
+        let Some((metadata, app_id, clear)) = self.defer(
+            (metadata, app_id, clear),
+            |pending, (metadata, app_id, clear)| {
+                pending.record(PendingUpdate::Metadata(metadata, app_id, clear, silent))
+            },
+        ) else {
+            return Ok(true);
+        };
+
+        if !self.apply_metadata_unless_cancelled(&metadata, app_id.clone(), clear, &token)? {
+            return Ok(false);
+        }
+        self.end_placeholder()?;
+
+        {
+            let mut state = self.state();
+            state.metadata = Some(metadata.clone());
+            state.app_id = app_id;
+            state.preview = None;
+            state.auto_disable_generation += 1;
+        }
+        if !silent {
+            self.notify_state_changed(StateChange {
+                metadata: Some(metadata),
+                ..StateChange::default()
+            });
+        }
+
+        Ok(true)
+    }
+
+    /// Sends a `StateChange` after every successful `update_*` call, for apps
+    /// mirroring the control's state. Only the values the update set are
+    /// filled in; updates held back by `freeze` are reported once applied.
+    pub fn state_changed_event(&self, sink: StreamSink<StateChange>) {
+        self.state().state_listeners.add(move |change| {
+            let _ = sink.add(change);
+        });
+    }
+
+    fn notify_state_changed(&self, change: StateChange) {
+        let (listeners, dispatcher) = {
+            let state = self.state();
+            if state.state_listeners.is_empty() {
+                return;
+            }
+            (state.state_listeners.clone(), state.dispatcher.clone())
+        };
+        dispatcher.dispatch(move || listeners.notify(change));
+    }
+
+    /// Blanks only `fields`, on the overlay and in the stored metadata, and
+    /// leaves the rest shown, e.g. to drop the album of a single. Title and
+    /// artist are also blanked as the title and subtitle of video and image
+    /// content.
+    pub fn clear_metadata_fields(&self, fields: Vec<MetadataField>) -> anyhow::Result<()> {
+        let mut calls = Vec::new();
+
+        for &field in &fields {
+            match field {
+                MetadataField::Title => calls.extend(blank([
+                    DisplayField::MusicTitle,
+                    DisplayField::VideoTitle,
+                    DisplayField::ImageTitle,
+                ])),
+                MetadataField::Artist => calls.extend(blank([
+                    DisplayField::MusicArtist,
+                    DisplayField::VideoSubtitle,
+                    DisplayField::ImageSubtitle,
+                ])),
+                MetadataField::Album => calls.extend(blank([DisplayField::MusicAlbum])),
+                MetadataField::AlbumArtist => calls.extend(blank([DisplayField::MusicAlbumArtist])),
+                MetadataField::Genres => calls.push(BackendCall::SetGenres(Vec::new())),
+                MetadataField::TrackNumber => calls.push(BackendCall::SetTrackNumber(0)),
+                MetadataField::Thumbnail => {
+                    // Keep a thumbnail still loading from showing up later.
+                    self.next_thumbnail_generation();
+                    calls.push(BackendCall::SetThumbnail(None));
                 }
             }
+        }
+        calls.push(BackendCall::UpdateDisplay);
+        self.apply(&calls)?;
 
-            Ok(())
-        });
+        if let Some(metadata) = &mut self.state().metadata {
+            metadata.clear_fields(&fields);
+        }
+        Ok(())
+    }
+
+    /// Stores the upcoming tracks. No Windows build shows a queue for SMTC
+    /// sessions (`MediaPlaybackList` only applies to media the player itself
+    /// plays), so the queue is only kept for `queue`; it is never sent to
+    /// the OS.
+    pub fn set_queue(&self, queue: Vec<MusicMetadata>) {
+        self.state().queue = queue;
+    }
+
+    pub fn queue(&self) -> Vec<MusicMetadata> {
+        self.state().queue.clone()
+    }
+
+    /// Shows a neutral "nothing playing" entry, for keeping the session
+    /// visible with an empty queue: `title` and `subtitle` over `thumbnail`,
+    /// `Stopped`, and every transport button disabled. It isn't treated as
+    /// metadata (the current metadata becomes `None`); the next
+    /// `update_metadata` replaces it and re-enables the configured buttons.
+    pub fn set_placeholder(
+        &self,
+        title: String,
+        subtitle: Option<String>,
+        thumbnail: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.next_thumbnail_generation();
+
+        self.update_playback_status(PlaybackStatus::Stopped)?;
+        let mut calls = button_calls(&SMTCConfig {
+            play_enabled: false,
+            pause_enabled: false,
+            stop_enabled: false,
+            next_enabled: false,
+            prev_enabled: false,
+            fast_forward_enabled: false,
+            rewind_enabled: false,
+            channel_up_enabled: false,
+            channel_down_enabled: false,
+            record_as_favorite: false,
+            record_as_toggle: false,
+            ..SMTCConfig::default()
+        });
+
+        calls.push(BackendCall::ClearDisplay);
+        calls.push(BackendCall::SetMediaType(MediaType::Music));
+        calls.push(BackendCall::SetText(DisplayField::MusicTitle, title));
+        if let Some(subtitle) = subtitle {
+            calls.push(BackendCall::SetText(DisplayField::MusicArtist, subtitle));
+        }
+
+        if self.state().config.thumbnails_enabled {
+            if let Some(thumbnail) = self.resolve_first_thumbnail(thumbnail.as_deref().into_iter())
+            {
+                calls.push(BackendCall::SetThumbnail(Some(thumbnail)));
+            }
+        }
+
+        calls.push(BackendCall::UpdateDisplay);
+        self.apply(&calls)?;
+
+        let mut state = self.state();
+        state.placeholder = true;
+        state.metadata = None;
+        state.preview = None;
+
+        Ok(())
+    }
+
+    /// Restores the configured buttons if the placeholder is shown.
+    fn end_placeholder(&self) -> anyhow::Result<()> {
+        let config = {
+            let mut state = self.state();
+            if !std::mem::take(&mut state.placeholder) {
+                return Ok(());
+            }
+            state.config.clone()
+        };
+
+        self.apply(&button_calls(&config))
+    }
+
+    /// Shows `metadata` for `revert_after_ms`, then goes back to the metadata
+    /// last set through `update_metadata`, e.g. to preview the target track
+    /// while the user is still skipping. `confirm_preview` keeps the preview
+    /// instead, and any `update_metadata` call replaces it.
+    pub fn preview_metadata(
+        &self,
+        metadata: MusicMetadata,
+        revert_after_ms: u64,
+    ) -> anyhow::Result<()> {
+        let metadata = self.prepare_metadata(metadata)?;
+        let app_id = self.state().app_id.clone();
+        self.apply_metadata(&metadata, app_id, ClearFlags::all())?;
+
+        let generation = {
+            let mut state = self.state();
+            state.preview_generation += 1;
+            state.preview = Some(MetadataPreview {
+                generation: state.preview_generation,
+                metadata,
+            });
+            state.preview_generation
+        };
+
+        let internal = self.clone();
+        self.clock.run_after(
+            Duration::from_millis(revert_after_ms),
+            Box::new(move || {
+                let _ = internal.revert_preview(generation);
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    /// Makes the metadata shown by `preview_metadata` the current metadata,
+    /// cancelling its revert. Returns `false` if no preview was active.
+    pub fn confirm_preview(&self) -> bool {
+        let mut state = self.state();
+        let Some(preview) = state.preview.take() else {
+            return false;
+        };
+        state.metadata = Some(preview.metadata);
+        true
+    }
+
+    fn revert_preview(&self, generation: u64) -> anyhow::Result<()> {
+        let (metadata, app_id) = {
+            let mut state = self.state();
+            match &state.preview {
+                Some(preview) if preview.generation == generation => {}
+                _ => return Ok(()),
+            }
+            state.preview = None;
+            (state.metadata.clone(), state.app_id.clone())
+        };
+
+        match metadata {
+            Some(metadata) => self.apply_metadata(&metadata, app_id, ClearFlags::all()),
+            None => self.clear_metadata(),
+        }
+    }
+
+    /// Applies the configured sanitizing and validation to `metadata`.
+    fn prepare_metadata(&self, metadata: MusicMetadata) -> anyhow::Result<MusicMetadata> {
+        let metadata = if self.state().config.sanitize_metadata {
+            metadata.sanitized()
+        } else {
+            metadata
+        };
+
+        if self.state().config.strict_metadata {
+            if let Err(problems) = validate_metadata(&metadata) {
+                anyhow::bail!("invalid metadata: {}", problems.join(", "));
+            }
+        }
+
+        Ok(metadata)
+    }
+
+    fn apply_metadata(
+        &self,
+        metadata: &MusicMetadata,
+        app_id: Option<String>,
+        clear: ClearFlags,
+    ) -> anyhow::Result<()> {
+        self.apply_metadata_unless_cancelled(metadata, app_id, clear, &CancellationToken::new())
+            .map(drop)
+    }
+
+    /// Applies `metadata` unless `token` is cancelled before the display is
+    /// touched. The art is resolved first, since that's the slow part.
+    fn apply_metadata_unless_cancelled(
+        &self,
+        metadata: &MusicMetadata,
+        app_id: Option<String>,
+        clear: ClearFlags,
+        token: &CancellationToken,
+    ) -> anyhow::Result<bool> {
+        let Some(calls) = self.metadata_calls(metadata, app_id, clear, token) else {
+            return Ok(false);
+        };
+        self.apply(&calls)?;
+
+        Ok(true)
+    }
+
+    /// The calls that show `metadata`, ending with the display update, or
+    /// `None` if `token` was cancelled while the art was resolved.
+    fn metadata_calls(
+        &self,
+        metadata: &MusicMetadata,
+        app_id: Option<String>,
+        clear: ClearFlags,
+        token: &CancellationToken,
+    ) -> Option<Vec<BackendCall>> {
+        let generation = self.next_thumbnail_generation();
+        let (thumbnails_enabled, transition) = {
+            let config = &self.state().config;
+            (config.thumbnails_enabled, config.thumbnail_transition)
+        };
+        let (thumbnail, pending) = if thumbnails_enabled {
+            self.resolve_metadata_thumbnail(metadata, generation, token)
+        } else {
+            (None, false)
+        };
+
+        if token.is_cancelled() {
+            return None;
+        }
+
+        let kept = if pending && transition == ThumbnailTransition::KeepOld {
+            self.backend.thumbnail().ok().flatten()
+        } else {
+            None
+        };
+
+        let mut calls = clear_calls(clear);
+
+        if let Some(app_id) = app_id {
+            calls.push(BackendCall::SetAppMediaId(app_id));
+        }
+
+        calls.push(BackendCall::SetMediaType(metadata.media_type));
+
+        let title = self
+            .state()
+            .title_override
+            .clone()
+            .or_else(|| metadata.title.clone());
+        let mut text = |field, value: &Option<String>| {
+            if let Some(value) = value {
+                calls.push(BackendCall::SetText(field, value.clone()));
+            }
+        };
+
+        match metadata.media_type {
+            MediaType::Music => {
+                text(DisplayField::MusicArtist, &metadata.artist);
+                text(DisplayField::MusicAlbum, &metadata.album);
+                text(DisplayField::MusicTitle, &title);
+                text(DisplayField::MusicAlbumArtist, &metadata.album_artist);
+
+                if !metadata.genres.is_empty() {
+                    calls.push(BackendCall::SetGenres(metadata.genres.clone()));
+                }
+                if let Some(track_number) = metadata.track_number {
+                    calls.push(BackendCall::SetTrackNumber(track_number));
+                }
+            }
+            MediaType::Video => {
+                text(DisplayField::VideoTitle, &title);
+                text(DisplayField::VideoSubtitle, &metadata.artist);
+            }
+            MediaType::Image => {
+                text(DisplayField::ImageTitle, &title);
+                text(DisplayField::ImageSubtitle, &metadata.artist);
+            }
+            MediaType::Unknown => {
+                // Only the app media id and the thumbnail apply; drop what a
+                // partial clear left in the typed groups.
+                calls.extend(clear_calls(
+                    ClearFlags::MUSIC_PROPERTIES | ClearFlags::VIDEO_PROPERTIES,
+                ));
+            }
+        }
+
+        match thumbnail.or(kept) {
+            Some(x) => calls.push(BackendCall::SetThumbnail(Some(x))),
+            None if clear.contains(ClearFlags::THUMBNAIL) || !thumbnails_enabled => {
+                calls.push(BackendCall::SetThumbnail(None))
+            }
+            None => {}
+        }
+
+        calls.push(BackendCall::UpdateDisplay);
+        Some(calls)
+    }
+
+    /// Resolves the art for `metadata`, giving up after
+    /// `thumbnail_budget_ms` if set, or right away in `ThumbnailMode::Async`.
+    /// Art that isn't returned is shown once it loads, unless `generation`
+    /// was superseded or `token` cancelled by then; the returned flag says
+    /// whether that is still to come.
+    fn resolve_metadata_thumbnail(
+        &self,
+        metadata: &MusicMetadata,
+        generation: u64,
+        token: &CancellationToken,
+    ) -> (Option<RandomAccessStreamReference>, bool) {
+        let (mode, budget, transition) = {
+            let config = &self.state().config;
+            (
+                config.thumbnail_mode,
+                config.thumbnail_budget_ms,
+                config.thumbnail_transition,
+            )
+        };
+        let budget = match (mode, budget) {
+            (ThumbnailMode::Async, _) => 0,
+            (ThumbnailMode::Sync, 0) => {
+                return (
+                    self.resolve_first_thumbnail(metadata.thumbnail_sources()),
+                    false,
+                );
+            }
+            (ThumbnailMode::Sync, budget) => budget,
+        };
+
+        let sources: Vec<String> = metadata.thumbnail_sources().map(str::to_string).collect();
+        if sources.is_empty() {
+            return (None, false);
+        }
+
+        // Taken by whichever side gives up first, so the art is either
+        // returned here or applied by the job, never lost in between.
+        let (sender, receiver) = mpsc::channel();
+        let sender = Arc::new(Mutex::new(Some(sender)));
+
+        let internal = self.clone();
+        let job_sender = sender.clone();
+        let token = token.clone();
+        let _ = self.thumbnail_pool().submit(move || {
+            let result = internal.resolve_first_thumbnail(sources.iter().map(String::as_str));
+            let sender = job_sender
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take();
+            match sender {
+                Some(sender) => {
+                    let _ = sender.send(result);
+                }
+                None => {
+                    if token.is_cancelled() || internal.thumbnail_superseded(generation) {
+                        return;
+                    }
+                    match result {
+                        Some(thumbnail) => {
+                            let _ = internal.show_thumbnail(&thumbnail);
+                        }
+                        // Don't leave the previous track's art behind.
+                        _ if transition == ThumbnailTransition::KeepOld => {
+                            let _ = internal.hide_thumbnail();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        });
+
+        match receiver.recv_timeout(Duration::from_millis(budget)) {
+            Ok(result) => (result, false),
+            Err(_) => {
+                let gave_up = sender
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .take()
+                    .is_some();
+                if gave_up {
+                    log::debug!("showing thumbnail once loaded, after {budget}ms");
+                    (None, true)
+                } else {
+                    // The job got there first and is sending the result.
+                    (receiver.recv().unwrap_or(None), false)
+                }
+            }
+        }
+    }
+
+    fn show_thumbnail(&self, thumbnail: &RandomAccessStreamReference) -> anyhow::Result<()> {
+        self.apply(&[
+            BackendCall::SetThumbnail(Some(thumbnail.clone())),
+            BackendCall::UpdateDisplay,
+        ])
+    }
+
+    fn hide_thumbnail(&self) -> anyhow::Result<()> {
+        self.apply(&[BackendCall::SetThumbnail(None), BackendCall::UpdateDisplay])
+    }
+
+    /// Shows `title` instead of the track title, e.g. "Advertisement" during
+    /// an ad, leaving the other fields and the art alone. Metadata updates
+    /// made in the meantime keep the override until `clear_title_override`.
+    pub fn push_title_override(&self, title: String) -> anyhow::Result<()> {
+        self.state().title_override = Some(title.clone());
+        self.set_displayed_title(Some(title))
+    }
+
+    /// Restores the title of the metadata currently shown.
+    pub fn clear_title_override(&self) -> anyhow::Result<()> {
+        let title = {
+            let mut state = self.state();
+            state.title_override = None;
+            let shown = match &state.preview {
+                Some(preview) => Some(&preview.metadata),
+                None => state.metadata.as_ref(),
+            };
+            shown.and_then(|m| m.title.clone())
+        };
+        self.set_displayed_title(title)
+    }
+
+    fn set_displayed_title(&self, title: Option<String>) -> anyhow::Result<()> {
+        self.apply(&[
+            BackendCall::SetText(DisplayField::MusicTitle, title.unwrap_or_default()),
+            BackendCall::UpdateDisplay,
+        ])
+    }
+
+    /// Resolves `source` and applies the configured post-processing, reusing
+    /// a prefetched reference if there is one. The outcome is reported to
+    /// `thumbnail_result_event` listeners.
+    fn resolve_thumbnail(
+        &self,
+        source: &str,
+    ) -> Result<RandomAccessStreamReference, ThumbnailError> {
+        let (result, from_cache) = match self.resolve_thumbnail_uncached(source) {
+            Ok((reference, from_cache)) => (Ok(reference), from_cache),
+            Err(e) => (Err(e), false),
+        };
+        self.report_thumbnail(source, &result, from_cache);
+        result
+    }
+
+    /// Also returns whether the reference came from the cache.
+    fn resolve_thumbnail_uncached(
+        &self,
+        source: &str,
+    ) -> Result<(RandomAccessStreamReference, bool), ThumbnailError> {
+        let scheme = ThumbnailScheme::of(source);
+        if !self
+            .state()
+            .config
+            .allowed_thumbnail_schemes
+            .contains(&scheme)
+        {
+            return Err(ThumbnailError::SchemeNotAllowed(scheme));
+        }
+
+        if let Some(reference) = self.state().thumbnail_cache.get(source) {
+            return Ok((reference, true));
+        }
+
+        self.load_thumbnail(source)
+            .map(|reference| (reference, false))
+    }
+
+    fn report_thumbnail(
+        &self,
+        source: &str,
+        result: &Result<RandomAccessStreamReference, ThumbnailError>,
+        from_cache: bool,
+    ) {
+        let (listeners, dispatcher) = {
+            let state = self.state();
+            (state.thumbnail_listeners.clone(), state.dispatcher.clone())
+        };
+        if listeners.is_empty() {
+            return;
+        }
+
+        let result = ThumbnailResult {
+            source: source.to_string(),
+            error: result.as_ref().err().cloned(),
+            from_cache,
+        };
+        dispatcher.dispatch(move || listeners.notify(result));
+    }
+
+    /// Reports the outcome of every thumbnail load, from `update_metadata`,
+    /// `set_thumbnail` or `prefetch_thumbnail`.
+    pub fn thumbnail_result_event(&self, sink: StreamSink<ThumbnailResult>) {
+        self.state().thumbnail_listeners.add(move |result| {
+            let _ = sink.add(result);
+        });
+    }
+
+    /// Resolves the first of `sources` that loads, logging the ones that
+    /// don't; each failure also reaches `thumbnail_result_event`. If none
+    /// loads there is no art, so the text still goes out without it.
+    fn resolve_first_thumbnail<'a>(
+        &self,
+        sources: impl Iterator<Item = &'a str>,
+    ) -> Option<RandomAccessStreamReference> {
+        for source in sources {
+            match self.resolve_thumbnail(source) {
+                Ok(reference) => return Some(reference),
+                Err(e @ ThumbnailError::SchemeNotAllowed(_)) => {
+                    log::warn!("skipping thumbnail {source}: {e}");
+                }
+                Err(e) => log::warn!("failed to load thumbnail {source}: {e}"),
+            }
+        }
+        None
+    }
+
+    fn load_thumbnail(&self, source: &str) -> Result<RandomAccessStreamReference, ThumbnailError> {
+        let (transcode, content_type, max_bytes, budget, headers, fetcher, resolver) = {
+            let state = self.state();
+            (
+                state.config.transcode_thumbnails,
+                state.config.thumbnail_content_type.clone(),
+                state.config.max_thumbnail_bytes,
+                state.config.thumbnail_budget_ms,
+                state.config.thumbnail_headers.clone(),
+                state.fetcher.clone(),
+                state.thumbnail_resolver.clone(),
+            )
+        };
+
+        let reference = match resolver {
+            Some(resolver) => {
+                let timeout = match budget {
+                    0 => RESOLVER_TIMEOUT,
+                    budget => Duration::from_millis(budget),
+                };
+                let bytes = (resolver.0)(source, timeout)
+                    .map_err(|e| ThumbnailError::FetchFailed(format!("{source}: {e:#}")))?;
+                thumbnail::check_size(source, bytes.len() as u64, max_bytes)?;
+                thumbnail::from_bytes(&bytes)?
+            }
+            None => {
+                let reference = thumbnail::resolve_with_headers(
+                    source,
+                    &headers,
+                    max_bytes,
+                    fetcher.0.as_ref(),
+                )?;
+                if max_bytes > 0 && !thumbnail::is_url(source) {
+                    let size = thumbnail::stream_size(&reference)?;
+                    thumbnail::check_size(source, size, max_bytes)?;
+                }
+                reference
+            }
+        };
+
+        let reference = if transcode {
+            // Fall back to the original art, the overlay may still manage it.
+            match thumbnail::transcode_to_png(&reference) {
+                Ok(transcoded) => transcoded,
+                Err(e) => {
+                    log::warn!("keeping untranscoded thumbnail {source}: {e}");
+                    reference
+                }
+            }
+        } else {
+            reference
+        };
+
+        match content_type {
+            Some(content_type) => thumbnail::with_content_type(&reference, &content_type),
+            None => Ok(reference),
+        }
+    }
+
+    /// Resolves `source` ahead of time, so a later `update_metadata` with the
+    /// same thumbnail doesn't wait on loading it. Returns `false` if the
+    /// config doesn't allow loading `source`.
+    pub fn prefetch_thumbnail(&self, source: &str) -> anyhow::Result<bool> {
+        if !self.state().config.thumbnails_enabled {
+            return Ok(false);
+        }
+
+        let reference = match self.resolve_thumbnail(source) {
+            Ok(reference) => reference,
+            Err(ThumbnailError::SchemeNotAllowed(_)) => return Ok(false),
+            Err(e) => return Err(e.into()),
+        };
+
+        self.state()
+            .thumbnail_cache
+            .insert(source.to_string(), reference);
+        Ok(true)
+    }
+
+    /// Makes this instance use `other`'s thumbnail cache instead of its own.
+    /// The cache keeps up to 8 thumbnails however many instances share it,
+    /// so sharing caps memory use but lets busy sessions evict each other's
+    /// prefetched art. A config update on either instance clears it for both.
+    pub fn share_thumbnail_cache(&self, other: &SMTCInternal) {
+        let cache = other.state().thumbnail_cache.clone();
+        self.state().thumbnail_cache = cache;
+    }
+
+    /// Replaces the client used to fetch thumbnails when `thumbnail_headers`
+    /// are configured.
+    pub fn set_thumbnail_fetcher(&self, fetcher: Arc<dyn ThumbnailFetcher>) {
+        self.state().fetcher = SharedFetcher(fetcher);
+    }
+
+    /// Loads every thumbnail through `resolver` instead of the built-in
+    /// file and http(s) handling, e.g. to go through the app's own cache.
+    /// The allowed schemes and transcoding still apply. `resolver` is given
+    /// how long it may take: `thumbnail_budget_ms`, or 10 seconds without a
+    /// budget. What it can't load in that time isn't shown.
+    pub fn set_thumbnail_resolver(
+        &self,
+        resolver: impl Fn(&str, Duration) -> anyhow::Result<Vec<u8>> + Send + Sync + 'static,
+    ) {
+        let mut state = self.state();
+        state.thumbnail_resolver = Some(ThumbnailResolver(Arc::new(resolver)));
+        state.thumbnail_cache.clear();
+    }
+
+    /// Goes back to the built-in thumbnail loading.
+    pub fn clear_thumbnail_resolver(&self) {
+        let mut state = self.state();
+        state.thumbnail_resolver = None;
+        state.thumbnail_cache.clear();
+    }
+
+    /// Replaces only the thumbnail with `source`, leaving the text metadata
+    /// alone. Returns `false` if the art couldn't be loaded (or the config
+    /// doesn't allow it); the previous art stays in that case.
+    pub fn set_thumbnail(&self, source: &str) -> anyhow::Result<bool> {
+        let generation = self.next_thumbnail_generation();
+        self.set_thumbnail_unless_superseded(source, generation)
+    }
+
+    /// `set_thumbnail` on the thumbnail worker pool, which runs at most
+    /// `SMTCConfig::thumbnail_workers` loads at once. Resolves to `false`
+    /// without touching the control if a newer thumbnail or metadata update
+    /// came in before this one got to apply its art.
+    pub fn set_thumbnail_async(&self, source: String) -> Completion<anyhow::Result<bool>> {
+        let generation = self.next_thumbnail_generation();
+        let internal = self.clone();
+        self.thumbnail_pool()
+            .submit(move || internal.set_thumbnail_unless_superseded(&source, generation))
+    }
+
+    fn next_thumbnail_generation(&self) -> u64 {
+        let mut state = self.state();
+        state.thumbnail_generation += 1;
+        state.thumbnail_generation
+    }
+
+    fn thumbnail_superseded(&self, generation: u64) -> bool {
+        self.state().thumbnail_generation != generation
+    }
+
+    /// The shared pool, (re)created to match `thumbnail_workers`. Jobs
+    /// already queued on a replaced pool still run.
+    fn thumbnail_pool(&self) -> Arc<WorkerPool> {
+        let mut state = self.state();
+        let size = state.config.thumbnail_workers.max(1);
+        match &state.thumbnail_pool {
+            Some(pool) if pool.size() == size => pool.clone(),
+            _ => {
+                let pool = Arc::new(WorkerPool::new("smtc-thumbnail", size));
+                state.thumbnail_pool = Some(pool.clone());
+                pool
+            }
+        }
+    }
+
+    /// `set_thumbnail`, also returning the average color of the art as ARGB
+    /// for theming the app around it. `None` if the art wasn't shown or its
+    /// pixels couldn't be read.
+    pub fn set_thumbnail_with_color(&self, source: &str) -> anyhow::Result<Option<u32>> {
+        let generation = self.next_thumbnail_generation();
+        let mut color = None;
+        self.set_thumbnail_inspecting(source, generation, |thumbnail| {
+            color = thumbnail::average_color(thumbnail)
+                .map_err(|e| log::warn!("failed to read the colors of {source}: {e}"))
+                .ok();
+        })
+        .map(|shown| if shown { color } else { None })
+    }
+
+    fn set_thumbnail_unless_superseded(
+        &self,
+        source: &str,
+        generation: u64,
+    ) -> anyhow::Result<bool> {
+        self.set_thumbnail_inspecting(source, generation, |_| {})
+    }
+
+    /// Resolves `source`, hands it to `inspect` and shows it, unless a newer
+    /// thumbnail change came in by then.
+    fn set_thumbnail_inspecting(
+        &self,
+        source: &str,
+        generation: u64,
+        inspect: impl FnOnce(&RandomAccessStreamReference),
+    ) -> anyhow::Result<bool> {
+        if !self.state().config.thumbnails_enabled || self.thumbnail_superseded(generation) {
+            return Ok(false);
+        }
+
+        let thumbnail = match self.resolve_thumbnail(source) {
+            Ok(thumbnail) => thumbnail,
+            Err(e) => {
+                log::warn!("failed to load thumbnail {source}: {e}");
+                return Ok(false);
+            }
+        };
+
+        inspect(&thumbnail);
+
+        if self.thumbnail_superseded(generation) {
+            return Ok(false);
+        }
+
+        self.show_thumbnail(&thumbnail)?;
+
+        Ok(true)
+    }
+
+    /// Replaces only the thumbnail with a file from the app's future access
+    /// list, see `thumbnail::from_access_token` for the packaging
+    /// requirement. Fails with a `ThumbnailError` for malformed or stale
+    /// tokens, or files over `max_thumbnail_bytes`. Does nothing while
+    /// `thumbnails_enabled` is off.
+    pub fn set_thumbnail_from_token(&self, token: &str) -> anyhow::Result<()> {
+        let (thumbnails_enabled, max_bytes, content_type) = {
+            let config = &self.state().config;
+            (
+                config.thumbnails_enabled,
+                config.max_thumbnail_bytes,
+                config.thumbnail_content_type.clone(),
+            )
+        };
+        if !thumbnails_enabled {
+            return Ok(());
+        }
+
+        let result = thumbnail::from_access_token(token).and_then(|reference| {
+            if max_bytes > 0 {
+                thumbnail::check_size(token, thumbnail::stream_size(&reference)?, max_bytes)?;
+            }
+            match content_type {
+                Some(content_type) => thumbnail::with_content_type(&reference, &content_type),
+                None => Ok(reference),
+            }
+        });
+        self.report_thumbnail(token, &result, false);
+
+        self.show_thumbnail(&result?)
+    }
+
+    /// Replaces only the thumbnail, reading it from an already open file.
+    /// Does nothing while `thumbnails_enabled` is off.
+    pub fn set_thumbnail_from_file(&self, mut file: File) -> anyhow::Result<()> {
+        let (thumbnails_enabled, max_bytes, content_type) = {
+            let config = &self.state().config;
+            (
+                config.thumbnails_enabled,
+                config.max_thumbnail_bytes,
+                config.thumbnail_content_type.clone(),
+            )
+        };
+        if !thumbnails_enabled {
+            return Ok(());
+        }
+
+        let mut thumbnail = thumbnail::from_file(&mut file, max_bytes)?;
+        if let Some(content_type) = content_type {
+            thumbnail = thumbnail::with_content_type(&thumbnail, &content_type)?;
+        }
+
+        self.show_thumbnail(&thumbnail)
+    }
+
+    /// The bytes of the thumbnail the overlay shows, after any transcoding
+    /// or resizing, for showing the same art in the app. `None` without a
+    /// thumbnail. Read from the control and kept until the thumbnail
+    /// changes, so repeated calls are cheap.
+    pub fn current_thumbnail_bytes(&self) -> anyhow::Result<Option<Vec<u8>>> {
+        let Some(reference) = self.backend.thumbnail()? else {
+            return Ok(None);
+        };
+
+        if let Some((cached, bytes)) = &self.state().shown_thumbnail_bytes {
+            if *cached == reference {
+                return Ok(Some(bytes.clone()));
+            }
+        }
+
+        let bytes = thumbnail::to_bytes(&reference)?;
+        self.state().shown_thumbnail_bytes = Some((reference, bytes.clone()));
+        Ok(Some(bytes))
+    }
+
+    pub fn clear_metadata(&self) -> anyhow::Result<()> {
+        self.apply(&[BackendCall::ClearDisplay, BackendCall::UpdateDisplay])
+    }
+
+    pub fn clear_app_media_id(&self) -> anyhow::Result<()> {
+        self.apply(&[
+            BackendCall::SetAppMediaId(String::new()),
+            BackendCall::UpdateDisplay,
+        ])
+    }
+
+    pub fn session_info(&self) -> anyhow::Result<SessionInfo> {
+        let app_media_id = self.backend.app_media_id()?;
+
+        Ok(SessionInfo {
+            app_media_id: (!app_media_id.is_empty()).then_some(app_media_id),
+            enabled: self.backend.is_enabled()?,
+        })
+    }
+
+    /// Windows has no per-session icon: the overlay always shows the icon of
+    /// the app identity that owns the `MediaPlayer` (the package, or the
+    /// executable for unpackaged apps), and neither the app media id nor the
+    /// display updater can change it. This always fails with
+    /// `SMTCError::Unsupported`; aggregators wanting a service logo can show
+    /// it as the thumbnail instead.
+    pub fn set_session_icon(&self, _source: &str) -> anyhow::Result<()> {
+        Err(SMTCError::Unsupported(
+            "the session icon is taken from the app identity and can't be set per session",
+        )
+        .into())
+    }
+
+    /// A readable report of the cached state and what the control currently
+    /// shows, for bug reports. Reads that fail are reported inline instead of
+    /// failing the whole dump.
+    pub fn debug_dump(&self) -> String {
+        fn line<T: std::fmt::Debug>(out: &mut String, name: &str, value: anyhow::Result<T>) {
+            let _ = match value {
+                Ok(value) => writeln!(out, "  {name}: {value:?}"),
+                Err(e) => writeln!(out, "  {name}: <failed to read: {e}>"),
+            };
+        }
+
+        let mut out = String::new();
+
+        {
+            let state = self.state();
+            let _ = writeln!(out, "[config]\n  {:?}", state.config);
+            let _ = writeln!(out, "[cached]");
+            let _ = writeln!(out, "  status: {:?}", state.status);
+            let _ = writeln!(
+                out,
+                "  pending_status_restore: {}",
+                state.pending_status_restore
+            );
+            let _ = writeln!(out, "  metadata: {:?}", state.metadata);
+            let _ = writeln!(out, "  app_id: {:?}", state.app_id);
+            let _ = writeln!(out, "  preview_active: {}", state.preview.is_some());
+            let _ = writeln!(out, "  timeline: {:?}", state.timeline);
+            let _ = writeln!(out, "  seeking_disabled: {}", state.seeking_disabled);
+            let _ = writeln!(
+                out,
+                "  elapsed_since_start_ms: {:?}",
+                state.playback_started_at.map(|started_at| self
+                    .clock
+                    .now()
+                    .saturating_sub(started_at)
+                    .as_millis())
+            );
+            let _ = writeln!(out, "  dispatcher: {:?}", state.dispatcher);
+            let _ = writeln!(out, "[handlers]\n  {:?}", state.handlers.counts());
+        }
+
+        let _ = writeln!(out, "[live]");
+        let backend = &self.backend;
+        line(&mut out, "enabled", backend.is_enabled());
+        line(&mut out, "playback_status", backend.playback_status());
+        line(&mut out, "shuffle", backend.shuffle());
+        line(&mut out, "repeat_mode", backend.repeat_mode());
+        for (name, button) in [
+            ("play_enabled", SmtcButton::Play),
+            ("pause_enabled", SmtcButton::Pause),
+            ("stop_enabled", SmtcButton::Stop),
+            ("next_enabled", SmtcButton::Next),
+            ("prev_enabled", SmtcButton::Previous),
+            ("fast_forward_enabled", SmtcButton::FastForward),
+            ("rewind_enabled", SmtcButton::Rewind),
+            ("record_enabled", SmtcButton::Record),
+        ] {
+            line(&mut out, name, backend.is_button_enabled(button));
+        }
+        line(&mut out, "app_media_id", backend.app_media_id());
+        line(&mut out, "title", backend.text(DisplayField::MusicTitle));
+        line(&mut out, "artist", backend.text(DisplayField::MusicArtist));
+
+        out
+    }
+
+    /// Whether the OS currently treats this app's session as the active one.
+    /// See `global_sessions::is_current_process_active` for the caveats.
+    pub fn is_active_session(&self) -> bool {
+        global_sessions::is_current_process_active()
+    }
+
+    pub fn update_timeline(&self, timeline: PlaybackTimeline) -> anyhow::Result<()> {
+        let Some(timeline) = self.defer(timeline, |pending, timeline| {
+            pending.record(PendingUpdate::Timeline(timeline))
+        }) else {
+            return Ok(());
+        };
+
+        let seeking_disabled = self.state().seeking_disabled;
+        self.apply(&[BackendCall::SetTimeline(Some(shown_timeline(
+            timeline,
+            seeking_disabled,
+        )))])?;
+
+        let restore = {
+            let mut state = self.state();
+            state.timeline = Some(timeline);
+            state.timeline_updated_at = Some(self.clock.now());
+            if std::mem::take(&mut state.pending_status_restore) {
+                state.status
+            } else {
+                None
+            }
+        };
+        if let Some(status) = restore {
+            self.apply(&[BackendCall::SetPlaybackStatus(status)])?;
+        }
+        self.notify_state_changed(StateChange {
+            timeline: Some(timeline),
+            ..StateChange::default()
+        });
+
+        Ok(())
+    }
+
+    /// Removes the timeline, so the overlay shows no seek bar, for content
+    /// without a meaningful position. `update_position` starts over from an
+    /// empty timeline afterwards.
+    pub fn clear_timeline(&self) -> anyhow::Result<()> {
+        self.apply(&[BackendCall::SetTimeline(None)])?;
+
+        let mut state = self.state();
+        state.timeline = None;
+        if let Some(pending) = &mut state.frozen {
+            pending.clear_timeline();
+        }
+        Ok(())
+    }
+
+    /// Sets the rate the content is playing at, 1.0 being normal speed.
+    /// Also attached to seek requests, see `PositionChangeRequest`.
+    pub fn set_playback_rate(&self, rate: f64) -> anyhow::Result<()> {
+        if !(rate.is_finite() && rate > 0.0) {
+            anyhow::bail!("playback rate must be a positive number, got {rate}");
+        }
+
+        self.apply(&[BackendCall::SetPlaybackRate(rate)])?;
+
+        self.state().playback_rate = Some(rate);
+        Ok(())
+    }
+
+    /// Turns the overlay's seek bar on or off for the current content, e.g.
+    /// off for ads or live streams. While off, every timeline is sent with
+    /// `MinSeekTime` and `MaxSeekTime` both set to the position; the bounds
+    /// passed to `update_timeline` are used again once turned back on.
+    pub fn set_seekable(&self, seekable: bool) -> anyhow::Result<()> {
+        let timeline = {
+            let mut state = self.state();
+            state.seeking_disabled = !seekable;
+            state.timeline
+        };
+
+        match timeline {
+            Some(timeline) => self.update_timeline(timeline),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets a timeline starting at 0 and ending at `duration_ms`, for the
+    /// common case where only the position and the track length are known.
+    pub fn update_timeline_simple(&self, position_ms: i64, duration_ms: i64) -> anyhow::Result<()> {
+        if duration_ms < 0 {
+            anyhow::bail!("duration must not be negative, got {duration_ms}ms");
+        }
+        if !(0..=duration_ms).contains(&position_ms) {
+            anyhow::bail!("position {position_ms}ms is outside of 0..={duration_ms}ms");
+        }
+
+        self.update_timeline(PlaybackTimeline {
+            start_time_ms: 0,
+            end_time_ms: duration_ms,
+            position_ms,
+            min_seek_time_ms: None,
+            max_seek_time_ms: None,
+        })
+    }
+
+    /// Shows only how far playback has got, for content with no known
+    /// length. The OS has no elapsed-only mode, so this sends a timeline
+    /// ending `ELAPSED_ONLY_END_MS` from the start with seeking pinned to the
+    /// position: the overlay shows the elapsed time next to a disabled, all
+    /// but empty seek bar, and the remaining time it shows is meaningless.
+    pub fn set_elapsed(&self, position_ms: i64) -> anyhow::Result<()> {
+        if position_ms < 0 {
+            anyhow::bail!("position must not be negative, got {position_ms}ms");
+        }
+
+        self.update_timeline(
+            PlaybackTimeline {
+                start_time_ms: 0,
+                end_time_ms: position_ms.max(ELAPSED_ONLY_END_MS),
+                position_ms,
+                min_seek_time_ms: None,
+                max_seek_time_ms: None,
+            }
+            .without_seeking(),
+        )
+    }
+
+    /// Moves the position of the last timeline passed to `update_timeline`,
+    /// keeping its bounds. Starts from an empty timeline if none was set.
+    pub fn update_position(&self, position_ms: i64) -> anyhow::Result<()> {
+        let timeline = self.current_timeline().unwrap_or(EMPTY_TIMELINE);
+
+        self.update_timeline(PlaybackTimeline {
+            position_ms,
+            ..timeline
+        })
+    }
+
+    /// Sets the length of the content, keeping the start and position of
+    /// the last timeline, so position-only updates after it keep the
+    /// duration.
+    pub fn set_duration(&self, duration_ms: i64) -> anyhow::Result<()> {
+        if duration_ms < 0 {
+            anyhow::bail!("duration must not be negative, got {duration_ms}ms");
+        }
+
+        let timeline = self.current_timeline().unwrap_or(EMPTY_TIMELINE);
+        let end_time_ms = timeline.start_time_ms + duration_ms;
+        self.update_timeline(PlaybackTimeline {
+            end_time_ms,
+            position_ms: timeline.position_ms.min(end_time_ms),
+            ..timeline
+        })
+    }
+
+    /// The last timeline set, including one held back by `freeze`.
+    pub fn current_timeline(&self) -> Option<PlaybackTimeline> {
+        let state = self.state();
+        let pending = state.frozen.as_ref().and_then(PendingUpdates::timeline);
+        pending.or(state.timeline)
+    }
+
+    /// Updates the position to `actual_ms` only if it is more than
+    /// `config.reconcile_threshold_ms` away from where the overlay should be
+    /// by now, e.g. after a seek, so small corrections don't make the bar
+    /// jitter. The expected position is the last one set, advanced by the
+    /// time since while `Playing`. Returns whether the position was updated.
+    pub fn reconcile_position(&self, actual_ms: i64) -> anyhow::Result<bool> {
+        let expected_ms = {
+            let state = self.state();
+            state.timeline.map(|timeline| {
+                let mut position_ms = timeline.position_ms;
+                if let (Some(PlaybackStatus::Playing), Some(updated_at)) =
+                    (state.status, state.timeline_updated_at)
+                {
+                    let elapsed = self.clock.now().saturating_sub(updated_at).as_millis() as f64;
+                    position_ms += (elapsed * state.playback_rate.unwrap_or(1.0)) as i64;
+                }
+                (
+                    position_ms.min(timeline.end_time_ms),
+                    state.config.reconcile_threshold_ms,
+                )
+            })
+        };
+
+        if let Some((expected_ms, threshold_ms)) = expected_ms {
+            if actual_ms.abs_diff(expected_ms) <= threshold_ms {
+                return Ok(false);
+            }
+        }
+
+        self.update_position(actual_ms)?;
+        Ok(true)
+    }
+
+    /// Records now as the moment playback started, as a reference for
+    /// `elapsed_since_start_ms`. Cleared when the status becomes `Stopped`.
+    pub fn mark_playback_start(&self) {
+        self.state().playback_started_at = Some(self.clock.now());
+    }
+
+    /// Milliseconds since the last `mark_playback_start`, measured with a
+    /// monotonic clock. `None` if playback wasn't marked as started.
+    pub fn elapsed_since_start_ms(&self) -> Option<i64> {
+        self.state()
+            .playback_started_at
+            .map(|started_at| self.clock.now().saturating_sub(started_at).as_millis() as i64)
+    }
+
+    pub fn update_playback_status(&self, status: PlaybackStatus) -> anyhow::Result<()> {
+        let Some(status) = self.defer(status, |pending, status| {
+            pending.record(PendingUpdate::Status(status))
+        }) else {
+            return Ok(());
+        };
+
+        self.apply(&[BackendCall::SetPlaybackStatus(status)])?;
+
+        let mut state = self.state();
+        state.status = Some(status);
+        state.pending_status_restore = false;
+        if let PlaybackStatus::Stopped = status {
+            state.playback_started_at = None;
+        }
+
+        state.auto_disable_generation += 1;
+        let auto_disable_after = state.config.auto_disable_after_stop_ms;
+        if status == PlaybackStatus::Stopped && auto_disable_after > 0 {
+            self.schedule_auto_disable(state.auto_disable_generation, auto_disable_after);
+        }
+
+        let mirror = match status {
+            _ if !state.config.mirror_playback_status => None,
+            PlaybackStatus::Playing => Some(true),
+            PlaybackStatus::Paused | PlaybackStatus::Stopped => Some(false),
+            PlaybackStatus::Closed | PlaybackStatus::Changing => None,
+        };
+        drop(state);
+
+        if let Some(playing) = mirror {
+            self.apply(&[BackendCall::SetPlayerPlaying(playing)])?;
+        }
+
+        self.notify_state_changed(StateChange {
+            status: Some(status),
+            ..StateChange::default()
+        });
+        Ok(())
+    }
+
+    fn downgrade(&self) -> WeakInternal {
+        WeakInternal {
+            backend: self.backend.clone(),
+            state: self.state.clone(),
+            clock: self.clock.clone(),
+            created_at: self.created_at,
+            lifetime: Arc::downgrade(&self._lifetime),
+        }
+    }
+
+    /// Sets where the position timer reads the position from. `provider` is
+    /// given the timer interval to answer in, so a slow answer never holds
+    /// up the next tick. Errors and panics from `provider` are logged and
+    /// skip that tick.
+    pub fn set_position_provider(
+        &self,
+        provider: impl Fn(Duration) -> anyhow::Result<i64> + Send + Sync + 'static,
+    ) {
+        self.state().position_provider = Some(PositionProvider(Arc::new(provider)));
+    }
+
+    pub fn clear_position_provider(&self) {
+        self.state().position_provider = None;
+    }
+
+    /// Updates the position every `interval_ms`, replacing any running
+    /// timer, until `stop_position_timer` or the control is dropped. The
+    /// position comes from `set_position_provider` when set; otherwise it is
+    /// extrapolated from the last timeline at the playback rate, while
+    /// `Playing`.
+    pub fn start_position_timer(&self, interval_ms: u64) -> anyhow::Result<()> {
+        if interval_ms == 0 {
+            anyhow::bail!("position timer interval must not be 0");
+        }
+
+        let generation = {
+            let mut state = self.state();
+            state.position_timer_generation += 1;
+            state.position_timer_generation
+        };
+
+        let weak = self.downgrade();
+        let interval = Duration::from_millis(interval_ms);
+        self.clock.run_every(
+            interval,
+            Box::new(move || {
+                let Some(internal) = weak.upgrade() else {
+                    return false;
+                };
+                if internal.state().position_timer_generation != generation {
+                    return false;
+                }
+                internal.tick_position(interval);
+                true
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn stop_position_timer(&self) {
+        self.state().position_timer_generation += 1;
+    }
+
+    fn tick_position(&self, interval: Duration) {
+        let (provider, status, playback_rate, timeline) = {
+            let state = self.state();
+            (
+                state.position_provider.clone(),
+                state.status,
+                state.playback_rate,
+                state.timeline,
+            )
+        };
+
+        let position_ms = match provider {
+            Some(provider) => {
+                match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    (provider.0)(interval)
+                })) {
+                    Ok(Ok(position_ms)) => position_ms,
+                    Ok(Err(e)) => {
+                        log::warn!("position provider failed: {e}");
+                        return;
+                    }
+                    Err(_) => {
+                        log::warn!("position provider panicked");
+                        return;
+                    }
+                }
+            }
+            None => {
+                let Some(timeline) = timeline else {
+                    return;
+                };
+                if status != Some(PlaybackStatus::Playing) {
+                    return;
+                }
+                let advanced = interval.as_millis() as f64 * playback_rate.unwrap_or(1.0);
+                (timeline.position_ms + advanced as i64).min(timeline.end_time_ms)
+            }
+        };
+
+        if let Err(e) = self.update_position(position_ms) {
+            log::warn!("position timer failed to update the position: {e}");
+        }
+    }
+
+    /// Disables the control after `delay_ms` unless a status or metadata
+    /// update bumps `auto_disable_generation` first.
+    fn schedule_auto_disable(&self, generation: u64, delay_ms: u64) {
+        let internal = self.clone();
+        let scheduled = self.clock.run_after(
+            Duration::from_millis(delay_ms),
+            Box::new(move || {
+                if internal.state().auto_disable_generation == generation {
+                    let _ = internal.disable_smtc();
+                }
+            }),
+        );
+        if let Err(e) = scheduled {
+            log::warn!("couldn't schedule auto_disable_after_stop_ms: {e}");
+        }
+    }
+
+    /// Shows the control as loading: the status becomes `Changing` and the
+    /// timeline is reset to an empty range, which makes the overlay hide the
+    /// seek bar instead of showing a misleading `0:00 / 0:00`.
+    ///
+    /// The next `update_timeline` call brings the seek bar back and restores
+    /// the last status set through `update_playback_status`.
+    pub fn set_buffering(&self) -> anyhow::Result<()> {
+        self.apply(&[
+            BackendCall::SetPlaybackStatus(PlaybackStatus::Changing),
+            BackendCall::SetTimeline(None),
+        ])?;
+
+        self.state().pending_status_restore = true;
+        Ok(())
+    }
+
+    pub fn update_shuffle(&self, shuffle: bool) -> anyhow::Result<()> {
+        let Some(shuffle) = self.defer(shuffle, |pending, shuffle| {
+            pending.record(PendingUpdate::Shuffle(shuffle))
+        }) else {
+            return Ok(());
+        };
+
+        self.apply(&[BackendCall::SetShuffle(shuffle)])?;
+        self.state().shuffle = Some(shuffle);
+
+        self.notify_state_changed(StateChange {
+            shuffle: Some(shuffle),
+            ..StateChange::default()
+        });
+        Ok(())
+    }
+
+    pub fn update_repeat_mode(&self, repeat_mode: String) -> anyhow::Result<()> {
+        let repeat_mode = RepeatMode::from_str_lossy(&repeat_mode);
+        self.check_repeat_mode(repeat_mode)?;
+
+        let Some(repeat_mode) = self.defer(repeat_mode, |pending, mode| {
+            pending.record(PendingUpdate::RepeatMode(mode))
+        }) else {
+            return Ok(());
+        };
+
+        self.apply(&[BackendCall::SetRepeatMode(repeat_mode)])?;
+        self.state().repeat_mode = Some(repeat_mode);
+
+        self.notify_state_changed(StateChange {
+            repeat_mode: Some(repeat_mode),
+            ..StateChange::default()
+        });
+        Ok(())
+    }
+
+    /// Sets shuffle and repeat together, for in-app toggles that change both.
+    pub fn update_shuffle_repeat(&self, shuffle: bool, repeat: RepeatMode) -> anyhow::Result<()> {
+        self.check_repeat_mode(repeat)?;
+
+        let Some((shuffle, repeat)) =
+            self.defer((shuffle, repeat), |pending, (shuffle, repeat)| {
+                pending.record(PendingUpdate::Shuffle(shuffle));
+                pending.record(PendingUpdate::RepeatMode(repeat));
+            })
+        else {
+            return Ok(());
+        };
+
+        self.apply(&[
+            BackendCall::SetShuffle(shuffle),
+            BackendCall::SetRepeatMode(repeat),
+        ])?;
+        {
+            let mut state = self.state();
+            state.shuffle = Some(shuffle);
+            state.repeat_mode = Some(repeat);
+        }
+
+        self.notify_state_changed(StateChange {
+            shuffle: Some(shuffle),
+            repeat_mode: Some(repeat),
+            ..StateChange::default()
+        });
+        Ok(())
+    }
+
+    fn check_repeat_mode(&self, repeat_mode: RepeatMode) -> anyhow::Result<()> {
+        if repeat_mode.clamp_to(&self.state().config.allowed_repeat_modes) != repeat_mode {
+            return Err(SMTCError::RepeatModeNotAllowed(repeat_mode).into());
+        }
+        Ok(())
+    }
+
+    pub fn enable_smtc(&self) -> anyhow::Result<()> {
+        self.apply(&[BackendCall::SetEnabled(true)])?;
+        let was_disabled = self.state().last_enabled == Some(false);
+        self.record_enabled(true, EnabledChangeSource::App);
+        self.show_default_metadata()?;
+
+        // Windows doesn't say when the overlay drops what it showed, but a
+        // re-enabled control is the one case where it does.
+        if was_disabled {
+            self.refresh()?;
+        }
+        Ok(())
+    }
+
+    /// Fires when the control may no longer show the last state, currently
+    /// after it is re-enabled. The value is whether `reapply_on_refresh`
+    /// already pushed the cached state again; if not, the app should.
+    pub fn refresh_event(&self, sink: StreamSink<bool>) {
+        self.state().refresh_listeners.add(move |reapplied| {
+            let _ = sink.add(reapplied);
+        });
+    }
+
+    /// Fires `refresh_event`, after reapplying the last state if
+    /// `reapply_on_refresh` is set, for apps that know the overlay dropped
+    /// what it showed, e.g. after Explorer restarted.
+    pub fn refresh(&self) -> anyhow::Result<()> {
+        let reapply = self.state().config.reapply_on_refresh;
+        if reapply {
+            self.reapply_state()?;
+        }
+
+        let (listeners, dispatcher) = {
+            let state = self.state();
+            (state.refresh_listeners.clone(), state.dispatcher.clone())
+        };
+        dispatcher.dispatch(move || listeners.notify(reapply));
+        Ok(())
+    }
+
+    /// Sends the last metadata, timeline, status, shuffle and repeat mode to
+    /// the control again, e.g. after the overlay lost them, all in one batch.
+    pub fn reapply_state(&self) -> anyhow::Result<()> {
+        let (metadata, app_id, timeline, seeking_disabled, status, shuffle, repeat_mode) = {
+            let state = self.state();
+            (
+                state.metadata.clone(),
+                state.app_id.clone(),
+                state.timeline,
+                state.seeking_disabled,
+                state.status,
+                state.shuffle,
+                state.repeat_mode,
+            )
+        };
+
+        let mut calls = metadata
+            .and_then(|metadata| {
+                let token = CancellationToken::new();
+                self.metadata_calls(&metadata, app_id, ClearFlags::all(), &token)
+            })
+            .unwrap_or_default();
+        calls.extend(timeline.map(|timeline| {
+            BackendCall::SetTimeline(Some(shown_timeline(timeline, seeking_disabled)))
+        }));
+        calls.extend(status.map(BackendCall::SetPlaybackStatus));
+        calls.extend(shuffle.map(BackendCall::SetShuffle));
+        calls.extend(repeat_mode.map(BackendCall::SetRepeatMode));
+        self.apply(&calls)
+    }
+
+    /// Applies `default_metadata` the first time the control is enabled, if
+    /// nothing else has been shown yet.
+    fn show_default_metadata(&self) -> anyhow::Result<()> {
+        let metadata = {
+            let mut state = self.state();
+            if std::mem::replace(&mut state.default_metadata_shown, true)
+                || state.metadata.is_some()
+                || state.placeholder
+            {
+                return Ok(());
+            }
+            state.config.default_metadata.clone()
+        };
+
+        match metadata {
+            Some(metadata) => self.apply_metadata(&metadata, None, ClearFlags::all()),
+            None => Ok(()),
+        }
+    }
+
+    /// Reads the live state. A value that differs from the one last set
+    /// through this instance is reported as a `System` change.
+    pub fn is_enabled(&self) -> anyhow::Result<bool> {
+        let enabled = self.backend.is_enabled()?;
+        // While frozen, the control doesn't have the app's changes yet.
+        if self.state().frozen.is_none() {
+            self.record_enabled(enabled, EnabledChangeSource::System);
+        }
+        Ok(enabled)
+    }
+
+    pub fn disable_smtc(&self) -> anyhow::Result<()> {
+        self.apply(&[BackendCall::SetEnabled(false)])?;
+        self.record_enabled(false, EnabledChangeSource::App);
+        Ok(())
+    }
+
+    /// Reports changes of the enabled state along with who made them.
+    ///
+    /// App changes are reported as they are made. `SystemMediaTransportControls`
+    /// has no notification for `IsEnabled`, so `System` changes are only
+    /// detected by polling: the event fires on the next `is_enabled` call
+    /// that reads a different value, and apps that need to notice them have
+    /// to call it periodically.
+    pub fn enabled_change_event(&self, sink: StreamSink<EnabledChange>) {
+        self.state().enabled_listeners.add(move |change| {
+            let _ = sink.add(change);
+        });
+    }
+
+    fn record_enabled(&self, enabled: bool, source: EnabledChangeSource) {
+        let (listeners, dispatcher) = {
+            let mut state = self.state();
+            let previous = state.last_enabled.replace(enabled);
+            if previous.is_none() || previous == Some(enabled) {
+                return;
+            }
+            (state.enabled_listeners.clone(), state.dispatcher.clone())
+        };
+
+        dispatcher.dispatch(move || listeners.notify(EnabledChange { enabled, source }));
+    }
+
+    /// Puts the control in an idle state: `Stopped`, with no metadata, art or
+    /// timeline, while keeping it enabled and registered with the OS.
+    ///
+    /// Unlike `disable_smtc`, the session stays around (so hardware media
+    /// keys keep reaching the app and resuming is a single update away); it
+    /// just shows nothing stale in the meantime.
+    pub fn idle(&self) -> anyhow::Result<()> {
+        self.update_playback_status(PlaybackStatus::Stopped)?;
+        self.clear_metadata()?;
+        self.clear_timeline()?;
+
+        let mut state = self.state();
+        // Idle is meant to keep the control enabled.
+        state.auto_disable_generation += 1;
+        Ok(())
+    }
+
+    pub fn button_press_event(&self, sink: StreamSink<String>) -> anyhow::Result<()> {
+        self.on_button_pressed(move |button| {
+            let _ = sink.add(button.as_str().to_string());
+        })?;
+        Ok(())
+    }
+
+    pub fn button_press_event_typed(&self, sink: StreamSink<ButtonPress>) -> anyhow::Result<()> {
+        self.on_button_pressed(move |button| {
+            let _ = sink.add(ButtonPress {
+                button,
+                source: ButtonSource::Unknown,
+            });
+        })?;
+        Ok(())
+    }
+
+    /// Channel button presses as `1` for up and `-1` for down, for apps that
+    /// map them to their own notion of next/previous station or volume.
+    /// Needs `channel_up_enabled`/`channel_down_enabled`.
+    pub fn channel_change_event(&self, sink: StreamSink<i32>) -> anyhow::Result<()> {
+        self.on_button_pressed(move |button| {
+            let step = match button {
+                SmtcButton::ChannelUp => 1,
+                SmtcButton::ChannelDown => -1,
+                _ => return,
+            };
+            let _ = sink.add(step);
+        })?;
+        Ok(())
+    }
+
+    /// With `record_as_toggle`, reports the new state each time a Record
+    /// press flips it.
+    pub fn record_toggle_event(&self, sink: StreamSink<bool>) -> anyhow::Result<()> {
+        let register = {
+            let mut state = self.state();
+            state.record_listeners.add(move |recording| {
+                let _ = sink.add(recording);
+            });
+            !std::mem::replace(&mut state.record_toggle_registered, true)
+        };
+        if !register {
+            return Ok(());
+        }
+
+        let state = self.state.clone();
+        let registered = self.on_button_pressed(move |button| {
+            if button != SmtcButton::Record {
+                return;
+            }
+            let (recording, listeners) = {
+                let mut state = lock_state(&state);
+                if !state.config.record_as_toggle {
+                    return;
+                }
+                state.recording = !state.recording;
+                (state.recording, state.record_listeners.clone())
+            };
+            listeners.notify(recording);
+        });
+        if registered.is_err() {
+            self.state().record_toggle_registered = false;
+        }
+        registered.map(drop)
+    }
+
+    /// Sets the state Record presses toggle, e.g. when recording stops on
+    /// its own. Doesn't report a toggle.
+    pub fn set_recording(&self, recording: bool) {
+        self.state().recording = recording;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.state().recording
+    }
+
+    pub fn position_change_request_event(&self, sink: StreamSink<i64>) -> anyhow::Result<()> {
+        self.on_position_change_request(move |request| {
+            let _ = sink.add(request.position_ms);
+        })?;
+        Ok(())
+    }
+
+    /// Seek request positions as 8 little-endian bytes, see
+    /// `PositionChangeRequest::packed`, for apps handling many of them.
+    pub fn position_change_request_event_packed(
+        &self,
+        sink: StreamSink<Vec<u8>>,
+    ) -> anyhow::Result<()> {
+        self.on_position_change_request(move |request| {
+            let _ = sink.add(request.packed().to_vec());
+        })?;
+        Ok(())
+    }
+
+    pub fn position_change_request_event_typed(
+        &self,
+        sink: StreamSink<PositionChangeRequest>,
+    ) -> anyhow::Result<()> {
+        self.on_position_change_request(move |request| {
+            let _ = sink.add(request);
+        })?;
+        Ok(())
+    }
+
+    pub fn shuffle_request_event(&self, sink: StreamSink<bool>) -> anyhow::Result<()> {
+        self.on_shuffle_request(move |shuffle| {
+            let _ = sink.add(shuffle);
+        })?;
+        Ok(())
+    }
+
+    pub fn repeat_mode_request_event(&self, sink: StreamSink<String>) -> anyhow::Result<()> {
+        self.on_repeat_mode_request(move |repeat_mode| {
+            let _ = sink.add(repeat_mode);
+        })?;
+        Ok(())
+    }
+
+    /// Forwards every control request through a single sink. Each event is
+    /// stamped with the milliseconds elapsed since this instance was created,
+    /// captured when the WinRT handler fires. Only the requests selected by
+    /// `config.events` at registration are forwarded.
+    ///
+    /// The first event is always a `Snapshot` of the current state, so a
+    /// subscriber attached after a hot restart can render right away.
+    pub fn unified_event_stream(&self, sink: StreamSink<SmtcEvent>) -> anyhow::Result<()> {
+        self.unified_events(move |event| {
+            let _ = sink.add(event);
+        })
+    }
+
+    /// `unified_event_stream` with any receiver in place of a Dart sink.
+    fn unified_events(
+        &self,
+        emit: impl Fn(SmtcEvent) + Send + Sync + 'static,
+    ) -> anyhow::Result<()> {
+        emit(self.snapshot_event());
+
+        let events = self.state().config.events;
+        let timestamp_ms = self.event_timestamp();
+        let coalesce = self.coalescer();
+        let forward = Arc::new(move |kind: SmtcEventKind| {
+            let timestamp_ms = timestamp_ms();
+            if coalesce(&kind, timestamp_ms) {
+                emit(SmtcEvent { kind, timestamp_ms });
+            }
+        });
+
+        if events.contains(EventSelection::BUTTON) {
+            let f = forward.clone();
+            self.on_button_pressed(move |button| f(SmtcEventKind::Button(button)))?;
+        }
+        if events.contains(EventSelection::POSITION) {
+            let f = forward.clone();
+            self.on_position_change_request(move |request| {
+                f(SmtcEventKind::PositionChangeRequest(request))
+            })?;
+        }
+        if events.contains(EventSelection::SHUFFLE) {
+            let f = forward.clone();
+            self.on_shuffle_request(move |shuffle| f(SmtcEventKind::ShuffleRequest(shuffle)))?;
+        }
+        if events.contains(EventSelection::REPEAT) {
+            self.on_repeat_mode_request(move |repeat_mode| {
+                forward(SmtcEventKind::RepeatModeRequest(repeat_mode))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Like `unified_event_stream`, but owns its handlers: once the Dart side
+    /// cancels the stream, the next event detaches its handlers instead
+    /// of leaving them registered with nowhere to send. Starts with a
+    /// `Snapshot` as well.
+    pub fn register_all_events(&self, sink: StreamSink<SmtcEvent>) -> anyhow::Result<()> {
+        let _ = sink.add(self.snapshot_event());
+
+        let backend = Arc::downgrade(&self.backend);
+        let state = self.state.clone();
+        let tokens: Arc<Mutex<Vec<(HandlerKind, i64)>>> = Default::default();
+        let timestamp_ms = self.event_timestamp();
+        let coalesce = self.coalescer();
+
+        let forward = {
+            let tokens = tokens.clone();
+            Arc::new(move |kind: SmtcEventKind| {
+                let timestamp_ms = timestamp_ms();
+                if !coalesce(&kind, timestamp_ms) {
+                    return;
+                }
+                if sink.add(SmtcEvent { kind, timestamp_ms }).is_ok() {
+                    return;
+                }
+
+                let tokens =
+                    std::mem::take(&mut *tokens.lock().unwrap_or_else(PoisonError::into_inner));
+                let Some(backend) = backend.upgrade() else {
+                    return;
+                };
+                for (kind, token) in tokens {
+                    let _ = backend.remove_handler(kind, token);
+                    lock_state(&state).handlers.remove(kind, token);
+                }
+            })
+        };
+
+        let events = self.state().config.events;
+        let mut registered = Vec::with_capacity(4);
+
+        if events.contains(EventSelection::BUTTON) {
+            let f = forward.clone();
+            registered.push((
+                HandlerKind::ButtonPressed,
+                self.on_button_pressed(move |button| f(SmtcEventKind::Button(button)))?,
+            ));
+        }
+        if events.contains(EventSelection::POSITION) {
+            let f = forward.clone();
+            registered.push((
+                HandlerKind::PositionChangeRequest,
+                self.on_position_change_request(move |request| {
+                    f(SmtcEventKind::PositionChangeRequest(request))
+                })?,
+            ));
+        }
+        if events.contains(EventSelection::SHUFFLE) {
+            let f = forward.clone();
+            registered.push((
+                HandlerKind::ShuffleRequest,
+                self.on_shuffle_request(move |shuffle| f(SmtcEventKind::ShuffleRequest(shuffle)))?,
+            ));
+        }
+        if events.contains(EventSelection::REPEAT) {
+            registered.push((
+                HandlerKind::RepeatModeRequest,
+                self.on_repeat_mode_request(move |repeat_mode| {
+                    forward(SmtcEventKind::RepeatModeRequest(repeat_mode))
+                })?,
+            ));
+        }
+
+        *tokens.lock().unwrap_or_else(PoisonError::into_inner) = registered;
+        Ok(())
+    }
+
+    /// Sends a `Snapshot` of the current state to `sink`, for a stream that
+    /// was attached without one.
+    pub fn replay_last_state(&self, sink: StreamSink<SmtcEvent>) {
+        let _ = sink.add(self.snapshot_event());
+    }
+
+    fn snapshot_event(&self) -> SmtcEvent {
+        let snapshot = {
+            let state = self.state();
+            StateSnapshot {
+                metadata: state.metadata.clone(),
+                status: state.status,
+                timeline: state.timeline,
+            }
+        };
+        SmtcEvent {
+            kind: SmtcEventKind::Snapshot(snapshot),
+            timestamp_ms: self.event_timestamp()(),
+        }
+    }
+
+    /// Milliseconds elapsed on the clock since this instance was created.
+    fn event_timestamp(&self) -> impl Fn() -> u64 + Send + Sync + 'static {
+        let clock = self.clock.clone();
+        let created_at = self.created_at;
+        move || clock.now().saturating_sub(created_at).as_millis() as u64
+    }
+
+    /// Milliseconds since this instance was created at which `button` was
+    /// last pressed, including presses dropped by `button_debounce_ms`.
+    pub fn last_button_press_ms(&self, button: SmtcButton) -> Option<u64> {
+        self.state()
+            .last_button_presses
+            .get(&button)
+            .map(|pressed_at| pressed_at.saturating_sub(self.created_at).as_millis() as u64)
+    }
+
+    /// With `trace_raw_events`, sends a line for every WinRT callback the
+    /// event handlers receive, before any filtering, e.g.
+    /// `ButtonPressed button=6`. Enum values are the raw WinRT numbers. Each
+    /// registered handler reports its own invocation.
+    pub fn raw_event_trace(&self, sink: StreamSink<String>) {
+        self.state().raw_trace_listeners.add(move |line| {
+            let _ = sink.add(line);
+        });
+    }
+
+    /// Forwards `MediaPlayer::MediaFailed`, which only fires once the player
+    /// has a source, as `"<error> (<code>): <message>"`.
+    pub fn media_error_event(&self, sink: StreamSink<String>) -> anyhow::Result<()> {
+        let state = self.state.clone();
+        let callback = Arc::new(move |error: String| {
+            let _ = sink.add(error);
+        });
+        let token = self.backend.on_media_failed(Box::new(move |failure| {
+            let Some(failure) = failure else {
+                trace(&state, || "MediaFailed without args".to_string());
+                return;
+            };
+            trace(&state, || {
+                format!(
+                    "MediaFailed error={} code={:#010x}",
+                    failure.error.map(|error| error.0).unwrap_or(-1),
+                    failure.code
+                )
+            });
+
+            let error = match failure.error {
+                Some(MediaPlayerError::Aborted) => "aborted",
+                Some(MediaPlayerError::NetworkError) => "network_error",
+                Some(MediaPlayerError::DecodingError) => "decoding_error",
+                Some(MediaPlayerError::SourceNotSupported) => "source_not_supported",
+                _ => "unknown",
+            };
+            let code = failure.code;
+            let message = failure.message;
+
+            emit(
+                &state,
+                &callback,
+                format!("{error} ({code:#010x}): {message}"),
+            );
+        }))?;
+        self.state()
+            .handlers
+            .register(HandlerKind::MediaFailed, token);
+
+        Ok(())
+    }
+
+    /// Returns whether an event should be forwarded under the configured
+    /// `event_coalesce_ms`. Each combined stream gets its own.
+    fn coalescer(&self) -> impl Fn(&SmtcEventKind, u64) -> bool + Send + Sync + 'static {
+        let state = self.state.clone();
+        let coalescer = Mutex::new(EventCoalescer::default());
+        move |kind, timestamp_ms| {
+            let window_ms = lock_state(&state).config.event_coalesce_ms;
+            coalescer
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .accept(kind, timestamp_ms, window_ms)
+        }
+    }
+
+    /// Number of handlers currently registered through the event methods.
+    pub fn handler_count(&self) -> usize {
+        self.state().handlers.count()
+    }
+
+    pub fn handler_counts(&self) -> HandlerCounts {
+        self.state().handlers.counts()
+    }
+
+    /// Detaches every handler registered through the event methods. Their
+    /// streams stop receiving events.
+    pub fn remove_handlers(&self) -> anyhow::Result<()> {
+        remove_handlers(&*self.backend, &self.state)?;
+
+        let mut state = self.state();
+        state.record_listeners = Listeners::default();
+        state.record_toggle_registered = false;
+        Ok(())
+    }
+
+    /// Calls `callback` with each button press, for callers that can't
+    /// consume a `StreamSink`. It runs on the configured dispatcher, one
+    /// press at a time, until the returned subscription is dropped.
+    pub fn on_button(
+        &self,
+        callback: impl FnMut(SmtcButton) + Send + 'static,
+    ) -> anyhow::Result<EventSubscription> {
+        let token = self.on_button_pressed(serialized(callback))?;
+        Ok(self.subscription(HandlerKind::ButtonPressed, token))
+    }
+
+    /// Like `on_button`, for seek requests.
+    pub fn on_position_change(
+        &self,
+        callback: impl FnMut(PositionChangeRequest) + Send + 'static,
+    ) -> anyhow::Result<EventSubscription> {
+        let token = self.on_position_change_request(serialized(callback))?;
+        Ok(self.subscription(HandlerKind::PositionChangeRequest, token))
+    }
+
+    /// Like `on_button`, for shuffle requests.
+    pub fn on_shuffle_change(
+        &self,
+        callback: impl FnMut(bool) + Send + 'static,
+    ) -> anyhow::Result<EventSubscription> {
+        let token = self.on_shuffle_request(serialized(callback))?;
+        Ok(self.subscription(HandlerKind::ShuffleRequest, token))
+    }
+
+    /// Like `on_button`, for repeat mode requests.
+    pub fn on_repeat_mode_change(
+        &self,
+        callback: impl FnMut(String) + Send + 'static,
+    ) -> anyhow::Result<EventSubscription> {
+        let token = self.on_repeat_mode_request(serialized(callback))?;
+        Ok(self.subscription(HandlerKind::RepeatModeRequest, token))
+    }
+
+    fn subscription(&self, kind: HandlerKind, token: i64) -> EventSubscription {
+        EventSubscription {
+            backend: Arc::downgrade(&self.backend),
+            state: Arc::downgrade(&self.state),
+            handler: Mutex::new(Some((kind, token))),
+        }
+    }
+
+    fn on_button_pressed(
+        &self,
+        callback: impl Fn(SmtcButton) + Send + Sync + 'static,
+    ) -> anyhow::Result<i64> {
+        let state = self.state.clone();
+        let callback = Arc::new(callback);
+        let clock = self.clock.clone();
+        // Per handler, so one stream suppressing a repeat doesn't hide the
+        // press from the others.
+        let debouncer = Mutex::new(ButtonDebouncer::default());
+        let token = self.backend.on_button_pressed(Box::new(move |button| {
+            let Some(button) = button else {
+                trace(&state, || "ButtonPressed without args".to_string());
+                return;
+            };
+            trace(&state, || format!("ButtonPressed button={}", button.0));
+
+            if let Some(button) = SmtcButton::from_winrt(button) {
+                let now = clock.now();
+                let (button, window) = {
+                    let mut state = lock_state(&state);
+                    let button = button.aliased(state.config.record_as_favorite);
+                    state.last_button_presses.insert(button, now);
+                    if state.config.ignore_disabled_buttons
+                        && !state.config.is_button_enabled(button)
+                    {
+                        return;
+                    }
+                    (
+                        button,
+                        Duration::from_millis(state.config.button_debounce_ms),
+                    )
+                };
+
+                let accepted = debouncer
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .accept(button, now, window);
+                if accepted {
+                    emit(&state, &callback, button);
+                }
+            }
+        }))?;
+        self.state()
+            .handlers
+            .register(HandlerKind::ButtonPressed, token);
+
+        Ok(token)
+    }
+
+    fn on_position_change_request(
+        &self,
+        callback: impl Fn(PositionChangeRequest) + Send + Sync + 'static,
+    ) -> anyhow::Result<i64> {
+        let state = self.state.clone();
+        let callback = Arc::new(callback);
+        let backend = Arc::downgrade(&self.backend);
+        let token = self
+            .backend
+            .on_position_change_requested(Box::new(move |ticks| {
+                let Some(ticks) = ticks else {
+                    trace(&state, || {
+                        "PlaybackPositionChangeRequested without args".to_string()
+                    });
+                    return;
+                };
+                trace(&state, || {
+                    format!("PlaybackPositionChangeRequested ticks={ticks}")
+                });
+                let request = {
+                    let state = lock_state(&state);
+                    let mut request = PositionChangeRequest::from_ticks(ticks, state.playback_rate);
+                    request.out_of_range = state.timeline.is_some_and(|timeline| {
+                        let timeline = if state.seeking_disabled {
+                            timeline.without_seeking()
+                        } else {
+                            timeline
+                        };
+                        !timeline.can_seek_to(request.position_ms)
+                    });
+                    request
+                };
+
+                if let (Some(backend), false) = (backend.upgrade(), request.out_of_range) {
+                    // A failed optimistic update only costs the preview, the
+                    // request itself is still forwarded.
+                    let _ = apply_optimistic_seek(&*backend, &state, request);
+                }
+
+                emit(&state, &callback, request);
+            }))?;
+        self.state()
+            .handlers
+            .register(HandlerKind::PositionChangeRequest, token);
+
+        Ok(token)
+    }
+
+    fn on_shuffle_request(
+        &self,
+        callback: impl Fn(bool) + Send + Sync + 'static,
+    ) -> anyhow::Result<i64> {
+        let state = self.state.clone();
+        let callback = Arc::new(callback);
+        let token = self.backend.on_shuffle_requested(Box::new(move |shuffle| {
+            let Some(shuffle) = shuffle else {
+                trace(&state, || {
+                    "ShuffleEnabledChangeRequested without args".to_string()
+                });
+                return;
+            };
+            trace(&state, || {
+                format!("ShuffleEnabledChangeRequested shuffle={shuffle}")
+            });
+
+            emit(&state, &callback, shuffle);
+        }))?;
+        self.state()
+            .handlers
+            .register(HandlerKind::ShuffleRequest, token);
+
+        Ok(token)
+    }
+
+    fn on_repeat_mode_request(
+        &self,
+        callback: impl Fn(String) + Send + Sync + 'static,
+    ) -> anyhow::Result<i64> {
+        let state = self.state.clone();
+        let callback = Arc::new(callback);
+        let token = self
+            .backend
+            .on_repeat_mode_requested(Box::new(move |repeat_mode| {
+                let Some(repeat_mode) = repeat_mode else {
+                    trace(&state, || {
+                        "AutoRepeatModeChangeRequested without args".to_string()
+                    });
+                    return;
+                };
+                trace(&state, || {
+                    format!("AutoRepeatModeChangeRequested mode={}", repeat_mode.0)
+                });
+                let repeat_mode = RepeatMode::from(repeat_mode)
+                    .clamp_to(&lock_state(&state).config.allowed_repeat_modes);
+
+                emit(&state, &callback, repeat_mode.as_str().to_string());
+            }))?;
+        self.state()
+            .handlers
+            .register(HandlerKind::RepeatModeRequest, token);
+
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use windows::Media::SystemMediaTransportControlsButton;
+
+    use super::*;
+    use crate::internal::{backend::MockBackend, clock::ManualClock};
+
+    fn control(config: SMTCConfig) -> (SMTCInternal, Arc<MockBackend>) {
+        let backend = Arc::new(MockBackend::new());
+        let internal =
+            SMTCInternal::with_backend(config, None, Arc::new(ManualClock::new()), backend.clone())
+                .unwrap();
+        (internal, backend)
+    }
+
+    /// Like `control`, keeping the clock to drive timers with.
+    fn timed_control(config: SMTCConfig) -> (SMTCInternal, Arc<MockBackend>, Arc<ManualClock>) {
+        let backend = Arc::new(MockBackend::new());
+        let clock = Arc::new(ManualClock::new());
+        let internal =
+            SMTCInternal::with_backend(config, None, clock.clone(), backend.clone()).unwrap();
+        (internal, backend, clock)
+    }
+
+    fn shown_title(backend: &MockBackend) -> Option<String> {
+        backend
+            .control()
+            .text
+            .get(&DisplayField::MusicTitle)
+            .cloned()
+    }
+
+    fn metadata(title: &str) -> MusicMetadata {
+        MusicMetadata {
+            title: Some(title.to_string()),
+            artist: Some("Artist".to_string()),
+            album: Some("Album".to_string()),
+            album_artist: None,
+            thumbnail: Vec::new(),
+            genres: vec!["Jazz".to_string()],
+            track_number: Some(3),
+            media_type: MediaType::Music,
+            title_locale: None,
+            artist_locale: None,
+        }
+    }
+
+    fn timeline(position_ms: i64) -> PlaybackTimeline {
+        PlaybackTimeline {
+            start_time_ms: 0,
+            end_time_ms: 100_000,
+            position_ms,
+            min_seek_time_ms: None,
+            max_seek_time_ms: None,
+        }
+    }
+
+    #[test]
+    fn creation_applies_buttons_and_enables() {
+        let config = SMTCConfig::default();
+        let (_internal, backend) = control(config.clone());
+
+        let shown = backend.control();
+        assert!(shown.enabled);
+        for button in [SmtcButton::Play, SmtcButton::Next, SmtcButton::Rewind] {
+            assert_eq!(
+                shown.buttons.get(&button),
+                Some(&config.is_button_enabled(button))
+            );
+        }
+        assert_eq!(backend.calls().last(), Some(&BackendCall::SetEnabled(true)));
+    }
+
+    #[test]
+    fn update_metadata_applies_in_one_display_update() {
+        let (internal, backend) = control(SMTCConfig::default());
+        backend.take_calls();
+
+        internal.update_metadata(metadata("Song"), None).unwrap();
+
+        let calls = backend.take_calls();
+        assert_eq!(calls.first(), Some(&BackendCall::ClearDisplay));
+        assert_eq!(calls.last(), Some(&BackendCall::UpdateDisplay));
+        assert_eq!(
+            calls
+                .iter()
+                .filter(|call| **call == BackendCall::UpdateDisplay)
+                .count(),
+            1
+        );
+
+        let shown = backend.control();
+        assert_eq!(shown.media_type, MediaType::Music);
+        assert_eq!(shown.text[&DisplayField::MusicTitle], "Song");
+        assert_eq!(shown.text[&DisplayField::MusicArtist], "Artist");
+        assert_eq!(shown.genres, ["Jazz"]);
+        assert_eq!(shown.track_number, 3);
+    }
+
+    #[test]
+    fn update_metadata_verified_reads_back_what_was_set() {
+        let (internal, _backend) = control(SMTCConfig::default());
+        let report = internal
+            .update_metadata_verified(metadata("Song"), Some("app".to_string()))
+            .unwrap();
+        assert!(report.is_empty(), "{report:?}");
+    }
+
+    #[test]
+    fn clear_metadata_fields_blanks_every_media_type() {
+        let (internal, backend) = control(SMTCConfig::default());
+        internal.update_metadata(metadata("Song"), None).unwrap();
+
+        internal
+            .clear_metadata_fields(vec![MetadataField::Title])
+            .unwrap();
+
+        let shown = backend.control();
+        for field in [
+            DisplayField::MusicTitle,
+            DisplayField::VideoTitle,
+            DisplayField::ImageTitle,
+        ] {
+            assert_eq!(shown.text[&field], "");
+        }
+        assert_eq!(shown.text[&DisplayField::MusicArtist], "Artist");
+    }
+
+    #[test]
+    fn placeholder_disables_buttons_until_metadata() {
+        let (internal, backend) = control(SMTCConfig::default());
+
+        internal
+            .set_placeholder("Loading".to_string(), None, None)
+            .unwrap();
+        let shown = backend.control();
+        assert!(shown.buttons.values().all(|enabled| !enabled));
+        assert_eq!(shown.text[&DisplayField::MusicTitle], "Loading");
+        assert_eq!(shown.status, PlaybackStatus::Stopped);
+
+        internal.update_metadata(metadata("Song"), None).unwrap();
+        assert_eq!(
+            backend.control().buttons.get(&SmtcButton::Play),
+            Some(&true)
+        );
+    }
+
+    #[test]
+    fn mirror_playback_status_drives_the_player() {
+        let (internal, backend) = control(SMTCConfig {
+            mirror_playback_status: true,
+            ..SMTCConfig::default()
+        });
+
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+        assert!(backend.control().playing);
+        internal
+            .update_playback_status(PlaybackStatus::Paused)
+            .unwrap();
+        assert!(!backend.control().playing);
+    }
+
+    #[test]
+    fn button_presses_reach_subscribers_until_dropped() {
+        let (internal, backend) = control(SMTCConfig::default());
+        let pressed = Arc::new(Mutex::new(Vec::new()));
+
+        let subscription = {
+            let pressed = pressed.clone();
+            internal
+                .on_button(move |button| pressed.lock().unwrap().push(button))
+                .unwrap()
+        };
+        backend.press(SystemMediaTransportControlsButton::Next);
+        backend.raise_without_args(HandlerKind::ButtonPressed);
+        assert_eq!(*pressed.lock().unwrap(), [SmtcButton::Next]);
+
+        drop(subscription);
+        assert_eq!(backend.handler_count(), 0);
+        backend.press(SystemMediaTransportControlsButton::Next);
+        assert_eq!(pressed.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn presses_from_many_threads_arrive_on_the_event_thread() {
+        const CALLERS: usize = 8;
+        const PRESSES: usize = 100;
+        let (internal, backend) = control(SMTCConfig::default());
+        internal.set_event_thread(true).unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        let _subscription = internal
+            .on_button(move |button| {
+                sender.send((button, std::thread::current().id())).unwrap();
+            })
+            .unwrap();
+        let callers: Vec<_> = (0..CALLERS)
+            .map(|_| {
+                let backend = backend.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..PRESSES {
+                        backend.press(SystemMediaTransportControlsButton::Next);
+                    }
+                    std::thread::current().id()
+                })
+            })
+            .collect();
+        let callers: Vec<_> = callers.into_iter().map(|c| c.join().unwrap()).collect();
+
+        let events: Vec<_> = (0..CALLERS * PRESSES)
+            .map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        let event_thread = events[0].1;
+        assert!(!callers.contains(&event_thread));
+        assert!(events
+            .iter()
+            .all(|&(button, on)| button == SmtcButton::Next && on == event_thread));
+    }
+
+    #[test]
+    fn position_requests_are_reported_in_ms() {
+        let (internal, backend) = control(SMTCConfig::default());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let _subscription = {
+            let requests = requests.clone();
+            internal
+                .on_position_change(move |request| requests.lock().unwrap().push(request))
+                .unwrap()
+        };
+        backend.request_position(42_000);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].position_ms, 42_000);
+    }
+
+    #[test]
+    fn optimistic_seek_shows_changing_until_the_next_timeline() {
+        let (internal, backend) = control(SMTCConfig {
+            optimistic_seek: true,
+            ..SMTCConfig::default()
+        });
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+        internal.update_timeline(timeline(0)).unwrap();
+        let _subscription = internal.on_position_change(|_| {}).unwrap();
+
+        backend.request_position(50_000);
+        let shown = backend.control();
+        assert_eq!(shown.status, PlaybackStatus::Changing);
+        assert_eq!(shown.timeline.map(|t| t.position_ms), Some(50_000));
+
+        internal.update_timeline(timeline(51_000)).unwrap();
+        assert_eq!(backend.control().status, PlaybackStatus::Playing);
+    }
+
+    #[test]
+    fn dry_run_leaves_the_control_alone() {
+        let (internal, backend) = control(SMTCConfig {
+            dry_run: true,
+            ..SMTCConfig::default()
+        });
+
+        internal
+            .set_placeholder("Loading".to_string(), None, None)
+            .unwrap();
+        internal.update_metadata(metadata("Song"), None).unwrap();
+        internal.push_title_override("Ad".to_string()).unwrap();
+        internal
+            .clear_metadata_fields(vec![MetadataField::Album])
+            .unwrap();
+        internal.update_timeline(timeline(0)).unwrap();
+        internal.set_buffering().unwrap();
+        internal.clear_timeline().unwrap();
+        internal.set_playback_rate(2.0).unwrap();
+        internal.update_shuffle(true).unwrap();
+        internal.disable_smtc().unwrap();
+        internal.enable_smtc().unwrap();
+        internal.reapply_state().unwrap();
+
+        assert!(backend.calls().is_empty(), "{:?}", backend.calls());
+        assert_eq!(
+            internal.snapshot_event().kind,
+            SmtcEventKind::Snapshot(StateSnapshot {
+                metadata: Some(MusicMetadata {
+                    album: None,
+                    ..metadata("Song")
+                }),
+                status: Some(PlaybackStatus::Stopped),
+                timeline: None,
+            })
+        );
+    }
+
+    #[test]
+    fn turning_dry_run_off_applies_the_buttons() {
+        let (internal, backend) = control(SMTCConfig {
+            dry_run: true,
+            ..SMTCConfig::default()
+        });
+        internal.update_config(SMTCConfig::default()).unwrap();
+        assert_eq!(
+            backend.control().buttons.get(&SmtcButton::Play),
+            Some(&true)
+        );
+    }
+
+    #[test]
+    fn freeze_holds_back_every_change() {
+        let (internal, backend) = control(SMTCConfig::default());
+        backend.take_calls();
+
+        internal.freeze();
+        internal
+            .set_placeholder("Loading".to_string(), None, None)
+            .unwrap();
+        internal.push_title_override("Ad".to_string()).unwrap();
+        internal.set_playback_rate(2.0).unwrap();
+        internal.disable_smtc().unwrap();
+        assert!(backend.calls().is_empty(), "{:?}", backend.calls());
+        assert!(internal.is_enabled().unwrap());
+
+        internal.thaw().unwrap();
+        let shown = backend.control();
+        assert!(shown.buttons.values().all(|enabled| !enabled));
+        assert_eq!(shown.text[&DisplayField::MusicTitle], "Ad");
+        assert_eq!(shown.status, PlaybackStatus::Stopped);
+        assert_eq!(shown.playback_rate, 2.0);
+        assert!(!shown.enabled);
+    }
+
+    #[test]
+    fn thaw_keeps_the_order_of_changes() {
+        let (internal, backend) = control(SMTCConfig::default());
+
+        internal.freeze();
+        internal.update_metadata(metadata("First"), None).unwrap();
+        internal
+            .set_placeholder("Loading".to_string(), None, None)
+            .unwrap();
+        internal.update_metadata(metadata("Second"), None).unwrap();
+        internal.thaw().unwrap();
+
+        let shown = backend.control();
+        assert_eq!(shown.text[&DisplayField::MusicTitle], "Second");
+        assert_eq!(shown.buttons.get(&SmtcButton::Play), Some(&true));
+        assert!(!backend.calls().contains(&BackendCall::SetText(
+            DisplayField::MusicTitle,
+            "First".to_string()
+        )));
+    }
+
+    #[test]
+    fn clear_timeline_drops_a_held_back_timeline() {
+        let (internal, backend) = control(SMTCConfig::default());
+
+        internal.freeze();
+        internal.update_timeline(timeline(1_000)).unwrap();
+        internal.clear_timeline().unwrap();
+        assert_eq!(internal.current_timeline(), None);
+        internal.thaw().unwrap();
+
+        assert_eq!(backend.control().timeline, None);
+    }
+
+    #[test]
+    fn dropping_the_last_clone_detaches_handlers() {
+        let (internal, backend) = control(SMTCConfig::default());
+        internal.on_button_pressed(|_| {}).unwrap();
+        internal.on_shuffle_request(|_| {}).unwrap();
+        assert_eq!(backend.handler_count(), 2);
+
+        drop(internal);
+        assert_eq!(backend.handler_count(), 0);
+    }
+
+    #[test]
+    fn auto_disable_fires_once_the_delay_has_passed() {
+        let (internal, backend, clock) = timed_control(SMTCConfig {
+            auto_disable_after_stop_ms: 1_000,
+            ..SMTCConfig::default()
+        });
+        internal
+            .update_playback_status(PlaybackStatus::Stopped)
+            .unwrap();
+
+        clock.advance(Duration::from_millis(999));
+        assert!(backend.control().enabled);
+        clock.advance(Duration::from_millis(1));
+        assert!(!backend.control().enabled);
+    }
+
+    #[test]
+    fn auto_disable_is_cancelled_by_a_new_status() {
+        let (internal, backend, clock) = timed_control(SMTCConfig {
+            auto_disable_after_stop_ms: 1_000,
+            ..SMTCConfig::default()
+        });
+        internal
+            .update_playback_status(PlaybackStatus::Stopped)
+            .unwrap();
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+
+        clock.advance(Duration::from_millis(5_000));
+        assert!(backend.control().enabled);
+    }
+
+    #[test]
+    fn preview_reverts_after_its_delay() {
+        let (internal, backend, clock) = timed_control(SMTCConfig::default());
+        internal.update_metadata(metadata("Current"), None).unwrap();
+        internal.preview_metadata(metadata("Next"), 500).unwrap();
+        assert_eq!(shown_title(&backend).as_deref(), Some("Next"));
+
+        clock.advance(Duration::from_millis(499));
+        assert_eq!(shown_title(&backend).as_deref(), Some("Next"));
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(shown_title(&backend).as_deref(), Some("Current"));
+    }
+
+    #[test]
+    fn confirmed_previews_stay() {
+        let (internal, backend, clock) = timed_control(SMTCConfig::default());
+        internal.update_metadata(metadata("Current"), None).unwrap();
+        internal.preview_metadata(metadata("Next"), 500).unwrap();
+        assert!(internal.confirm_preview());
+
+        clock.advance(Duration::from_millis(2_500));
+        assert_eq!(shown_title(&backend).as_deref(), Some("Next"));
+        assert!(!internal.confirm_preview());
+    }
+
+    #[test]
+    fn thumbnail_resolvers_get_the_budget_to_answer() {
+        for (budget, expected) in [(250, Duration::from_millis(250)), (0, RESOLVER_TIMEOUT)] {
+            let (internal, _backend) = control(SMTCConfig {
+                thumbnail_budget_ms: budget,
+                ..SMTCConfig::default()
+            });
+            let (sender, timeouts) = mpsc::channel();
+            internal.set_thumbnail_resolver(move |_, timeout| {
+                let _ = sender.send(timeout);
+                anyhow::bail!("no art")
+            });
+
+            internal
+                .update_metadata(
+                    MusicMetadata {
+                        thumbnail: vec!["https://example.com/art.png".to_string()],
+                        ..metadata("Song")
+                    },
+                    None,
+                )
+                .unwrap();
+            let timeout = timeouts.recv_timeout(Duration::from_secs(5)).unwrap();
+            assert_eq!(timeout, expected);
+        }
+    }
+
+    #[test]
+    fn position_providers_get_the_interval_to_answer() {
+        let (internal, _backend, clock) = timed_control(SMTCConfig::default());
+        let timeouts = Arc::new(Mutex::new(Vec::new()));
+        {
+            let timeouts = timeouts.clone();
+            internal.set_position_provider(move |timeout| {
+                timeouts.lock().unwrap().push(timeout);
+                Ok(1_000)
+            });
+        }
+        internal.start_position_timer(500).unwrap();
+
+        clock.advance(Duration::from_millis(1_000));
+        let interval = Duration::from_millis(500);
+        assert_eq!(*timeouts.lock().unwrap(), [interval, interval]);
+    }
+
+    #[test]
+    fn refresh_reapplies_what_the_overlay_dropped_in_one_batch() {
+        let (internal, backend) = control(SMTCConfig {
+            reapply_on_refresh: true,
+            ..SMTCConfig::default()
+        });
+        internal.update_metadata(metadata("Song"), None).unwrap();
+        internal.update_timeline(timeline(1_000)).unwrap();
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+        internal.update_shuffle(true).unwrap();
+
+        backend.clear_by_system();
+        let batches = backend.batches();
+        internal.refresh().unwrap();
+
+        assert_eq!(backend.batches(), batches + 1);
+        let shown = backend.control();
+        assert_eq!(shown_title(&backend).as_deref(), Some("Song"));
+        assert_eq!(shown.timeline.map(|t| t.position_ms), Some(1_000));
+        assert_eq!(shown.status, PlaybackStatus::Playing);
+        assert!(shown.shuffle);
+    }
+
+    #[test]
+    fn switch_session_full_applies_the_new_session_in_one_batch() {
+        let (internal, backend) = control(SMTCConfig {
+            next_enabled: false,
+            ..SMTCConfig::default()
+        });
+        internal.update_metadata(metadata("Old"), None).unwrap();
+        internal.update_timeline(timeline(60_000)).unwrap();
+        internal
+            .update_playback_status(PlaybackStatus::Paused)
+            .unwrap();
+
+        let batches = backend.batches();
+        internal
+            .switch_session_full(SmtcInit {
+                metadata: Some(metadata("New")),
+                timeline: Some(timeline(0)),
+                status: Some(PlaybackStatus::Playing),
+                ..SmtcInit::default()
+            })
+            .unwrap();
+
+        // One batch, so a readback sees the old session or the new one,
+        // never a mix of both.
+        assert_eq!(backend.batches(), batches + 1);
+        let shown = backend.control();
+        assert_eq!(shown_title(&backend).as_deref(), Some("New"));
+        assert_eq!(shown.timeline.map(|t| t.position_ms), Some(0));
+        assert_eq!(shown.status, PlaybackStatus::Playing);
+        // Without a config the current one stays.
+        assert_eq!(shown.buttons.get(&SmtcButton::Next), Some(&false));
+    }
+
+    #[test]
+    fn silent_metadata_updates_notify_no_one() {
+        let (internal, backend) = control(SMTCConfig::default());
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        {
+            let changes = changes.clone();
+            internal
+                .state()
+                .state_listeners
+                .add(move |change| changes.lock().unwrap().push(change));
+        }
+
+        internal
+            .update_metadata_silently(metadata("Quiet"), None)
+            .unwrap();
+        assert_eq!(shown_title(&backend).as_deref(), Some("Quiet"));
+        assert!(changes.lock().unwrap().is_empty());
+
+        internal.update_metadata(metadata("Loud"), None).unwrap();
+        assert_eq!(changes.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn thumbnail_content_type_applies_to_resolved_art() {
+        let (internal, backend) = control(SMTCConfig {
+            thumbnail_content_type: Some("image/jpeg".to_string()),
+            ..SMTCConfig::default()
+        });
+        internal.set_thumbnail_resolver(|_, _| Ok(b"octet-stream art".to_vec()));
+
+        internal
+            .update_metadata(
+                MusicMetadata {
+                    thumbnail: vec!["https://cdn.example.com/art".to_string()],
+                    ..metadata("Song")
+                },
+                None,
+            )
+            .unwrap();
+
+        let shown = backend.thumbnail().unwrap().unwrap();
+        let stream = shown.OpenReadAsync().unwrap().get().unwrap();
+        assert_eq!(stream.ContentType().unwrap(), "image/jpeg");
+    }
+
+    // These go through `shut_down_all` rather than `shutdown`, which would
+    // also shut down the instances of tests running in parallel.
+    #[test]
+    fn a_second_shutdown_does_nothing() {
+        let (internal, backend) = control(SMTCConfig::default());
+        let instances = vec![Arc::downgrade(&internal._lifetime)];
+
+        shut_down_all(instances.clone());
+        assert!(backend.is_closed());
+        assert!(!backend.control().enabled);
+        let batches = backend.batches();
+
+        shut_down_all(instances);
+        assert_eq!(backend.batches(), batches);
+    }
+
+    #[test]
+    fn calls_after_shutdown_fail_with_shut_down() {
+        let (internal, backend) = control(SMTCConfig::default());
+        shut_down_all(vec![Arc::downgrade(&internal._lifetime)]);
+        let batches = backend.batches();
+
+        let e = internal
+            .update_metadata(metadata("Song"), None)
+            .unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<SMTCError>(),
+            Some(SMTCError::ShutDown)
+        ));
+        let e = internal.update_timeline(timeline(1_000)).unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<SMTCError>(),
+            Some(SMTCError::ShutDown)
+        ));
+        assert_eq!(backend.batches(), batches);
+    }
+
+    #[test]
+    fn partial_clears_keep_the_groups_they_leave_out() {
+        let (internal, backend) = control(SMTCConfig::default());
+        internal
+            .update_metadata_with(
+                metadata("First"),
+                Some("app".to_string()),
+                ClearFlags::all(),
+            )
+            .unwrap();
+
+        internal
+            .update_metadata_with(
+                MusicMetadata {
+                    album: None,
+                    ..metadata("Second")
+                },
+                None,
+                ClearFlags::THUMBNAIL,
+            )
+            .unwrap();
+        let control = backend.control();
+        assert_eq!(shown_title(&backend).as_deref(), Some("Second"));
+        assert_eq!(control.text[&DisplayField::MusicAlbum], "Album");
+        assert_eq!(control.app_media_id, "app");
+
+        internal
+            .update_metadata_with(
+                MusicMetadata {
+                    album: None,
+                    ..metadata("Third")
+                },
+                None,
+                ClearFlags::MUSIC_PROPERTIES | ClearFlags::APP_MEDIA_ID,
+            )
+            .unwrap();
+        let control = backend.control();
+        assert_eq!(shown_title(&backend).as_deref(), Some("Third"));
+        assert_eq!(control.text[&DisplayField::MusicAlbum], "");
+        assert_eq!(control.app_media_id, "");
+    }
+
+    #[test]
+    fn unified_events_leave_out_unselected_kinds() {
+        let (internal, backend) = control(SMTCConfig {
+            events: EventSelection::BUTTON | EventSelection::REPEAT,
+            ..SMTCConfig::default()
+        });
+        let kinds = Arc::new(Mutex::new(Vec::new()));
+        {
+            let kinds = kinds.clone();
+            internal
+                .unified_events(move |event| kinds.lock().unwrap().push(event.kind))
+                .unwrap();
+        }
+        assert_eq!(backend.handler_count(), 2);
+
+        backend.request_shuffle(true);
+        backend.request_position(1_000);
+        backend.press(SystemMediaTransportControlsButton::Next);
+
+        let kinds = kinds.lock().unwrap();
+        assert_eq!(kinds.len(), 2, "{kinds:?}");
+        assert!(matches!(kinds[0], SmtcEventKind::Snapshot(_)));
+        assert_eq!(kinds[1], SmtcEventKind::Button(SmtcButton::Next));
+    }
+
+    #[test]
+    fn thumbnail_sources_are_tried_in_order_until_one_loads() {
+        let (internal, backend) = control(SMTCConfig::default());
+        let tried = Arc::new(Mutex::new(Vec::new()));
+        {
+            let tried = tried.clone();
+            internal.set_thumbnail_resolver(move |source, _| {
+                tried.lock().unwrap().push(source.to_string());
+                match source {
+                    "https://c.example.com/art.png" => Ok(b"art".to_vec()),
+                    _ => anyhow::bail!("{source} is down"),
+                }
+            });
+        }
+
+        internal
+            .update_metadata(
+                MusicMetadata {
+                    thumbnail: vec![
+                        "https://a.example.com/art.png".to_string(),
+                        "https://b.example.com/art.png".to_string(),
+                        "https://c.example.com/art.png".to_string(),
+                        "https://d.example.com/art.png".to_string(),
+                    ],
+                    ..metadata("Song")
+                },
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            *tried.lock().unwrap(),
+            [
+                "https://a.example.com/art.png",
+                "https://b.example.com/art.png",
+                "https://c.example.com/art.png",
+            ]
+        );
+        let shown = backend.thumbnail().unwrap().unwrap();
+        assert_eq!(thumbnail::to_bytes(&shown).unwrap(), b"art");
+    }
+
+    #[test]
+    fn disabled_thumbnails_still_update_the_text() {
+        let (internal, backend) = control(SMTCConfig {
+            thumbnails_enabled: false,
+            ..SMTCConfig::default()
+        });
+        let resolved = Arc::new(Mutex::new(0));
+        {
+            let resolved = resolved.clone();
+            internal.set_thumbnail_resolver(move |_, _| {
+                *resolved.lock().unwrap() += 1;
+                Ok(b"art".to_vec())
+            });
+        }
+
+        internal
+            .update_metadata(
+                MusicMetadata {
+                    thumbnail: vec!["https://example.com/art.png".to_string()],
+                    ..metadata("Song")
+                },
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(shown_title(&backend).as_deref(), Some("Song"));
+        assert!(backend.control().thumbnail.is_none());
+        assert_eq!(*resolved.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn buffering_lasts_until_the_next_timeline() {
+        let (internal, backend) = control(SMTCConfig::default());
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+        internal.update_timeline(timeline(10_000)).unwrap();
+
+        internal.set_buffering().unwrap();
+        let shown = backend.control();
+        assert_eq!(shown.status, PlaybackStatus::Changing);
+        assert_eq!(shown.timeline, None);
+
+        internal.update_timeline(timeline(12_000)).unwrap();
+        let shown = backend.control();
+        assert_eq!(shown.status, PlaybackStatus::Playing);
+        assert_eq!(shown.timeline.map(|t| t.position_ms), Some(12_000));
+    }
+
+    #[test]
+    fn clear_app_media_id_resets_the_session_info() {
+        let (internal, _backend) = control(SMTCConfig::default());
+        internal
+            .update_metadata(metadata("Song"), Some("com.example.player".to_string()))
+            .unwrap();
+        assert_eq!(
+            internal.session_info().unwrap().app_media_id.as_deref(),
+            Some("com.example.player")
+        );
+
+        internal.clear_app_media_id().unwrap();
+        assert_eq!(internal.session_info().unwrap().app_media_id, None);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn event_runtime_runs_callbacks_on_the_runtime() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let (internal, backend) = control(SMTCConfig::default());
+        internal.set_event_runtime(Some(runtime.handle().clone()));
+        let (sender, receiver) = mpsc::channel();
+        let _subscription = internal
+            .on_button(move |button| {
+                sender.send((button, std::thread::current().id())).unwrap();
+            })
+            .unwrap();
+
+        backend.press(SystemMediaTransportControlsButton::Next);
+        // Nothing drives the runtime yet, so the press is only queued.
+        assert!(receiver.try_recv().is_err());
+
+        let delivered = runtime.block_on(async {
+            for _ in 0..1_000 {
+                if let Ok(delivered) = receiver.try_recv() {
+                    return delivered;
+                }
+                tokio::task::yield_now().await;
+            }
+            panic!("the press never reached the runtime");
+        });
+        assert_eq!(delivered, (SmtcButton::Next, std::thread::current().id()));
+    }
+
+    #[test]
+    fn elapsed_since_start_follows_the_clock() {
+        let (internal, backend, clock) = timed_control(SMTCConfig::default());
+        assert_eq!(internal.elapsed_since_start_ms(), None);
+
+        internal.mark_playback_start();
+        clock.advance(Duration::from_millis(1_500));
+        let elapsed = internal.elapsed_since_start_ms();
+        assert_eq!(elapsed, Some(1_500));
+
+        internal.set_elapsed(elapsed.unwrap()).unwrap();
+        assert_eq!(
+            backend.control().timeline.map(|t| t.position_ms),
+            Some(1_500)
+        );
+
+        internal
+            .update_playback_status(PlaybackStatus::Stopped)
+            .unwrap();
+        assert_eq!(internal.elapsed_since_start_ms(), None);
+    }
+
+    #[test]
+    fn prefetched_thumbnails_are_reused_until_the_cache_is_cleared() {
+        const ART: &str = "https://example.com/art.png";
+        let (internal, _backend) = control(SMTCConfig::default());
+        let loads = Arc::new(Mutex::new(0));
+        {
+            let loads = loads.clone();
+            internal.set_thumbnail_resolver(move |_, _| {
+                *loads.lock().unwrap() += 1;
+                Ok(b"art".to_vec())
+            });
+        }
+        let with_art = || MusicMetadata {
+            thumbnail: vec![ART.to_string()],
+            ..metadata("Song")
+        };
+
+        assert!(internal.prefetch_thumbnail(ART).unwrap());
+        internal.update_metadata(with_art(), None).unwrap();
+        assert_eq!(*loads.lock().unwrap(), 1);
+
+        // Art could load differently under the new limit.
+        internal
+            .update_config(SMTCConfig {
+                max_thumbnail_bytes: 1024,
+                ..SMTCConfig::default()
+            })
+            .unwrap();
+        internal.update_metadata(with_art(), None).unwrap();
+        assert_eq!(*loads.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn current_thumbnail_bytes_reads_back_the_shown_art() {
+        let (internal, _backend) = control(SMTCConfig::default());
+        internal.set_thumbnail_resolver(|source, _| Ok(source.as_bytes().to_vec()));
+        assert_eq!(internal.current_thumbnail_bytes().unwrap(), None);
+
+        for art in ["https://example.com/a.png", "https://example.com/b.png"] {
+            internal
+                .update_metadata(
+                    MusicMetadata {
+                        thumbnail: vec![art.to_string()],
+                        ..metadata("Song")
+                    },
+                    None,
+                )
+                .unwrap();
+            // Twice, the second read coming from the kept copy.
+            for _ in 0..2 {
+                assert_eq!(
+                    internal.current_thumbnail_bytes().unwrap().as_deref(),
+                    Some(art.as_bytes())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reconcile_position_only_corrects_past_the_threshold() {
+        let (internal, backend, clock) = timed_control(SMTCConfig {
+            reconcile_threshold_ms: 250,
+            ..SMTCConfig::default()
+        });
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+        internal.update_timeline(timeline(10_000)).unwrap();
+        clock.advance(Duration::from_millis(1_000));
+        let shown = || backend.control().timeline.map(|t| t.position_ms);
+
+        // Expected at 11_000 by now.
+        assert!(!internal.reconcile_position(11_250).unwrap());
+        assert!(!internal.reconcile_position(10_750).unwrap());
+        assert_eq!(shown(), Some(10_000));
+
+        assert!(internal.reconcile_position(11_251).unwrap());
+        assert_eq!(shown(), Some(11_251));
+    }
+
+    #[test]
+    fn button_debounce_drops_repeats_within_the_window() {
+        let (internal, backend, clock) = timed_control(SMTCConfig {
+            button_debounce_ms: 300,
+            ..SMTCConfig::default()
+        });
+        let pressed = Arc::new(Mutex::new(Vec::new()));
+        let _subscription = {
+            let pressed = pressed.clone();
+            internal
+                .on_button(move |button| pressed.lock().unwrap().push(button))
+                .unwrap()
+        };
+
+        backend.press(SystemMediaTransportControlsButton::Next);
+        clock.advance(Duration::from_millis(100));
+        backend.press(SystemMediaTransportControlsButton::Next);
+        // Other buttons have their own window.
+        backend.press(SystemMediaTransportControlsButton::Play);
+        clock.advance(Duration::from_millis(200));
+        backend.press(SystemMediaTransportControlsButton::Next);
+
+        assert_eq!(
+            *pressed.lock().unwrap(),
+            [SmtcButton::Next, SmtcButton::Play, SmtcButton::Next]
+        );
+    }
+
+    #[test]
+    fn ignore_disabled_buttons_drops_their_presses() {
+        for (ignore, expected) in [
+            (true, vec![SmtcButton::Play]),
+            (false, vec![SmtcButton::Next, SmtcButton::Play]),
+        ] {
+            let (internal, backend) = control(SMTCConfig {
+                next_enabled: false,
+                ignore_disabled_buttons: ignore,
+                ..SMTCConfig::default()
+            });
+            let pressed = Arc::new(Mutex::new(Vec::new()));
+            let _subscription = {
+                let pressed = pressed.clone();
+                internal
+                    .on_button(move |button| pressed.lock().unwrap().push(button))
+                    .unwrap()
+            };
+
+            backend.press(SystemMediaTransportControlsButton::Next);
+            backend.press(SystemMediaTransportControlsButton::Play);
+
+            assert_eq!(*pressed.lock().unwrap(), expected, "ignore: {ignore}");
+        }
+    }
+
+    #[test]
+    fn repeat_requests_are_clamped_to_the_allowed_modes() {
+        use windows::Media::MediaPlaybackAutoRepeatMode;
+
+        let (internal, backend) = control(SMTCConfig {
+            allowed_repeat_modes: vec![RepeatMode::None, RepeatMode::Track],
+            ..SMTCConfig::default()
+        });
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let _subscription = {
+            let requested = requested.clone();
+            internal
+                .on_repeat_mode_change(move |mode| requested.lock().unwrap().push(mode))
+                .unwrap()
+        };
+
+        backend.request_repeat_mode(MediaPlaybackAutoRepeatMode::List);
+        backend.request_repeat_mode(MediaPlaybackAutoRepeatMode::Track);
+        backend.request_repeat_mode(MediaPlaybackAutoRepeatMode::None);
+
+        assert_eq!(*requested.lock().unwrap(), ["track", "track", "none"]);
+    }
+
+    #[test]
+    fn newer_thumbnails_supersede_slower_async_ones() {
+        let (internal, backend) = control(SMTCConfig::default());
+        let (started, slow_started) = mpsc::channel();
+        let (release, released) = mpsc::channel::<()>();
+        let released = Mutex::new(released);
+        internal.set_thumbnail_resolver(move |source, _| {
+            if source == "https://example.com/slow.png" {
+                started.send(()).unwrap();
+                released.lock().unwrap().recv().unwrap();
+            }
+            Ok(source.as_bytes().to_vec())
+        });
+
+        let slow = internal.set_thumbnail_async("https://example.com/slow.png".to_string());
+        slow_started.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(internal
+            .set_thumbnail("https://example.com/fast.png")
+            .unwrap());
+        release.send(()).unwrap();
+
+        assert!(!task::block_on(slow).unwrap());
+        let shown = backend.thumbnail().unwrap().unwrap();
+        assert_eq!(
+            thumbnail::to_bytes(&shown).unwrap(),
+            b"https://example.com/fast.png"
+        );
+    }
+
+    #[test]
+    fn art_past_the_budget_goes_out_without_its_thumbnail_first() {
+        let (internal, backend) = control(SMTCConfig {
+            thumbnail_budget_ms: 50,
+            ..SMTCConfig::default()
+        });
+        let (release, released) = mpsc::channel::<()>();
+        let released = Mutex::new(released);
+        internal.set_thumbnail_resolver(move |_, _| {
+            released.lock().unwrap().recv().unwrap();
+            Ok(b"art".to_vec())
+        });
+
+        internal
+            .update_metadata(
+                MusicMetadata {
+                    thumbnail: vec!["https://example.com/slow.png".to_string()],
+                    ..metadata("Song")
+                },
+                None,
+            )
+            .unwrap();
+        assert_eq!(shown_title(&backend).as_deref(), Some("Song"));
+        assert!(backend.control().thumbnail.is_none());
+
+        release.send(()).unwrap();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while backend.control().thumbnail.is_none() {
+            assert!(std::time::Instant::now() < deadline, "the art never came");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn update_metadata_leaves_out_art_over_the_size_limit() {
+        let (internal, backend) = control(SMTCConfig {
+            max_thumbnail_bytes: 1024,
+            ..SMTCConfig::default()
+        });
+        internal.set_thumbnail_resolver(|source, _| {
+            let size = if source.ends_with("big.png") {
+                1025
+            } else {
+                1024
+            };
+            Ok(vec![0; size])
+        });
+        let with_art = |art: &str| MusicMetadata {
+            thumbnail: vec![art.to_string()],
+            ..metadata("Song")
+        };
+
+        internal
+            .update_metadata(with_art("https://example.com/big.png"), None)
+            .unwrap();
+        assert_eq!(shown_title(&backend).as_deref(), Some("Song"));
+        assert!(backend.control().thumbnail.is_none());
+
+        internal
+            .update_metadata(with_art("https://example.com/small.png"), None)
+            .unwrap();
+        assert!(backend.control().thumbnail.is_some());
+    }
+
+    #[test]
+    fn debug_dump_reports_cached_and_live_state() {
+        let (internal, _backend) = control(SMTCConfig::default());
+        internal
+            .update_metadata(metadata("Song"), Some("app".to_string()))
+            .unwrap();
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+
+        let dump = internal.debug_dump();
+
+        let sections: Vec<_> = dump.lines().filter(|line| line.starts_with('[')).collect();
+        assert_eq!(sections, ["[config]", "[cached]", "[handlers]", "[live]"]);
+        for expected in [
+            "  status: Some(Playing)",
+            "  app_id: Some(\"app\")",
+            "  enabled: true",
+            "  app_media_id: \"app\"",
+            "  title: \"Song\"",
+        ] {
+            assert!(
+                dump.lines().any(|line| line == expected),
+                "{expected}\n{dump}"
+            );
+        }
+    }
+
+    #[test]
+    fn idle_shows_nothing_but_stays_enabled() {
+        let (internal, backend, clock) = timed_control(SMTCConfig {
+            auto_disable_after_stop_ms: 1_000,
+            ..SMTCConfig::default()
+        });
+        internal.update_metadata(metadata("Song"), None).unwrap();
+        internal.update_timeline(timeline(10_000)).unwrap();
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+
+        internal.idle().unwrap();
+        clock.advance(Duration::from_millis(2_000));
+
+        let shown = backend.control();
+        assert!(shown.enabled);
+        assert_eq!(shown.status, PlaybackStatus::Stopped);
+        assert_eq!(shown.timeline, None);
+        assert_eq!(shown_title(&backend), None);
+    }
+
+    #[test]
+    fn raw_event_traces_come_before_any_filtering() {
+        for trace in [true, false] {
+            let (internal, backend) = control(SMTCConfig {
+                trace_raw_events: trace,
+                next_enabled: false,
+                ignore_disabled_buttons: true,
+                ..SMTCConfig::default()
+            });
+            let lines = Arc::new(Mutex::new(Vec::new()));
+            {
+                let lines = lines.clone();
+                internal
+                    .state()
+                    .raw_trace_listeners
+                    .add(move |line| lines.lock().unwrap().push(line));
+            }
+            let _subscription = internal.on_button(|_| {}).unwrap();
+
+            backend.press(SystemMediaTransportControlsButton::Next);
+            backend.raise_without_args(HandlerKind::ButtonPressed);
+
+            let expected: &[&str] = if trace {
+                &["ButtonPressed button=6", "ButtonPressed without args"]
+            } else {
+                &[]
+            };
+            assert_eq!(*lines.lock().unwrap(), expected, "trace: {trace}");
+        }
+    }
+
+    #[test]
+    fn unified_events_start_with_a_snapshot_of_the_current_state() {
+        let (internal, _backend) = control(SMTCConfig::default());
+        internal.update_metadata(metadata("Song"), None).unwrap();
+        internal
+            .update_playback_status(PlaybackStatus::Playing)
+            .unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        {
+            let events = events.clone();
+            internal
+                .unified_events(move |event| events.lock().unwrap().push(event.kind))
+                .unwrap();
+        }
+
+        let events = events.lock().unwrap();
+        let [SmtcEventKind::Snapshot(snapshot)] = events.as_slice() else {
+            panic!("expected only a snapshot: {events:?}");
+        };
+        let title = snapshot.metadata.as_ref().and_then(|m| m.title.as_deref());
+        assert_eq!(title, Some("Song"));
+        assert_eq!(snapshot.status, Some(PlaybackStatus::Playing));
+        assert_eq!(snapshot.timeline, None);
+    }
+
+    #[test]
+    fn set_elapsed_sends_a_long_timeline_without_seeking() {
+        let (internal, backend) = control(SMTCConfig::default());
 
-        let media_player = &self.media_player;
-        let smtc = media_player.SystemMediaTransportControls()?;
+        internal.set_elapsed(90_000).unwrap();
+        let shown = backend.control().timeline.unwrap();
+        assert_eq!(shown.start_time_ms, 0);
+        assert_eq!(shown.end_time_ms, ELAPSED_ONLY_END_MS);
+        assert_eq!(shown.position_ms, 90_000);
+        assert_eq!(shown.min_seek_time_ms, Some(90_000));
+        assert_eq!(shown.max_seek_time_ms, Some(90_000));
 
-        smtc.AutoRepeatModeChangeRequested(&handler)?;
+        let past_the_end = ELAPSED_ONLY_END_MS + 1;
+        internal.set_elapsed(past_the_end).unwrap();
+        let shown = backend.control().timeline.unwrap();
+        assert_eq!(shown.end_time_ms, past_the_end);
 
-        anyhow::Result::Ok(())
+        assert!(internal.set_elapsed(-1).is_err());
+        assert_eq!(backend.control().timeline, Some(shown));
     }
 }
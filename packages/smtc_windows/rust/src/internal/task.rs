@@ -0,0 +1,298 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::Thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+struct Slot<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// Resolves with the result of a closure run by `spawn_blocking` or a
+/// `WorkerPool`.
+#[derive(Debug)]
+pub struct Completion<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+}
+
+impl<T> Completion<T> {
+    /// A pending completion, plus the function that resolves it.
+    fn new() -> (Self, impl FnOnce(T)) {
+        let slot = Arc::new(Mutex::new(Slot {
+            value: None,
+            waker: None,
+        }));
+
+        let completion = Completion { slot: slot.clone() };
+        let complete = move |value| {
+            let mut slot = slot.lock().unwrap_or_else(PoisonError::into_inner);
+            slot.value = Some(value);
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        };
+        (completion, complete)
+    }
+}
+
+impl<T> Future for Completion<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut slot = self.slot.lock().unwrap_or_else(PoisonError::into_inner);
+        match slot.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Runs `f` on its own thread, so the blocking WinRT `.get()` calls it makes
+/// don't hold up the caller's executor.
+pub fn spawn_blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> Completion<T> {
+    let (completion, complete) = Completion::new();
+    std::thread::spawn(move || complete(f()));
+    completion
+}
+
+/// Shared flag for abandoning work that is already running. Clones observe
+/// the same flag; once cancelled a token stays cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls `future` to completion on the current thread, parking it while
+/// pending. Only for threads the crate owns; never call it from an executor.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+/// Like `block_on`, but gives up and returns `None` once `timeout` has
+/// passed without `future` completing.
+pub fn block_on_timeout<F: Future>(future: F, timeout: Duration) -> Option<F::Output> {
+    let deadline = Instant::now() + timeout;
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Some(output);
+        }
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return None;
+        }
+        std::thread::park_timeout(left);
+    }
+}
+
+/// Wraps an async `callback` so each call starts its future and returns at
+/// once. The futures run to completion one at a time, in call order, on a
+/// thread of their own, so a slow or re-entrant callback never blocks the
+/// thread the event arrived on.
+pub fn fire_in_order<T: 'static, F>(
+    callback: impl Fn(T) -> F + Send + Sync + 'static,
+) -> impl Fn(T) + Send + Sync + 'static
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let pool = WorkerPool::new("smtc-callback", 1);
+    move |value| {
+        let future = callback(value);
+        let _ = pool.submit(move || block_on(future));
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed number of threads working through a shared queue, so bursts of
+/// work don't turn into bursts of threads. The threads exit once the pool is
+/// dropped and the queue is drained.
+pub struct WorkerPool {
+    size: usize,
+    jobs: Mutex<Sender<Job>>,
+}
+
+impl WorkerPool {
+    /// Starts `size` threads (at least one) named `<name>-<index>`.
+    pub fn new(name: &str, size: usize) -> Self {
+        let size = size.max(1);
+        let (jobs, queue) = mpsc::channel::<Job>();
+        let queue = Arc::new(Mutex::new(queue));
+
+        for index in 0..size {
+            let queue = queue.clone();
+            let _ = std::thread::Builder::new()
+                .name(format!("{name}-{index}"))
+                .spawn(move || work(&queue));
+        }
+
+        Self {
+            size,
+            jobs: Mutex::new(jobs),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn submit<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Completion<T> {
+        let (completion, complete) = Completion::new();
+        let job: Job = Box::new(move || complete(f()));
+
+        let jobs = self.jobs.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Err(mpsc::SendError(job)) = jobs.send(job) {
+            // Every worker failed to start; run the job here instead of
+            // leaving the completion pending forever.
+            job();
+        }
+        completion
+    }
+}
+
+fn work(queue: &Mutex<Receiver<Job>>) {
+    loop {
+        let job = queue.lock().unwrap_or_else(PoisonError::into_inner).recv();
+        match job {
+            Ok(job) => job(),
+            Err(_) => return,
+        }
+    }
+}
+
+impl std::fmt::Debug for WorkerPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorkerPool")
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawn_blocking_resolves_with_the_result() {
+        let caller = std::thread::current().id();
+        let ran_on = block_on(spawn_blocking(|| std::thread::current().id()));
+        assert_ne!(ran_on, caller);
+    }
+
+    #[test]
+    fn worker_pool_runs_every_job_on_its_threads() {
+        let pool = WorkerPool::new("smtc-test", 2);
+        let completions: Vec<_> = (0..20)
+            .map(|job| {
+                pool.submit(move || (job, std::thread::current().name().map(str::to_string)))
+            })
+            .collect();
+
+        let mut threads = std::collections::HashSet::new();
+        for (expected, completion) in completions.into_iter().enumerate() {
+            let (job, thread) = block_on(completion);
+            assert_eq!(job, expected);
+            threads.insert(thread.unwrap());
+        }
+        assert!(threads.len() <= 2, "{threads:?}");
+        assert!(threads.iter().all(|name| name.starts_with("smtc-test-")));
+    }
+
+    #[test]
+    fn worker_pool_has_at_least_one_thread() {
+        let pool = WorkerPool::new("smtc-test", 0);
+        assert_eq!(pool.size(), 1);
+        assert_eq!(block_on(pool.submit(|| 7)), 7);
+    }
+
+    #[test]
+    fn block_on_timeout_gives_up_on_a_pending_future() {
+        assert_eq!(
+            block_on_timeout(std::future::pending::<()>(), Duration::from_millis(20)),
+            None
+        );
+        assert_eq!(
+            block_on_timeout(spawn_blocking(|| 7), Duration::from_secs(5)),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn fired_callbacks_return_before_their_futures_finish() {
+        let (release, released) = mpsc::channel::<()>();
+        let released = Arc::new(Mutex::new(released));
+        let (done, finished) = mpsc::channel();
+        let callback = fire_in_order(move |value: usize| {
+            let done = done.clone();
+            let released = (value == 0).then(|| released.clone());
+            async move {
+                if let Some(released) = released {
+                    released.lock().unwrap().recv().unwrap();
+                }
+                done.send(value).unwrap();
+            }
+        });
+
+        for value in 0..5 {
+            callback(value);
+        }
+        assert!(finished.try_recv().is_err());
+        release.send(()).unwrap();
+
+        let order: Vec<_> = (0..5)
+            .map(|_| finished.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        assert_eq!(order, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cancellation_is_shared_and_sticks() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+        assert!(token.is_cancelled());
+    }
+}
@@ -0,0 +1,1012 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{Read, Seek};
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use anyhow::Context;
+use windows::core::{HRESULT, HSTRING};
+use windows::{
+    Foundation::Uri,
+    Graphics::Imaging::{
+        BitmapAlphaMode, BitmapDecoder, BitmapEncoder, BitmapPixelFormat, BitmapTransform,
+        ColorManagementMode, ExifOrientationMode,
+    },
+    Storage::{
+        AccessCache::StorageApplicationPermissions,
+        StorageFile, StreamedFileDataRequest, StreamedFileDataRequestedHandler,
+        StreamedFileFailureMode,
+        Streams::{
+            DataReader, DataWriter, InMemoryRandomAccessStream, InputStreamOptions,
+            RandomAccessStreamReference,
+        },
+    },
+    Web::Http::{HttpClient, HttpCompletionOption, HttpMethod, HttpRequestMessage},
+};
+
+const EXTENDED_PREFIX: &str = r"\\?\";
+const EXTENDED_UNC_PREFIX: &str = r"\\?\UNC\";
+const DEVICE_PREFIX: &str = r"\\.\";
+const UNC_PREFIX: &str = r"\\";
+
+/// Paths at or above this length need the extended-length prefix.
+const MAX_PATH: usize = 260;
+
+/// How many prefetched thumbnails `ThumbnailCache` keeps.
+const CACHE_CAPACITY: usize = 8;
+
+/// `HRESULT_FROM_WIN32(ERROR_TIMEOUT)` and `WININET_E_TIMEOUT`, returned by
+/// `HttpClient` when a request times out.
+const ERROR_TIMEOUT: HRESULT = HRESULT(0x800705B4_u32 as i32);
+const WININET_E_TIMEOUT: HRESULT = HRESULT(0x80072EE2_u32 as i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThumbnailScheme {
+    File,
+    Http,
+    Https,
+}
+
+impl ThumbnailScheme {
+    pub fn all() -> Vec<Self> {
+        vec![Self::File, Self::Http, Self::Https]
+    }
+
+    pub fn of(source: &str) -> Self {
+        if source.starts_with("https") {
+            Self::Https
+        } else if is_url(source) {
+            Self::Http
+        } else {
+            Self::File
+        }
+    }
+}
+
+/// How `update_metadata` loads art.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ThumbnailMode {
+    /// Waits for the art (up to `thumbnail_budget_ms`, if set) and applies
+    /// it with the text.
+    #[default]
+    Sync,
+    /// Applies the text right away and the art on the thumbnail worker pool
+    /// once it has loaded, unless newer art or metadata came in first.
+    Async,
+}
+
+/// What the overlay shows while new art is still loading in the background,
+/// i.e. with `ThumbnailMode::Async` or past `thumbnail_budget_ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ThumbnailTransition {
+    /// No art until the new art has loaded.
+    #[default]
+    Blank,
+    /// The previous art next to the new text until the new art has loaded,
+    /// or is cleared if it fails to.
+    KeepOld,
+}
+
+/// Thumbnails resolved ahead of time, keyed by their source. Oldest entries
+/// are evicted first once `CACHE_CAPACITY` is reached.
+#[derive(Debug, Default)]
+pub struct ThumbnailCache {
+    entries: VecDeque<(String, RandomAccessStreamReference)>,
+}
+
+impl ThumbnailCache {
+    pub fn get(&self, source: &str) -> Option<RandomAccessStreamReference> {
+        self.entries
+            .iter()
+            .find(|(cached, _)| cached == source)
+            .map(|(_, reference)| reference.clone())
+    }
+
+    pub fn insert(&mut self, source: String, reference: RandomAccessStreamReference) {
+        self.entries.retain(|(cached, _)| *cached != source);
+        if self.entries.len() >= CACHE_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((source, reference));
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// A `ThumbnailCache` one or more instances use. Each `SMTCInternal` starts
+/// with its own, so one session's art never evicts another's; sharing one
+/// saves memory and loads when sessions show the same art.
+#[derive(Debug, Clone, Default)]
+pub struct SharedThumbnailCache(Arc<Mutex<ThumbnailCache>>);
+
+impl SharedThumbnailCache {
+    fn lock(&self) -> std::sync::MutexGuard<'_, ThumbnailCache> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn get(&self, source: &str) -> Option<RandomAccessStreamReference> {
+        self.lock().get(source)
+    }
+
+    pub fn insert(&self, source: String, reference: RandomAccessStreamReference) {
+        self.lock().insert(source, reference)
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear()
+    }
+}
+
+/// Why a thumbnail couldn't be shown. The payloads are human readable details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ThumbnailError {
+    /// `SMTCConfig::allowed_thumbnail_schemes` doesn't include the scheme.
+    SchemeNotAllowed(ThumbnailScheme),
+    FileNotFound(String),
+    NetworkTimeout(String),
+    /// The request failed for any other reason, e.g. a non-success status.
+    FetchFailed(String),
+    /// The image couldn't be decoded for transcoding.
+    DecodeFailed(String),
+    /// WinRT couldn't open the source or wrap it in a stream.
+    StreamCreateFailed(String),
+    /// A future access list token that can't be a token at all.
+    InvalidToken(String),
+    /// A future access list token that no longer refers to a file.
+    StaleToken(String),
+    /// The art is bigger than `SMTCConfig::max_thumbnail_bytes`.
+    TooLarge(String),
+}
+
+impl std::fmt::Display for ThumbnailError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThumbnailError::SchemeNotAllowed(scheme) => {
+                write!(f, "{scheme:?} thumbnail sources are not allowed")
+            }
+            ThumbnailError::FileNotFound(path) => {
+                write!(f, "thumbnail file does not exist: {path}")
+            }
+            ThumbnailError::NetworkTimeout(detail) => {
+                write!(f, "timed out fetching thumbnail: {detail}")
+            }
+            ThumbnailError::FetchFailed(detail) => write!(f, "failed to fetch thumbnail: {detail}"),
+            ThumbnailError::DecodeFailed(detail) => {
+                write!(f, "failed to decode thumbnail: {detail}")
+            }
+            ThumbnailError::StreamCreateFailed(detail) => {
+                write!(f, "failed to create thumbnail stream: {detail}")
+            }
+            ThumbnailError::InvalidToken(token) => {
+                write!(f, "invalid future access list token: {token:?}")
+            }
+            ThumbnailError::StaleToken(token) => {
+                write!(f, "future access list token is no longer valid: {token}")
+            }
+            ThumbnailError::TooLarge(detail) => write!(f, "thumbnail is too large: {detail}"),
+        }
+    }
+}
+
+impl std::error::Error for ThumbnailError {}
+
+/// Reported through `thumbnail_result_event` for every thumbnail load.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ThumbnailResult {
+    pub source: String,
+    /// `None` if the thumbnail loaded.
+    pub error: Option<ThumbnailError>,
+    /// Whether the thumbnail came from the cache instead of being loaded.
+    pub from_cache: bool,
+}
+
+fn stream_error(context: String) -> impl FnOnce(windows::core::Error) -> ThumbnailError {
+    move |e| ThumbnailError::StreamCreateFailed(format!("{context}: {}", e.message()))
+}
+
+pub fn is_url(source: &str) -> bool {
+    source.starts_with("http")
+}
+
+/// Rewrites a local path into a form `StorageFile::GetFileFromPathAsync` accepts.
+///
+/// Forward slashes are converted to backslashes and relative paths are made
+/// absolute against the working directory, resolving `.` and `..`. Paths
+/// that are still too long for the legacy `MAX_PATH` limit (counted in
+/// UTF-16 units, like the OS does) get the `\\?\` (or `\\?\UNC\` for
+/// `\\server\share` paths) extended-length prefix. Already prefixed paths are
+/// returned as is.
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('/', "\\");
+
+    if path.starts_with(EXTENDED_PREFIX) || path.starts_with(DEVICE_PREFIX) {
+        return path;
+    }
+
+    // The extended prefix turns off the OS's own normalization, so relative
+    // parts have to be resolved before it is added.
+    let path = match std::path::absolute(&path) {
+        Ok(absolute) => absolute.to_string_lossy().into_owned(),
+        Err(_) => path,
+    };
+
+    if path.encode_utf16().count() < MAX_PATH {
+        return path;
+    }
+
+    match path.strip_prefix(UNC_PREFIX) {
+        Some(share) => format!("{EXTENDED_UNC_PREFIX}{share}"),
+        None => format!("{EXTENDED_PREFIX}{path}"),
+    }
+}
+
+pub fn resolve(source: &str) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    if is_url(source) {
+        return resolve_url(source);
+    }
+    resolve_file(source)
+}
+
+fn resolve_url(url: &str) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    let uri = Uri::CreateUri(&HSTRING::from(url))
+        .map_err(stream_error(format!("invalid thumbnail url {url}")))?;
+    RandomAccessStreamReference::CreateFromUri(&uri).map_err(stream_error(url.to_string()))
+}
+
+fn resolve_file(path: &str) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    let path = normalize_path(path);
+
+    if !Path::new(&path).is_file() {
+        return Err(ThumbnailError::FileNotFound(path));
+    }
+
+    let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(&path))
+        .and_then(|operation| operation.get())
+        .map_err(stream_error(format!("failed to open {path}")))?;
+    RandomAccessStreamReference::CreateFromFile(&file).map_err(stream_error(path))
+}
+
+/// Resolves a file the user granted access to earlier, through a token from
+/// `StorageApplicationPermissions::FutureAccessList`. The list is only
+/// available to apps with package identity (MSIX or a sparse package);
+/// unpackaged apps get `StreamCreateFailed`.
+pub fn from_access_token(token: &str) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    let token = token.trim();
+    if token.is_empty() || token.chars().any(char::is_control) {
+        return Err(ThumbnailError::InvalidToken(token.to_string()));
+    }
+
+    let list = StorageApplicationPermissions::FutureAccessList().map_err(stream_error(
+        "future access list is unavailable".to_string(),
+    ))?;
+    let token = HSTRING::from(token);
+
+    let known = list
+        .ContainsItem(&token)
+        .map_err(stream_error("failed to look up token".to_string()))?;
+    if !known {
+        return Err(ThumbnailError::StaleToken(token.to_string()));
+    }
+
+    let file = list
+        .GetFileAsync(&token)
+        .and_then(|operation| operation.get())
+        .map_err(|_| ThumbnailError::StaleToken(token.to_string()))?;
+    RandomAccessStreamReference::CreateFromFile(&file).map_err(stream_error(token.to_string()))
+}
+
+/// Downloads thumbnails that need request headers or a size limit, which
+/// `RandomAccessStreamReference::CreateFromUri` can't apply.
+pub trait ThumbnailFetcher: Send + Sync {
+    fn fetch(&self, url: &str, headers: &[(String, String)]) -> anyhow::Result<Vec<u8>>;
+
+    /// `fetch`, failing with `ThumbnailError::TooLarge` if the body is over
+    /// `max_bytes` (`0` for no limit). This default only checks once the
+    /// whole body is downloaded; fetchers that can should stop early.
+    fn fetch_limited(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        max_bytes: u64,
+    ) -> anyhow::Result<Vec<u8>> {
+        let bytes = self.fetch(url, headers)?;
+        check_size(url, bytes.len() as u64, max_bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Bytes read from a response at a time while checking the size limit.
+const FETCH_CHUNK_SIZE: u32 = 64 * 1024;
+
+/// Fetches with the WinRT `HttpClient`.
+#[derive(Debug, Default)]
+pub struct HttpClientFetcher;
+
+impl ThumbnailFetcher for HttpClientFetcher {
+    fn fetch(&self, url: &str, headers: &[(String, String)]) -> anyhow::Result<Vec<u8>> {
+        self.fetch_limited(url, headers, 0)
+    }
+
+    /// Rejects responses whose `Content-Length` is over `max_bytes` before
+    /// reading the body, and stops reading once the body passes it.
+    fn fetch_limited(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        max_bytes: u64,
+    ) -> anyhow::Result<Vec<u8>> {
+        let uri = Uri::CreateUri(&HSTRING::from(url))
+            .with_context(|| format!("invalid thumbnail url: {url}"))?;
+        let request = HttpRequestMessage::Create(&HttpMethod::Get()?, &uri)?;
+
+        let request_headers = request.Headers()?;
+        for (name, value) in headers {
+            if !request_headers
+                .TryAppendWithoutValidation(&HSTRING::from(name), &HSTRING::from(value))?
+            {
+                anyhow::bail!("invalid thumbnail request header: {name}");
+            }
+        }
+
+        let response = HttpClient::new()?
+            .SendRequestWithOptionAsync(&request, HttpCompletionOption::ResponseHeadersRead)?
+            .get()?;
+        response.EnsureSuccessStatusCode()?;
+
+        let content = response.Content()?;
+        let length = content
+            .Headers()?
+            .ContentLength()
+            .and_then(|length| length.Value())
+            .ok();
+        if let Some(length) = length {
+            check_size(url, length, max_bytes)?;
+        }
+
+        let reader = DataReader::CreateDataReader(&content.ReadAsInputStreamAsync()?.get()?)?;
+        reader.SetInputStreamOptions(InputStreamOptions::Partial)?;
+        let mut bytes = Vec::new();
+        loop {
+            let read = reader.LoadAsync(FETCH_CHUNK_SIZE)?.get()?;
+            if read == 0 {
+                return Ok(bytes);
+            }
+
+            let mut chunk = vec![0; read as usize];
+            reader.ReadBytes(&mut chunk)?;
+            bytes.extend_from_slice(&chunk);
+            if max_bytes > 0 && bytes.len() as u64 > max_bytes {
+                return Err(ThumbnailError::TooLarge(format!(
+                    "{url} is over the limit of {max_bytes} bytes"
+                ))
+                .into());
+            }
+        }
+    }
+}
+
+/// The fetcher used for thumbnails with headers, swappable so apps can route
+/// requests through their own client.
+#[derive(Clone)]
+pub struct SharedFetcher(pub Arc<dyn ThumbnailFetcher>);
+
+impl Default for SharedFetcher {
+    fn default() -> Self {
+        Self(Arc::new(HttpClientFetcher))
+    }
+}
+
+impl std::fmt::Debug for SharedFetcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedFetcher")
+    }
+}
+
+/// Loads the bytes of any thumbnail source, within the given time, in place
+/// of the built-in file and http(s) handling, see
+/// `SMTCInternal::set_thumbnail_resolver`.
+#[derive(Clone)]
+pub struct ThumbnailResolver(
+    pub Arc<dyn Fn(&str, Duration) -> anyhow::Result<Vec<u8>> + Send + Sync>,
+);
+
+impl std::fmt::Debug for ThumbnailResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ThumbnailResolver")
+    }
+}
+
+/// Resolves an http(s) thumbnail by fetching it with `headers` and at most
+/// `max_bytes` (`0` for no limit), and wrapping the bytes. Falls back to
+/// `resolve` for files and for urls with neither headers nor a limit.
+pub fn resolve_with_headers(
+    url: &str,
+    headers: &[(String, String)],
+    max_bytes: u64,
+    fetcher: &dyn ThumbnailFetcher,
+) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    if !is_url(url) || (headers.is_empty() && max_bytes == 0) {
+        return resolve(url);
+    }
+
+    let bytes = fetcher
+        .fetch_limited(url, headers, max_bytes)
+        .map_err(|e| {
+            if let Some(e) = e.downcast_ref::<ThumbnailError>() {
+                return e.clone();
+            }
+            let timed_out = e
+                .downcast_ref::<windows::core::Error>()
+                .is_some_and(|e| matches!(e.code(), ERROR_TIMEOUT | WININET_E_TIMEOUT));
+            if timed_out {
+                ThumbnailError::NetworkTimeout(url.to_string())
+            } else {
+                ThumbnailError::FetchFailed(format!("{url}: {e:#}"))
+            }
+        })?;
+    from_bytes(&bytes)
+}
+
+/// Copies `bytes` into an in-memory WinRT stream. The stream has no content
+/// type; the overlay detects the format from the data, see
+/// `with_content_type` for art it gets wrong.
+pub fn from_bytes(bytes: &[u8]) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    let copy = || -> windows::core::Result<RandomAccessStreamReference> {
+        let stream = InMemoryRandomAccessStream::new()?;
+
+        let writer = DataWriter::CreateDataWriter(&stream)?;
+        writer.WriteBytes(bytes)?;
+        writer.StoreAsync()?.get()?;
+        writer.FlushAsync()?.get()?;
+        writer.DetachStream()?;
+
+        stream.Seek(0)?;
+        RandomAccessStreamReference::CreateFromStream(&stream)
+    };
+    copy().map_err(stream_error("failed to copy thumbnail bytes".to_string()))
+}
+
+/// Reads the rest of an already open file, so callers that can't reopen the
+/// source by path (e.g. handles from a content provider) can still set art.
+/// Fails with `ThumbnailError::TooLarge`, before reading anything, if the
+/// rest is over `max_bytes` (`0` for no limit).
+pub fn from_file(file: &mut File, max_bytes: u64) -> anyhow::Result<RandomAccessStreamReference> {
+    if max_bytes > 0 {
+        let position = file
+            .stream_position()
+            .context("thumbnail file handle is not seekable")?;
+        let len = file
+            .metadata()
+            .context("thumbnail file handle has no readable size")?
+            .len();
+        check_size(
+            "thumbnail file handle",
+            len.saturating_sub(position),
+            max_bytes,
+        )?;
+    }
+
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)
+        .context("thumbnail file handle is not readable")?;
+
+    if bytes.is_empty() {
+        anyhow::bail!("thumbnail file handle has no data left to read");
+    }
+
+    Ok(from_bytes(&bytes)?)
+}
+
+/// The file extension WinRT maps back to `content_type`.
+fn extension_of(content_type: &str) -> Option<&'static str> {
+    let extension = match content_type.trim().to_ascii_lowercase().as_str() {
+        "image/png" => "png",
+        "image/jpeg" | "image/jpg" => "jpg",
+        "image/gif" => "gif",
+        "image/bmp" => "bmp",
+        "image/webp" => "webp",
+        "image/tiff" => "tif",
+        _ => return None,
+    };
+    Some(extension)
+}
+
+/// Rewraps `source` so its stream reports `content_type` (e.g. `image/png`)
+/// instead of whatever was detected. WinRT streams can't be given a content
+/// type directly, so the bytes are served through a streamed file whose
+/// name carries the matching extension. Only common image types are known;
+/// anything else fails with `StreamCreateFailed`.
+pub fn with_content_type(
+    source: &RandomAccessStreamReference,
+    content_type: &str,
+) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    let extension = extension_of(content_type).ok_or_else(|| {
+        ThumbnailError::StreamCreateFailed(format!("unknown thumbnail content type {content_type}"))
+    })?;
+    let bytes = to_bytes(source)?;
+
+    let handler = StreamedFileDataRequestedHandler::new(move |request| {
+        let Some(request) = request.as_ref() else {
+            return Ok(());
+        };
+        let write = |request: &StreamedFileDataRequest| -> windows::core::Result<()> {
+            let writer = DataWriter::CreateDataWriter(request)?;
+            writer.WriteBytes(&bytes)?;
+            writer.StoreAsync()?.get()?;
+            writer.FlushAsync()?.get()?;
+            writer.DetachStream()?;
+            request.Close()
+        };
+        write(request).or_else(|_| request.FailAndClose(StreamedFileFailureMode::Failed))
+    });
+
+    let file = StorageFile::CreateStreamedFileAsync(
+        &HSTRING::from(format!("thumbnail.{extension}")),
+        &handler,
+        source,
+    )
+    .and_then(|operation| operation.get())
+    .map_err(stream_error(format!(
+        "failed to serve thumbnail as {content_type}"
+    )))?;
+    RandomAccessStreamReference::CreateFromFile(&file)
+        .map_err(stream_error(content_type.to_string()))
+}
+
+/// Duplicates a raw Win32 file `HANDLE` into a `File` of its own, after
+/// checking it refers to an open disk file. The duplicate shares the
+/// original's file pointer, but closing it leaves the original open.
+///
+/// # Safety
+///
+/// `handle` must be a handle value the caller owns (or borrows) for the
+/// duration of the call. A closed or garbage value is rejected, but one
+/// that happens to name an unrelated file handle of this process can't be
+/// told apart from a real one.
+#[cfg(windows)]
+pub unsafe fn file_from_handle(handle: std::os::windows::io::RawHandle) -> anyhow::Result<File> {
+    use std::os::windows::io::FromRawHandle;
+    use windows::Win32::{
+        Foundation::{DuplicateHandle, DUPLICATE_SAME_ACCESS, HANDLE},
+        Storage::FileSystem::{GetFileType, FILE_TYPE_DISK},
+        System::Threading::GetCurrentProcess,
+    };
+
+    let handle = HANDLE(handle);
+    if handle.is_invalid() {
+        anyhow::bail!("thumbnail file handle is null or INVALID_HANDLE_VALUE");
+    }
+    if GetFileType(handle) != FILE_TYPE_DISK {
+        anyhow::bail!("thumbnail file handle is closed or not a disk file");
+    }
+
+    let process = GetCurrentProcess();
+    let mut duplicate = HANDLE::default();
+    DuplicateHandle(
+        process,
+        handle,
+        process,
+        &mut duplicate,
+        0,
+        false,
+        DUPLICATE_SAME_ACCESS,
+    )
+    .context("failed to duplicate thumbnail file handle")?;
+    Ok(File::from_raw_handle(duplicate.0))
+}
+
+/// Longest side the art is scaled down to before averaging its colors.
+const COLOR_SAMPLE_SIZE: u32 = 64;
+
+/// Fails with `TooLarge` if `size` is over `max` bytes. A `max` of `0` allows
+/// any size.
+pub fn check_size(source: &str, size: u64, max: u64) -> Result<(), ThumbnailError> {
+    if max > 0 && size > max {
+        return Err(ThumbnailError::TooLarge(format!(
+            "{source} is {size} bytes, the limit is {max}"
+        )));
+    }
+    Ok(())
+}
+
+/// The size of `source` in bytes, without reading it. Opening a url
+/// reference downloads it, so urls are limited by the fetcher instead.
+pub fn stream_size(source: &RandomAccessStreamReference) -> Result<u64, ThumbnailError> {
+    source
+        .OpenReadAsync()
+        .and_then(|operation| operation.get())
+        .and_then(|stream| stream.Size())
+        .map_err(stream_error("failed to open thumbnail".to_string()))
+}
+
+/// Reads the whole of `source`, e.g. to show the same art in the app.
+pub fn to_bytes(source: &RandomAccessStreamReference) -> Result<Vec<u8>, ThumbnailError> {
+    let stream = source
+        .OpenReadAsync()
+        .and_then(|operation| operation.get())
+        .map_err(stream_error("failed to open thumbnail".to_string()))?;
+    let read = || -> windows::core::Result<Vec<u8>> {
+        let size = stream.Size()? as u32;
+        let reader = DataReader::CreateDataReader(&stream.GetInputStreamAt(0)?)?;
+        reader.LoadAsync(size)?.get()?;
+        let mut bytes = vec![0; size as usize];
+        reader.ReadBytes(&mut bytes)?;
+        Ok(bytes)
+    };
+    read().map_err(stream_error("failed to read thumbnail".to_string()))
+}
+
+/// The average color of `source` as `0xAARRGGBB`, weighted by alpha so
+/// transparent areas don't darken it. Fully transparent art gives `0`.
+pub fn average_color(source: &RandomAccessStreamReference) -> Result<u32, ThumbnailError> {
+    let decode_error = |e: windows::core::Error| ThumbnailError::DecodeFailed(e.message());
+
+    let input = source
+        .OpenReadAsync()
+        .and_then(|operation| operation.get())
+        .map_err(stream_error("failed to open thumbnail".to_string()))?;
+    let pixels = (|| -> windows::core::Result<Vec<u8>> {
+        let decoder = BitmapDecoder::CreateAsync(&input)?.get()?;
+        let (width, height) = (decoder.PixelWidth()?, decoder.PixelHeight()?);
+        let scale = |side: u32| {
+            (side as u64 * COLOR_SAMPLE_SIZE as u64 / width.max(height).max(1) as u64).max(1) as u32
+        };
+
+        let transform = BitmapTransform::new()?;
+        if width.max(height) > COLOR_SAMPLE_SIZE {
+            transform.SetScaledWidth(scale(width))?;
+            transform.SetScaledHeight(scale(height))?;
+        }
+        decoder
+            .GetPixelDataTransformedAsync(
+                BitmapPixelFormat::Bgra8,
+                BitmapAlphaMode::Straight,
+                &transform,
+                ExifOrientationMode::IgnoreExifOrientation,
+                ColorManagementMode::DoNotColorManage,
+            )?
+            .get()?
+            .DetachPixelData()
+            .map(|pixels| pixels.to_vec())
+    })()
+    .map_err(decode_error)?;
+
+    let (mut b, mut g, mut r, mut a, mut count) = (0u64, 0u64, 0u64, 0u64, 0u64);
+    for pixel in pixels.chunks_exact(4) {
+        let alpha = pixel[3] as u64;
+        b += pixel[0] as u64 * alpha;
+        g += pixel[1] as u64 * alpha;
+        r += pixel[2] as u64 * alpha;
+        a += alpha;
+        count += 1;
+    }
+    if a == 0 {
+        return Ok(0);
+    }
+
+    let channel = |sum: u64| (sum / a) as u32;
+    let alpha = (a / count) as u32;
+    Ok(alpha << 24 | channel(r) << 16 | channel(g) << 8 | channel(b))
+}
+
+/// MIME types of every image format the installed imaging codecs can
+/// decode, e.g. `image/png`. Each type is listed once.
+pub fn supported_formats() -> anyhow::Result<Vec<String>> {
+    let mut formats = Vec::new();
+    for codec in BitmapDecoder::GetDecoderInformationEnumerator()? {
+        for mime_type in codec.MimeTypes()? {
+            let mime_type = mime_type.to_string().to_lowercase();
+            if !formats.contains(&mime_type) {
+                formats.push(mime_type);
+            }
+        }
+    }
+    Ok(formats)
+}
+
+/// Decodes `source` and re-encodes it as PNG, for formats the overlay
+/// doesn't render reliably.
+pub fn transcode_to_png(
+    source: &RandomAccessStreamReference,
+) -> Result<RandomAccessStreamReference, ThumbnailError> {
+    let decode_error = |e: windows::core::Error| ThumbnailError::DecodeFailed(e.message());
+
+    let input = source
+        .OpenReadAsync()
+        .and_then(|operation| operation.get())
+        .map_err(stream_error("failed to open thumbnail".to_string()))?;
+    let bitmap = BitmapDecoder::CreateAsync(&input)
+        .and_then(|operation| operation.get())
+        .and_then(|decoder| decoder.GetSoftwareBitmapAsync()?.get())
+        .map_err(decode_error)?;
+
+    let encode = || -> windows::core::Result<RandomAccessStreamReference> {
+        let output = InMemoryRandomAccessStream::new()?;
+        let encoder = BitmapEncoder::CreateAsync(BitmapEncoder::PngEncoderId()?, &output)?.get()?;
+        encoder.SetSoftwareBitmap(&bitmap)?;
+        encoder.FlushAsync()?.get()?;
+
+        output.Seek(0)?;
+        RandomAccessStreamReference::CreateFromStream(&output)
+    };
+    encode().map_err(stream_error(
+        "failed to encode thumbnail as png".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedFetcher(usize);
+
+    impl ThumbnailFetcher for FixedFetcher {
+        fn fetch(&self, _url: &str, _headers: &[(String, String)]) -> anyhow::Result<Vec<u8>> {
+            Ok(vec![0; self.0])
+        }
+    }
+
+    #[test]
+    fn scheme_of_tells_urls_from_paths() {
+        assert_eq!(
+            ThumbnailScheme::of("https://example.com/cover.png"),
+            ThumbnailScheme::Https
+        );
+        assert_eq!(
+            ThumbnailScheme::of("http://example.com/cover.png"),
+            ThumbnailScheme::Http
+        );
+        assert_eq!(
+            ThumbnailScheme::of(r"C:\Music\cover.png"),
+            ThumbnailScheme::File
+        );
+        assert_eq!(
+            ThumbnailScheme::of("covers/https.png"),
+            ThumbnailScheme::File
+        );
+    }
+
+    #[test]
+    fn check_size_applies_the_limit() {
+        assert!(check_size("cover.png", 100, 0).is_ok());
+        assert!(check_size("cover.png", 100, 100).is_ok());
+        assert!(matches!(
+            check_size("cover.png", 101, 100),
+            Err(ThumbnailError::TooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn fetch_limited_rejects_large_bodies() {
+        let fetcher = FixedFetcher(10);
+        assert_eq!(
+            fetcher
+                .fetch_limited("https://a/b.png", &[], 10)
+                .unwrap()
+                .len(),
+            10
+        );
+        let e = fetcher
+            .fetch_limited("https://a/b.png", &[], 9)
+            .unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<ThumbnailError>(),
+            Some(ThumbnailError::TooLarge(_))
+        ));
+    }
+
+    struct FailingFetcher(HRESULT);
+
+    impl ThumbnailFetcher for FailingFetcher {
+        fn fetch(&self, _url: &str, _headers: &[(String, String)]) -> anyhow::Result<Vec<u8>> {
+            Err(windows::core::Error::from(self.0).into())
+        }
+    }
+
+    #[test]
+    fn resolve_with_headers_reports_timeouts() {
+        for code in [ERROR_TIMEOUT, WININET_E_TIMEOUT] {
+            let result = resolve_with_headers("https://a/b.png", &[], 9, &FailingFetcher(code));
+            assert_eq!(
+                result.err(),
+                Some(ThumbnailError::NetworkTimeout(
+                    "https://a/b.png".to_string()
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_with_headers_reports_other_failures_as_fetch_failed() {
+        let e_fail = HRESULT(0x80004005_u32 as i32);
+        let result = resolve_with_headers("https://a/b.png", &[], 9, &FailingFetcher(e_fail));
+        match result {
+            Err(ThumbnailError::FetchFailed(detail)) => {
+                assert!(detail.starts_with("https://a/b.png: "), "{detail}")
+            }
+            other => panic!("expected FetchFailed, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn resolve_with_headers_keeps_fetcher_errors() {
+        let result = resolve_with_headers("https://a/b.png", &[], 9, &FixedFetcher(10));
+        assert!(matches!(result, Err(ThumbnailError::TooLarge(_))));
+    }
+
+    #[test]
+    fn with_content_type_sets_the_stream_content_type() {
+        let source = from_bytes(b"not sniffed").unwrap();
+
+        let typed = with_content_type(&source, "image/png").unwrap();
+
+        let stream = typed.OpenReadAsync().unwrap().get().unwrap();
+        assert_eq!(stream.ContentType().unwrap(), "image/png");
+        assert_eq!(to_bytes(&typed).unwrap(), b"not sniffed");
+    }
+
+    #[test]
+    fn with_content_type_rejects_unknown_types() {
+        let source = from_bytes(b"not sniffed").unwrap();
+
+        assert!(matches!(
+            with_content_type(&source, "application/octet-stream"),
+            Err(ThumbnailError::StreamCreateFailed(_))
+        ));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn file_from_handle_reads_a_borrowed_handle() {
+        use std::io::Write;
+        use std::os::windows::io::AsRawHandle;
+
+        let path = std::env::temp_dir().join("smtc_windows_file_from_handle.png");
+        std::fs::File::create(&path)
+            .unwrap()
+            .write_all(b"art")
+            .unwrap();
+        let original = File::open(&path).unwrap();
+
+        let mut duplicate = unsafe { file_from_handle(original.as_raw_handle()) }.unwrap();
+        let mut bytes = Vec::new();
+        duplicate.read_to_end(&mut bytes).unwrap();
+        drop(duplicate);
+
+        assert_eq!(bytes, b"art");
+        // Still open after the duplicate is closed.
+        assert!(original.metadata().is_ok());
+        drop(original);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn file_from_handle_rejects_non_handles() {
+        // Null, `INVALID_HANDLE_VALUE` and the current thread's pseudo handle.
+        for handle in [0, -1, -2] {
+            let result = unsafe { file_from_handle(handle as std::os::windows::io::RawHandle) };
+            assert!(result.is_err(), "{handle}");
+        }
+    }
+
+    #[test]
+    fn normalize_path_converts_slashes() {
+        assert_eq!(normalize_path("C:/Music/cover.png"), r"C:\Music\cover.png");
+    }
+
+    #[test]
+    fn normalize_path_keeps_prefixed_paths() {
+        let extended = r"\\?\C:\Music\cover.png";
+        let device = r"\\.\C:\Music\cover.png";
+        assert_eq!(normalize_path(extended), extended);
+        assert_eq!(normalize_path(device), device);
+    }
+
+    #[test]
+    fn normalize_path_prefixes_long_paths() {
+        let path = format!(r"C:\{}\cover.png", "a".repeat(MAX_PATH));
+        assert_eq!(normalize_path(&path), format!(r"\\?\{path}"));
+    }
+
+    #[test]
+    fn normalize_path_prefixes_long_unc_paths() {
+        let share = format!(r"server\share\{}\cover.png", "a".repeat(MAX_PATH));
+        assert_eq!(
+            normalize_path(&format!(r"\\{share}")),
+            format!(r"\\?\UNC\{share}")
+        );
+    }
+
+    #[test]
+    fn normalize_path_counts_utf16_units() {
+        // 2 bytes per character in UTF-8, but a single UTF-16 unit.
+        let path = format!(r"C:\{}.png", "é".repeat(200));
+        assert!(path.len() > MAX_PATH);
+        assert_eq!(normalize_path(&path), path);
+    }
+
+    #[test]
+    fn normalize_path_absolutizes_relative_paths() {
+        let expected = std::env::current_dir().unwrap().join("cover.png");
+        assert_eq!(normalize_path("cover.png"), expected.to_string_lossy());
+
+        let long = format!(r"{}\skip\..\cover.png", "a".repeat(MAX_PATH));
+        let normalized = normalize_path(&long);
+        assert!(normalized.starts_with(EXTENDED_PREFIX));
+        assert!(!normalized.contains(r"\.."));
+    }
+
+    /// A 1x1 24-bit BMP of the given blue, green and red.
+    fn bmp_pixel(blue: u8, green: u8, red: u8) -> Vec<u8> {
+        let mut bmp = Vec::new();
+        // BITMAPFILEHEADER: magic, file size, reserved, pixel data offset.
+        bmp.extend_from_slice(b"BM");
+        bmp.extend_from_slice(&58u32.to_le_bytes());
+        bmp.extend_from_slice(&0u32.to_le_bytes());
+        bmp.extend_from_slice(&54u32.to_le_bytes());
+        // BITMAPINFOHEADER: size, width, height, planes, bits per pixel,
+        // no compression, image size, resolution and palette sizes.
+        bmp.extend_from_slice(&40u32.to_le_bytes());
+        bmp.extend_from_slice(&1i32.to_le_bytes());
+        bmp.extend_from_slice(&1i32.to_le_bytes());
+        bmp.extend_from_slice(&1u16.to_le_bytes());
+        bmp.extend_from_slice(&24u16.to_le_bytes());
+        bmp.extend_from_slice(&0u32.to_le_bytes());
+        bmp.extend_from_slice(&4u32.to_le_bytes());
+        bmp.extend_from_slice(&[0; 16]);
+        // One pixel, padded to a 4 byte row.
+        bmp.extend_from_slice(&[blue, green, red, 0]);
+        bmp
+    }
+
+    #[test]
+    fn transcode_to_png_turns_a_bmp_into_a_png() {
+        let bmp = from_bytes(&bmp_pixel(0x30, 0x20, 0x10)).unwrap();
+
+        let png = to_bytes(&transcode_to_png(&bmp).unwrap()).unwrap();
+
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"), "{:?}", &png[..8]);
+        let png = from_bytes(&png).unwrap();
+        assert_eq!(average_color(&png).unwrap(), 0xFF102030);
+    }
+
+    #[derive(Default)]
+    struct RecordingFetcher(Mutex<Vec<(String, Vec<(String, String)>)>>);
+
+    impl ThumbnailFetcher for RecordingFetcher {
+        fn fetch(&self, url: &str, headers: &[(String, String)]) -> anyhow::Result<Vec<u8>> {
+            self.0
+                .lock()
+                .unwrap()
+                .push((url.to_string(), headers.to_vec()));
+            Ok(b"art".to_vec())
+        }
+    }
+
+    #[test]
+    fn resolve_with_headers_sends_the_headers_through_the_fetcher() {
+        let fetcher = RecordingFetcher::default();
+        let headers = vec![("Authorization".to_string(), "Bearer x".to_string())];
+
+        let reference = resolve_with_headers("https://a/b.png", &headers, 0, &fetcher).unwrap();
+
+        assert_eq!(
+            *fetcher.0.lock().unwrap(),
+            [("https://a/b.png".to_string(), headers)]
+        );
+        assert_eq!(to_bytes(&reference).unwrap(), b"art");
+    }
+
+    #[test]
+    fn resolve_with_headers_leaves_plain_urls_to_the_overlay() {
+        let fetcher = RecordingFetcher::default();
+
+        resolve_with_headers("https://a/b.png", &[], 0, &fetcher).unwrap();
+
+        assert!(fetcher.0.lock().unwrap().is_empty());
+    }
+}
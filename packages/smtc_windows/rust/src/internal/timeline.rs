@@ -1,6 +1,20 @@
+use std::sync::Arc;
 use std::time::Duration;
 
 use windows::{Foundation::TimeSpan, Media::SystemMediaTransportControlsTimelineProperties};
+
+/// Returns the app's current position in milliseconds, within the given
+/// time, polled by the position timer, see
+/// `SMTCInternal::set_position_provider`.
+#[derive(Clone)]
+pub struct PositionProvider(pub Arc<dyn Fn(Duration) -> anyhow::Result<i64> + Send + Sync>);
+
+impl std::fmt::Debug for PositionProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PositionProvider")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub struct PlaybackTimeline {
     pub start_time_ms: i64,
@@ -32,6 +46,25 @@ impl PlaybackTimeline {
         self.max_seek_time_ms
             .map(|d| TimeSpan::from(Duration::from_millis(d as u64)))
     }
+
+    /// Whether the overlay lets the user seek to `position_ms`: within
+    /// `min_seek_time_ms..=max_seek_time_ms`, which default to the start and
+    /// end time.
+    pub fn can_seek_to(&self, position_ms: i64) -> bool {
+        let min = self.min_seek_time_ms.unwrap_or(self.start_time_ms);
+        let max = self.max_seek_time_ms.unwrap_or(self.end_time_ms);
+        (min..=max).contains(&position_ms)
+    }
+
+    /// Pins `MinSeekTime` and `MaxSeekTime` to the position. With no range
+    /// left to seek in, the overlay disables its seek bar.
+    pub fn without_seeking(self) -> Self {
+        Self {
+            min_seek_time_ms: Some(self.position_ms),
+            max_seek_time_ms: Some(self.position_ms),
+            ..self
+        }
+    }
 }
 
 impl Into<anyhow::Result<SystemMediaTransportControlsTimelineProperties>> for PlaybackTimeline {
@@ -53,3 +86,50 @@ impl Into<anyhow::Result<SystemMediaTransportControlsTimelineProperties>> for Pl
         return Ok(timeline);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeline(position_ms: i64) -> PlaybackTimeline {
+        PlaybackTimeline {
+            start_time_ms: 0,
+            end_time_ms: 100_000,
+            position_ms,
+            min_seek_time_ms: None,
+            max_seek_time_ms: None,
+        }
+    }
+
+    #[test]
+    fn without_seeking_pins_the_range_to_the_position() {
+        let pinned = timeline(42_000).without_seeking();
+        assert_eq!(pinned.min_seek_time_ms, Some(42_000));
+        assert_eq!(pinned.max_seek_time_ms, Some(42_000));
+        assert_eq!(pinned.end_time_ms, 100_000);
+        assert!(!pinned.can_seek_to(41_999));
+        assert!(pinned.can_seek_to(42_000));
+    }
+
+    #[test]
+    fn can_seek_to_defaults_to_the_whole_timeline() {
+        let timeline = timeline(0);
+        assert!(timeline.can_seek_to(0));
+        assert!(timeline.can_seek_to(100_000));
+        assert!(!timeline.can_seek_to(-1));
+        assert!(!timeline.can_seek_to(100_001));
+    }
+
+    #[test]
+    fn can_seek_to_honors_the_seek_range() {
+        let timeline = PlaybackTimeline {
+            min_seek_time_ms: Some(10_000),
+            max_seek_time_ms: Some(20_000),
+            ..timeline(15_000)
+        };
+        assert!(timeline.can_seek_to(10_000));
+        assert!(timeline.can_seek_to(20_000));
+        assert!(!timeline.can_seek_to(9_999));
+        assert!(!timeline.can_seek_to(20_001));
+    }
+}